@@ -0,0 +1,129 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use core::fmt;
+use core::ops;
+
+use crate::generic::binary_heap::GenericHeap;
+use crate::storage::Heap;
+
+/// A fixed-capacity priority queue, implemented as a binary max-heap.
+///
+/// The heap can hold between 0 and `CAPACITY` elements, and behaves similarly to Rust's `BinaryHeap`,
+/// except that it allocates memory immediately on construction, and can't shrink or grow.
+pub struct FixedCapacityBinaryHeap<T> {
+    inner: GenericHeap<T, Heap<T>>,
+}
+
+impl<T> FixedCapacityBinaryHeap<T> {
+    /// Creates an empty heap and allocates memory for up to `capacity` elements, where `capacity <= u32::MAX`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `capacity > u32::MAX`.
+    /// - Panics if the memory allocation fails.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        assert!(
+            capacity <= u32::MAX as usize,
+            "FixedCapacityBinaryHeap can hold at most u32::MAX elements"
+        );
+        Self {
+            inner: GenericHeap::new(capacity as u32),
+        }
+    }
+
+    /// Tries to create an empty heap for up to `capacity` elements, where `capacity <= u32::MAX`.
+    ///
+    /// Returns `None` if `capacity > u32::MAX`, or if the memory allocation fails.
+    #[must_use]
+    pub fn try_new(capacity: usize) -> Option<Self> {
+        if capacity <= u32::MAX as usize {
+            Some(Self {
+                inner: GenericHeap::try_new(capacity as u32)?,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Drop for FixedCapacityBinaryHeap<T> {
+    fn drop(&mut self) {
+        self.inner.clear();
+    }
+}
+
+impl<T> ops::Deref for FixedCapacityBinaryHeap<T> {
+    type Target = GenericHeap<T, Heap<T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> ops::DerefMut for FixedCapacityBinaryHeap<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for FixedCapacityBinaryHeap<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BinaryHeap;
+    use std::rc::Rc;
+
+    use super::*;
+
+    #[test]
+    fn push_and_pop() {
+        let mut heap = FixedCapacityBinaryHeap::<String>::new(4);
+        let mut control = BinaryHeap::new();
+        for value in ["b", "d", "a", "c"] {
+            heap.push(value.to_string()).unwrap();
+            control.push(value.to_string());
+        }
+        assert!(heap.push("e".to_string()).is_err());
+        assert_eq!(heap.peek(), Some(&"d".to_string()));
+
+        while let Some(expected) = control.pop() {
+            assert_eq!(heap.pop(), Some(expected));
+        }
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn drop_elements() {
+        let value = Rc::new(0);
+        let mut heap = FixedCapacityBinaryHeap::new(5);
+        for _ in 0..3 {
+            heap.push(value.clone()).unwrap();
+        }
+        drop(heap.pop());
+        assert_eq!(Rc::strong_count(&value), 3);
+        drop(heap);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn try_new() {
+        assert_eq!(FixedCapacityBinaryHeap::<i64>::try_new(5).unwrap().capacity(), 5);
+        assert!(FixedCapacityBinaryHeap::<i64>::try_new(u32::MAX as usize + 1).is_none());
+    }
+}
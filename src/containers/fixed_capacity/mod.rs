@@ -11,10 +11,14 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
+mod binary_heap;
 mod queue;
+mod spsc_queue;
 mod string;
 mod vec;
 
-pub use self::queue::FixedCapacityQueue;
+pub use self::binary_heap::FixedCapacityBinaryHeap;
+pub use self::queue::{FixedCapacityQueue, HeapQueue};
+pub use self::spsc_queue::FixedCapacitySpscQueue;
 pub use self::string::FixedCapacityString;
 pub use self::vec::FixedCapacityVec;
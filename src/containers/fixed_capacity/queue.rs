@@ -11,19 +11,32 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
+use core::fmt;
 use core::ops;
 
 use crate::generic::queue::GenericQueue;
 use crate::storage::Heap;
+use crate::InsufficientCapacity;
 
 /// A fixed-capacity queue.
 ///
 /// The queue can hold between 0 and `CAPACITY` elements, and behaves similarly to Rust's `VecDeque`,
-/// except that it allocates memory immediately on construction, and can't shrink or grow.
+/// except that it allocates memory immediately on construction, and never grows implicitly:
+/// pushing into a full queue fails instead of reallocating.
+///
+/// The capacity can only be increased explicitly with `try_reserve()`,
+/// which reallocates the heap storage. The queue never shrinks.
 pub struct FixedCapacityQueue<T> {
     inner: GenericQueue<T, Heap<T>>,
 }
 
+/// A fixed-capacity queue backed by heap memory.
+///
+/// This is an alias for [`FixedCapacityQueue`], named after its storage to pair with [`InlineQueue`](crate::inline::InlineQueue).
+/// Unlike [`InlineQueue`](crate::inline::InlineQueue), its capacity can be increased explicitly with
+/// `try_reserve()`, which reallocates the storage.
+pub type HeapQueue<T> = FixedCapacityQueue<T>;
+
 impl<T> FixedCapacityQueue<T> {
     /// Creates an empty queue and allocates memory for up to `capacity` elements, where `capacity <= u32::MAX`.
     ///
@@ -41,6 +54,63 @@ impl<T> FixedCapacityQueue<T> {
             inner: GenericQueue::new(capacity as u32),
         }
     }
+
+    /// Tries to create an empty queue for up to `capacity` elements, where `capacity <= u32::MAX`.
+    ///
+    /// Returns `None` if `capacity > u32::MAX`, or if the memory allocation fails.
+    #[must_use]
+    pub fn try_new(capacity: usize) -> Option<Self> {
+        if capacity <= u32::MAX as usize {
+            Some(Self {
+                inner: GenericQueue::try_new(capacity as u32)?,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Creates an empty queue and allocates memory for up to `capacity` elements, where `capacity <= u32::MAX`.
+    ///
+    /// This is equivalent to [`new`](Self::new).
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `capacity > u32::MAX`.
+    /// - Panics if the memory allocation fails.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::new(capacity)
+    }
+
+    /// Creates a queue for up to `capacity` elements, where `capacity <= u32::MAX`, and fills it from the iterator.
+    ///
+    /// Returns `Err(InsufficientCapacity)` if the iterator yields more than `capacity` elements.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `capacity > u32::MAX`.
+    /// - Panics if the memory allocation fails.
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(capacity: usize, iter: I) -> Result<Self, InsufficientCapacity> {
+        let mut queue = Self::new(capacity);
+        queue.extend_back(iter)?;
+        Ok(queue)
+    }
+
+    /// Splits the queue into two at the given index.
+    ///
+    /// Returns a new queue containing the elements `[at, len)`, in order, and leaves `self` with the elements `[0, at)`.
+    /// The new queue has a capacity of exactly `len - at`; the capacity of `self` is unchanged.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `at > len`.
+    /// - Panics if the memory allocation fails.
+    #[must_use]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        Self {
+            inner: self.inner.split_off(at),
+        }
+    }
 }
 
 impl<T> Drop for FixedCapacityQueue<T> {
@@ -63,9 +133,16 @@ impl<T> ops::DerefMut for FixedCapacityQueue<T> {
     }
 }
 
+impl<T: fmt::Debug> fmt::Debug for FixedCapacityQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::VecDeque;
+    use std::rc::Rc;
 
     use super::*;
 
@@ -75,6 +152,154 @@ mod tests {
         elements
     }
 
+    #[test]
+    fn try_reserve() {
+        let mut queue = FixedCapacityQueue::<String>::new(4);
+        let mut control = VecDeque::new();
+        // Wrap the contents around the end of the storage
+        for i in 0..6 {
+            queue.push_back(i.to_string()).unwrap();
+            control.push_back(i.to_string());
+            if queue.is_full() {
+                queue.pop_front();
+                control.pop_front();
+            }
+        }
+        queue.push_back("6".to_string()).unwrap();
+        control.push_back("6".to_string());
+        assert!(queue.is_full());
+
+        assert!(queue.try_reserve(3).is_ok());
+        assert_eq!(queue.capacity(), 8);
+        assert_eq!(queue.as_slices().1.len(), 0);
+        assert!(queue.iter().eq(control.iter()));
+
+        for i in 7..11 {
+            queue.push_back(i.to_string()).unwrap();
+            control.push_back(i.to_string());
+        }
+        assert!(queue.iter().eq(control.iter()));
+
+        // Enough capacity already, no reallocation
+        queue.pop_front();
+        assert!(queue.try_reserve(1).is_ok());
+        assert_eq!(queue.capacity(), 8);
+
+        assert!(queue.try_reserve(u32::MAX).is_err());
+        assert_eq!(queue.len(), 7);
+    }
+
+    #[test]
+    fn try_new() {
+        let queue = FixedCapacityQueue::<i64>::try_new(5).unwrap();
+        assert_eq!(queue.capacity(), 5);
+        assert!(queue.is_empty());
+
+        assert!(FixedCapacityQueue::<i64>::try_new(u32::MAX as usize + 1).is_none());
+        assert!(FixedCapacityQueue::<[u64; 1 << 30]>::try_new(u32::MAX as usize).is_none());
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut queue = FixedCapacityQueue::<String>::new(0);
+        assert_eq!(queue.capacity(), 0);
+        assert!(queue.is_empty());
+        assert!(queue.is_full());
+
+        // Repeat to make sure that failed operations leave the queue consistent.
+        for _ in 0..3 {
+            assert!(queue.push_back("a".to_string()).is_err());
+            assert!(queue.push_front("b".to_string()).is_err());
+            assert_eq!(queue.push_back_or_return("c".to_string()), Err("c".to_string()));
+            assert!(queue.extend_back(["d".to_string()]).is_err());
+            assert_eq!(queue.pop_front(), None);
+            assert_eq!(queue.pop_back(), None);
+            assert_eq!(queue.front(), None);
+            assert_eq!(queue.back(), None);
+            assert_eq!(queue.get(0), None);
+            assert_eq!(queue.len(), 0);
+            assert!(queue.is_full());
+        }
+
+        assert!(queue.extend_back([]).is_ok());
+        assert_eq!(queue.iter().next(), None);
+        assert_eq!(queue.iter_mut().next(), None);
+        assert_eq!(queue.as_slices(), (&[][..], &[][..]));
+        assert_eq!(queue.drain().next(), None);
+        queue.rotate_left(0);
+        queue.rotate_right(0);
+        queue.truncate_back(0);
+        queue.truncate_front(0);
+        queue.retain(|_| true);
+        queue.clear();
+        let other = queue.split_off(0);
+        assert!(other.is_full());
+        assert_eq!(queue.capacity(), 0);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn try_reserve_empty() {
+        let mut queue = FixedCapacityQueue::<u8>::new(0);
+        assert!(queue.try_reserve(5).is_ok());
+        assert_eq!(queue.capacity(), 5);
+        queue.push_back(1).unwrap();
+        assert_eq!(queue.pop_front(), Some(1));
+    }
+
+    #[test]
+    fn split_off() {
+        for capacity in 0..6 {
+            for offset in 0..capacity {
+                for len in 0..=capacity {
+                    for at in 0..=len {
+                        let mut queue = FixedCapacityQueue::<String>::new(capacity);
+                        let mut control = VecDeque::new();
+                        // Move the front of the queue, so that the contents wrap around the end of the storage
+                        for _ in 0..offset {
+                            queue.push_back(String::new()).unwrap();
+                            queue.pop_front();
+                        }
+                        for i in 0..len {
+                            queue.push_back(i.to_string()).unwrap();
+                            control.push_back(i.to_string());
+                        }
+
+                        let other = queue.split_off(at);
+                        let control_other = control.split_off(at);
+                        assert!(queue.iter().eq(control.iter()));
+                        assert!(other.iter().eq(control_other.iter()));
+                        assert_eq!(queue.capacity(), capacity);
+                        assert_eq!(other.capacity(), len - at);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn split_off_drops_once() {
+        let rc = Rc::new(());
+        let mut queue = FixedCapacityQueue::new(4);
+        for _ in 0..4 {
+            queue.push_back(rc.clone()).unwrap();
+        }
+        let other = queue.split_off(1);
+        assert_eq!(Rc::strong_count(&rc), 5);
+        drop(queue);
+        assert_eq!(Rc::strong_count(&rc), 4);
+        drop(other);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "`at` out of bounds")]
+    fn split_off_out_of_bounds() {
+        let mut queue = FixedCapacityQueue::<u8>::new(4);
+        queue.push_back(1).unwrap();
+        let _ = queue.split_off(2);
+    }
+
     #[test]
     fn front_and_back() {
         fn check_front_and_back(queue: &mut FixedCapacityQueue<i64>, control: &mut VecDeque<i64>) {
@@ -240,4 +465,47 @@ mod tests {
             run_test(i);
         }
     }
+
+    #[test]
+    fn try_from_iter() {
+        for n in 0..6 {
+            let queue = FixedCapacityQueue::try_from_iter(n, 0..n as i64).unwrap();
+            assert_eq!(queue.capacity(), n);
+            assert!(queue.is_full());
+            assert!(queue.iter().copied().eq(0..n as i64));
+
+            let queue = FixedCapacityQueue::try_from_iter(n + 1, 0..n as i64).unwrap();
+            assert_eq!(queue.len(), n);
+            assert!(queue.iter().copied().eq(0..n as i64));
+
+            let result = FixedCapacityQueue::try_from_iter(n, 0..n as i64 + 1);
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn heap_queue() {
+        let mut queue = HeapQueue::<i64>::with_capacity(3);
+        assert_eq!(queue.capacity(), 3);
+        assert!(queue.is_empty());
+
+        queue.push_back(1).unwrap();
+        queue.push_back(2).unwrap();
+        queue.push_front(0).unwrap();
+        assert!(queue.push_back(3).is_err());
+        assert_eq!(queue.pop_front(), Some(0));
+        assert_eq!(queue.pop_back(), Some(2));
+        assert_eq!(queue.pop_back(), Some(1));
+        assert_eq!(queue.pop_front(), None);
+    }
+
+    #[test]
+    fn debug() {
+        let mut queue = FixedCapacityQueue::<i64>::new(3);
+        assert_eq!(format!("{:?}", queue), "[]");
+        queue.push_back(2).unwrap();
+        queue.push_back(3).unwrap();
+        queue.push_front(1).unwrap();
+        assert_eq!(format!("{:?}", queue), "[1, 2, 3]");
+    }
 }
@@ -0,0 +1,105 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use core::ops;
+
+use crate::generic::spsc_queue::SpscQueue;
+use crate::storage::Heap;
+
+/// A fixed-capacity, wait-free single-producer/single-consumer queue.
+///
+/// Call [`split()`](SpscQueue::split) to obtain a producer and a consumer handle,
+/// which can be used from two different threads without any locking.
+///
+/// # Example
+///
+/// ```
+/// use containers::fixed_capacity::FixedCapacitySpscQueue;
+///
+/// let mut queue = FixedCapacitySpscQueue::new(16);
+/// let (mut producer, mut consumer) = queue.split();
+/// std::thread::scope(|scope| {
+///     scope.spawn(move || producer.try_push(42).unwrap());
+/// });
+/// assert_eq!(consumer.try_pop(), Some(42));
+/// ```
+pub struct FixedCapacitySpscQueue<T> {
+    inner: SpscQueue<T, Heap<T>>,
+}
+
+impl<T> FixedCapacitySpscQueue<T> {
+    /// Creates an empty queue and allocates memory for up to `capacity` elements, where `capacity <= u32::MAX / 2`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `capacity > u32::MAX / 2`.
+    /// - Panics if the memory allocation fails.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        assert!(
+            capacity <= (u32::MAX / 2) as usize,
+            "FixedCapacitySpscQueue can hold at most u32::MAX / 2 elements"
+        );
+        Self {
+            inner: SpscQueue::new(capacity as u32),
+        }
+    }
+}
+
+impl<T> Drop for FixedCapacitySpscQueue<T> {
+    fn drop(&mut self) {
+        self.inner.clear();
+    }
+}
+
+impl<T> ops::Deref for FixedCapacitySpscQueue<T> {
+    type Target = SpscQueue<T, Heap<T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> ops::DerefMut for FixedCapacitySpscQueue<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+
+    #[test]
+    fn drop_remaining_elements() {
+        let rc = Rc::new(());
+        {
+            let mut queue = FixedCapacitySpscQueue::new(4);
+            let (mut producer, mut consumer) = queue.split();
+            for _ in 0..4 {
+                producer.try_push(rc.clone()).unwrap();
+            }
+            drop(consumer.try_pop());
+            assert_eq!(Rc::strong_count(&rc), 4);
+        }
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn capacity_too_large() {
+        let _ = FixedCapacitySpscQueue::<u8>::new(u32::MAX as usize);
+    }
+}
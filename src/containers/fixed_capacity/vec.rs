@@ -150,4 +150,28 @@ mod tests {
             run_test(i);
         }
     }
+
+    #[test]
+    fn drop_elements() {
+        use std::rc::Rc;
+
+        let value = Rc::new(0);
+        let mut vector = FixedCapacityVec::<Rc<i32>>::new(4);
+        for _ in 0..3 {
+            vector.push(value.clone()).unwrap();
+        }
+        assert_eq!(Rc::strong_count(&value), 4);
+
+        drop(vector.pop());
+        assert_eq!(Rc::strong_count(&value), 3);
+
+        vector.clear();
+        assert_eq!(Rc::strong_count(&value), 1);
+
+        // Dropping the vector drops the remaining elements exactly once
+        vector.push(value.clone()).unwrap();
+        vector.push(value.clone()).unwrap();
+        drop(vector);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
 }
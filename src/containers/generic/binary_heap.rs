@@ -0,0 +1,230 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use core::fmt;
+
+use crate::generic::vec::GenericVec;
+use crate::storage::Storage;
+use crate::InsufficientCapacity;
+
+/// A priority queue implemented as a binary max-heap, which is generic over its storage method.
+pub struct GenericHeap<T, S: Storage<T>> {
+    /// The elements of the heap; each element is greater or equal to its children at `2 * i + 1` and `2 * i + 2`.
+    vec: GenericVec<T, S>,
+}
+
+impl<T, S: Storage<T>> GenericHeap<T, S> {
+    /// Creates an empty heap with the given capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if not enough memory could be allocated.
+    pub fn new(capacity: u32) -> Self {
+        Self {
+            vec: GenericVec::new(capacity),
+        }
+    }
+
+    /// Tries to create an empty heap with the given capacity.
+    ///
+    /// Returns `None` if not enough memory could be allocated.
+    pub fn try_new(capacity: u32) -> Option<Self> {
+        Some(Self {
+            vec: GenericVec::try_new(capacity)?,
+        })
+    }
+
+    /// Extracts a slice containing all elements of the heap, in arbitrary order.
+    pub fn as_slice(&self) -> &[T] {
+        self.vec.as_slice()
+    }
+
+    /// Returns a reference to the greatest element of the heap, or `None` if the heap is empty.
+    pub fn peek(&self) -> Option<&T> {
+        self.vec.first()
+    }
+
+    /// Returns the maximum number of elements the heap can hold.
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    /// Returns the current number of elements in the heap.
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Returns `true` if and only if the heap doesn't contain any elements.
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Returns `true` if and only if the heap has reached its capacity.
+    pub fn is_full(&self) -> bool {
+        self.vec.is_full()
+    }
+
+    /// Clears the heap, removing all values.
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+}
+
+impl<T: Ord, S: Storage<T>> GenericHeap<T, S> {
+    /// Tries to push an element onto the heap.
+    ///
+    /// If the heap has spare capacity, the push succeeds; otherwise, `Err(InsufficientCapacity)` is returned.
+    /// Takes `O(log(len))` time.
+    pub fn push(&mut self, value: T) -> Result<(), InsufficientCapacity> {
+        self.vec.push(value)?;
+        self.sift_up(self.len() - 1);
+        Ok(())
+    }
+
+    /// Removes the greatest element from the heap and returns it, or `None` if the heap is empty.
+    ///
+    /// Takes `O(log(len))` time.
+    pub fn pop(&mut self) -> Option<T> {
+        let len = self.len();
+        if len > 1 {
+            self.vec.swap(0, len - 1);
+        }
+        let value = self.vec.pop()?;
+        self.sift_down(0);
+        Some(value)
+    }
+
+    /// Moves the element at `index` up, until its parent is greater or equal.
+    ///
+    /// Elements are only swapped, so a panic in `Ord::cmp` can't cause a double-drop, only a broken heap order.
+    fn sift_up(&mut self, mut index: usize) {
+        let elements = self.vec.as_mut_slice();
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if elements[index] <= elements[parent] {
+                break;
+            }
+            elements.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    /// Moves the element at `index` down, until both its children are less or equal.
+    fn sift_down(&mut self, mut index: usize) {
+        let elements = self.vec.as_mut_slice();
+        loop {
+            let left = 2 * index + 1;
+            let right = left + 1;
+            let mut greatest = index;
+            if left < elements.len() && elements[left] > elements[greatest] {
+                greatest = left;
+            }
+            if right < elements.len() && elements[right] > elements[greatest] {
+                greatest = right;
+            }
+            if greatest == index {
+                break;
+            }
+            elements.swap(index, greatest);
+            index = greatest;
+        }
+    }
+}
+
+impl<T: fmt::Debug, S: Storage<T>> fmt::Debug for GenericHeap<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BinaryHeap;
+    use std::mem::MaybeUninit;
+
+    use super::*;
+
+    /// Simple xorshift generator, to get reproducible pseudo-random inputs.
+    fn random_values(seed: u64, count: usize) -> Vec<i64> {
+        let mut state = seed;
+        (0..count)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                // Narrow range, so that there are duplicates
+                (state % 32) as i64
+            })
+            .collect()
+    }
+
+    #[test]
+    fn push_and_pop() {
+        fn run_test(n: usize) {
+            let mut heap = GenericHeap::<i64, Vec<MaybeUninit<i64>>>::new(n as u32);
+            let mut control = BinaryHeap::new();
+
+            for seed in 1..20 {
+                assert_eq!(heap.pop(), None);
+                assert_eq!(heap.peek(), None);
+
+                for value in random_values(seed, n) {
+                    assert!(heap.push(value).is_ok());
+                    control.push(value);
+                    assert_eq!(heap.peek(), control.peek());
+                    assert_eq!(heap.len(), control.len());
+                }
+                assert!(heap.push(123456).is_err());
+
+                // Interleave pushes with pops
+                for value in random_values(seed + 100, n / 2) {
+                    assert_eq!(heap.pop(), control.pop());
+                    assert!(heap.push(value).is_ok());
+                    control.push(value);
+                    assert_eq!(heap.peek(), control.peek());
+                }
+
+                while let Some(expected) = control.pop() {
+                    assert_eq!(heap.pop(), Some(expected));
+                }
+                assert!(heap.is_empty());
+            }
+        }
+
+        for i in 0..10 {
+            run_test(i);
+        }
+        run_test(100);
+    }
+
+    #[test]
+    fn is_full_and_is_empty() {
+        let mut heap = GenericHeap::<i64, Vec<MaybeUninit<i64>>>::new(3);
+        assert!(heap.is_empty());
+        assert_eq!(heap.capacity(), 3);
+        for value in [2, 7, 5] {
+            assert!(!heap.is_full());
+            heap.push(value).unwrap();
+            assert!(!heap.is_empty());
+        }
+        assert!(heap.is_full());
+
+        let mut elements = heap.as_slice().to_vec();
+        elements.sort();
+        assert_eq!(elements, [2, 5, 7]);
+
+        heap.clear();
+        assert!(heap.is_empty());
+        assert_eq!(heap.peek(), None);
+    }
+}
@@ -11,6 +11,8 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
+pub(crate) mod binary_heap;
 pub(crate) mod queue;
+pub(crate) mod spsc_queue;
 pub(crate) mod string;
 pub(crate) mod vec;
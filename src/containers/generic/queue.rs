@@ -11,14 +11,15 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
+use core::fmt;
 use core::iter::FusedIterator;
 use core::marker::PhantomData;
-use core::mem::needs_drop;
+use core::mem::{needs_drop, MaybeUninit};
 use core::ops::Range;
 use core::ptr;
 use core::slice;
 
-use crate::storage::Storage;
+use crate::storage::{GrowableStorage, Storage};
 use crate::InsufficientCapacity;
 
 #[repr(C)]
@@ -34,6 +35,10 @@ pub struct GenericQueue<T, S: Storage<T>> {
 
 impl<T, S: Storage<T>> GenericQueue<T, S> {
     /// Creates an empty queue.
+    ///
+    /// # Panics
+    ///
+    /// Panics if not enough memory could be allocated.
     pub fn new(capacity: u32) -> Self {
         Self {
             len: 0,
@@ -43,6 +48,32 @@ impl<T, S: Storage<T>> GenericQueue<T, S> {
         }
     }
 
+    /// Tries to create an empty queue with the given capacity.
+    ///
+    /// Returns `None` if not enough memory could be allocated.
+    pub fn try_new(capacity: u32) -> Option<Self> {
+        Some(Self {
+            len: 0,
+            front_index: 0,
+            storage: S::try_new(capacity)?,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Creates a full queue from the given storage, with the front at index 0.
+    ///
+    /// # Safety
+    ///
+    /// All `storage.capacity()` elements of the storage must be initialized.
+    pub(crate) unsafe fn from_full_storage(storage: S) -> Self {
+        Self {
+            len: storage.capacity(),
+            front_index: 0,
+            storage,
+            _marker: PhantomData,
+        }
+    }
+
     /// Extracts the slices containing the entire queue contents, in order.
     ///
     /// The caller should not make any assumptions about the distribution of the elements between
@@ -84,6 +115,15 @@ impl<T, S: Storage<T>> GenericQueue<T, S> {
         (first, second)
     }
 
+    /// Returns `true` if the queue contents are stored in a single contiguous slice,
+    /// i.e., if the second slice returned by [`as_slices()`](Self::as_slices) is empty.
+    ///
+    /// Empty queues are always contiguous.
+    pub fn is_contiguous(&self) -> bool {
+        let (_, second) = self.slice_ranges();
+        second.is_empty()
+    }
+
     /// Returns a reference to the front of the queue (the element which would be returned by [`pop_front()`](Self::pop_front)),
     /// or None if the queue is empty.
     pub fn front(&self) -> Option<&T> {
@@ -124,6 +164,79 @@ impl<T, S: Storage<T>> GenericQueue<T, S> {
         })
     }
 
+    /// Returns a reference to the element at the given logical index, counted from the front of the queue,
+    /// or None if the index is out of bounds.
+    ///
+    /// Index 0 refers to the element returned by [`front()`](Self::front).
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.physical_index(index).map(|index| {
+            // SAFETY: self.physical_index() returned Some(), therefore index points to a valid (initialized) slot in the storage
+            unsafe { self.storage.element(index).assume_init_ref() }
+        })
+    }
+
+    /// Returns a mutable reference to the element at the given logical index, counted from the front of the queue,
+    /// or None if the index is out of bounds.
+    ///
+    /// Index 0 refers to the element returned by [`front_mut()`](Self::front_mut).
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.physical_index(index).map(|index| {
+            // SAFETY: self.physical_index() returned Some(), therefore index points to a valid (initialized) slot in the storage
+            unsafe { self.storage.element_mut(index).assume_init_mut() }
+        })
+    }
+
+    /// Swaps the elements at the logical indices `i` and `j`, counted from the front of the queue.
+    ///
+    /// `i` and `j` may be equal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds, like [`VecDeque::swap`](alloc::collections::VecDeque::swap).
+    pub fn swap(&mut self, i: usize, j: usize) {
+        let len = self.len();
+        let (Some(i), Some(j)) = (self.physical_index(i), self.physical_index(j)) else {
+            panic!("swap indices out of bounds: the len is {len} but the indices are {i} and {j}");
+        };
+        // SAFETY: self.physical_index() returned Some() for both, therefore they point to valid (initialized) slots
+        // in the storage; ptr::swap allows the two slots to be the same
+        unsafe {
+            let a = self.storage.subslice_mut(i, i + 1).cast::<T>();
+            let b = self.storage.subslice_mut(j, j + 1).cast::<T>();
+            ptr::swap(a, b);
+        }
+    }
+
+    /// Returns a reference to the `n`-th element counted from the front of the queue,
+    /// or None if `n >= self.len()`.
+    ///
+    /// `peek_nth(0)` is equivalent to [`front()`](Self::front). This is the same as [`get()`](Self::get).
+    pub fn peek_nth(&self, n: usize) -> Option<&T> {
+        self.get(n)
+    }
+
+    /// Returns a reference to the last element of the queue, or None if the queue is empty.
+    ///
+    /// This is the same as [`back()`](Self::back).
+    pub fn last(&self) -> Option<&T> {
+        self.back()
+    }
+
+    /// Returns `true` if the queue contains an element equal to the given value.
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let (first, second) = self.as_slices();
+        first.contains(x) || second.contains(x)
+    }
+
+    /// Returns the logical index, counted from the front of the queue, of the first element matching the predicate,
+    /// or `None` if no element matches.
+    pub fn position<P: FnMut(&T) -> bool>(&self, pred: P) -> Option<usize> {
+        self.iter().position(pred)
+    }
+
     /// Returns a front-to-back iterator over the elements.
     pub fn iter(&self) -> Iter<'_, T> {
         let (first, second) = self.as_slices();
@@ -142,6 +255,14 @@ impl<T, S: Storage<T>> GenericQueue<T, S> {
         }
     }
 
+    /// Returns a front-to-back iterator removing the elements from the queue.
+    ///
+    /// The queue is empty once the iterator is dropped, even if it wasn't fully consumed.
+    /// The capacity of the queue is not affected.
+    pub fn drain(&mut self) -> Drain<'_, T, S> {
+        Drain { queue: self }
+    }
+
     /// Returns the maximum number of elements the queue can hold.
     pub fn capacity(&self) -> usize {
         self.storage.capacity() as usize
@@ -167,6 +288,14 @@ impl<T, S: Storage<T>> GenericQueue<T, S> {
     /// If the queue has spare capacity, the push succeeds and a reference to that element
     /// is returned; otherwise, `Err(InsufficientCapacity)` is returned.
     pub fn push_back(&mut self, value: T) -> Result<&mut T, InsufficientCapacity> {
+        self.push_back_or_return(value).map_err(|_| InsufficientCapacity)
+    }
+
+    /// Tries to push an element to the back of the queue.
+    ///
+    /// If the queue has spare capacity, the push succeeds and a reference to that element
+    /// is returned; otherwise, the element is handed back as `Err(value)`, so it isn't dropped.
+    pub fn push_back_or_return(&mut self, value: T) -> Result<&mut T, T> {
         let capacity = self.storage.capacity();
         if self.len < capacity {
             let write_pos = self.front_index as u64 + self.len as u64;
@@ -178,8 +307,57 @@ impl<T, S: Storage<T>> GenericQueue<T, S> {
             self.len += 1;
             Ok(unsafe { self.storage.element_mut(write_pos).write(value) })
         } else {
-            Err(InsufficientCapacity)
+            Err(value)
+        }
+    }
+
+    /// Pushes the elements of the iterator to the back of the queue, in order.
+    ///
+    /// Stops at the first element which doesn't fit into the queue and returns `Err(InsufficientCapacity)`;
+    /// that element is dropped, while the elements pushed before it remain in the queue.
+    pub fn extend_back<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), InsufficientCapacity> {
+        for value in iter {
+            self.push_back(value)?;
+        }
+        Ok(())
+    }
+
+    /// Resizes the queue in place so that its length is `new_len`,
+    /// like [`VecDeque::resize_with`](alloc::collections::VecDeque::resize_with).
+    ///
+    /// If `new_len` is greater than the current length, the queue is extended at the back with elements generated by `f`;
+    /// otherwise, the queue is truncated at the back, dropping the removed elements.
+    ///
+    /// Returns `Err(InsufficientCapacity)` if `new_len` exceeds the capacity of the queue; the queue is left unchanged in that case.
+    pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, mut f: F) -> Result<(), InsufficientCapacity> {
+        if new_len > self.capacity() {
+            return Err(InsufficientCapacity);
         }
+        if new_len <= self.len() {
+            self.truncate_back(new_len);
+        } else {
+            // Each element is counted as soon as it's pushed, so a panic in `f` leaves the queue consistent
+            while self.len() < new_len {
+                let result = self.push_back_or_return(f());
+                debug_assert!(result.is_ok());
+            }
+        }
+        Ok(())
+    }
+
+    /// Moves all elements of `other` to the back of the queue, in order, leaving `other` empty.
+    ///
+    /// If the queue doesn't have enough spare capacity for all elements of `other`,
+    /// `Err(InsufficientCapacity)` is returned and neither queue is modified.
+    pub fn append<S2: Storage<T>>(&mut self, other: &mut GenericQueue<T, S2>) -> Result<(), InsufficientCapacity> {
+        if other.len > self.storage.capacity() - self.len {
+            return Err(InsufficientCapacity);
+        }
+        while let Some(value) = other.pop_front() {
+            let result = self.push_back_or_return(value);
+            debug_assert!(result.is_ok());
+        }
+        Ok(())
     }
 
     /// Tries to push an element to the front of the queue.
@@ -221,6 +399,31 @@ impl<T, S: Storage<T>> GenericQueue<T, S> {
         }
     }
 
+    /// Moves up to `out.len()` elements from the front of the queue into `out`, in order.
+    ///
+    /// Returns the number of moved elements `count`. Afterwards, `out[..count]` is initialized with these elements,
+    /// and the caller is responsible for dropping them; the rest of `out` is left untouched.
+    pub fn pop_front_n(&mut self, out: &mut [MaybeUninit<T>]) -> usize {
+        let count = out.len().min(self.len());
+        let (first, second) = self.slice_ranges();
+        let first_count = count.min((first.end - first.start) as usize);
+        let second_count = count - first_count;
+        // SAFETY:
+        // - both ranges are in bounds of the storage, and their first `count` elements are initialized
+        // - `out` is a different allocation than the storage, so the regions don't overlap
+        unsafe {
+            let dst = out.as_mut_ptr().cast::<T>();
+            let src = self.storage.subslice(first.start, first.end).cast::<T>();
+            ptr::copy_nonoverlapping(src, dst, first_count);
+            let src = self.storage.subslice(second.start, second.end).cast::<T>();
+            ptr::copy_nonoverlapping(src, dst.add(first_count), second_count);
+        }
+        // The moved elements are owned by `out` now, so their slots are no longer part of the queue
+        self.front_index = self.physical_index(count).unwrap_or(0);
+        self.len -= count as u32;
+        count
+    }
+
     /// Tries to pop an element from the back of the queue.
     ///
     /// If the queue has at least one element, the pop succeeds; otherwise, `None` is returned.
@@ -233,12 +436,156 @@ impl<T, S: Storage<T>> GenericQueue<T, S> {
         }
     }
 
+    /// Shortens the queue to `len` elements, dropping the elements at the back.
+    ///
+    /// Has no effect if the queue has `len` or fewer elements.
+    pub fn truncate_back(&mut self, len: usize) {
+        if len >= self.len() {
+            return;
+        }
+        let (first, second) = self.slice_ranges();
+        let first_len = (first.end - first.start) as usize;
+        // Compute ranges of the elements to drop; `len < self.len <= u32::MAX`, so the casts are lossless
+        let (first_drop, second_drop) = if len < first_len {
+            (first.start + len as u32..first.end, second)
+        } else {
+            (
+                first.end..first.end,
+                second.start + (len - first_len) as u32..second.end,
+            )
+        };
+        // Shorten the queue before dropping elements, to prevent double-drop in case there's a panic in drop_in_place
+        self.len = len as u32;
+        self.drop_ranges(first_drop, second_drop);
+    }
+
+    /// Shortens the queue to `len` elements, dropping the elements at the front.
+    ///
+    /// Has no effect if the queue has `len` or fewer elements.
+    pub fn truncate_front(&mut self, len: usize) {
+        if len >= self.len() {
+            return;
+        }
+        let count = self.len() - len;
+        let (first, second) = self.slice_ranges();
+        let first_len = (first.end - first.start) as usize;
+        // Compute ranges of the elements to drop; `count <= self.len <= u32::MAX`, so the casts are lossless
+        let (first_drop, second_drop) = if count <= first_len {
+            (first.start..first.start + count as u32, second.start..second.start)
+        } else {
+            (first, second.start..second.start + (count - first_len) as u32)
+        };
+        // Shorten the queue before dropping elements, to prevent double-drop in case there's a panic in drop_in_place
+        self.front_index = self.physical_index(count).unwrap_or(0);
+        self.len = len as u32;
+        self.drop_ranges(first_drop, second_drop);
+    }
+
+    /// Rotates the queue `mid` places to the left, so that the element at index `mid` becomes the front.
+    ///
+    /// Takes `O(1)` time if the queue is full, since only the start point of the ring moves;
+    /// otherwise, `min(mid, len - mid)` elements are moved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > self.len()`, like [`VecDeque::rotate_left`](alloc::collections::VecDeque::rotate_left).
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.len(), "rotation point out of bounds");
+        let k = self.len() - mid;
+        if mid == 0 || k == 0 {
+            // Rotation by zero or by the whole length doesn't change the order
+            return;
+        }
+        if self.is_full() {
+            self.front_index = self.physical_index(mid).unwrap_or(0);
+        } else if mid <= k {
+            self.move_front_to_back(mid);
+        } else {
+            self.move_back_to_front(k);
+        }
+    }
+
+    /// Rotates the queue `k` places to the right, so that the element at index `len - k` becomes the front.
+    ///
+    /// Takes `O(1)` time if the queue is full, since only the start point of the ring moves;
+    /// otherwise, `min(k, len - k)` elements are moved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k > self.len()`, like [`VecDeque::rotate_right`](alloc::collections::VecDeque::rotate_right).
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.len(), "rotation point out of bounds");
+        self.rotate_left(self.len() - k);
+    }
+
+    /// Moves `count` elements from the front to the back, one by one.
+    ///
+    /// Requires spare capacity, so that each push succeeds.
+    fn move_front_to_back(&mut self, count: usize) {
+        for _ in 0..count {
+            if let Some(value) = self.pop_front() {
+                let _ = self.push_back(value);
+            }
+        }
+    }
+
+    /// Moves `count` elements from the back to the front, one by one.
+    ///
+    /// Requires spare capacity, so that each push succeeds.
+    fn move_back_to_front(&mut self, count: usize) {
+        for _ in 0..count {
+            if let Some(value) = self.pop_back() {
+                let _ = self.push_front(value);
+            }
+        }
+    }
+
     /// Clears the queue, removing all values.
     pub fn clear(&mut self) {
         let (first, second) = self.slice_ranges();
         // Mark queue as empty before dropping elements, to prevent double-drop in case there's a panic in drop_in_place
         self.len = 0;
         self.front_index = 0;
+        self.drop_ranges(first, second);
+    }
+
+    /// Retains only the elements for which the predicate returns `true`, dropping the others.
+    ///
+    /// The elements are visited front-to-back, and the order of the retained elements is preserved.
+    /// If the predicate panics, no element is dropped twice or leaked, but the order of the remaining elements
+    /// is unspecified.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        // Rotate the queue once, pushing the retained elements back in their original order.
+        // The queue stays consistent at every step, so a panic can't cause a double-drop.
+        for _ in 0..self.len {
+            // SAFETY: the loop runs at most self.len times and each iteration removes at most one element,
+            // so the queue isn't empty here
+            let keep = f(unsafe { self.front().unwrap_unchecked() });
+            let value = unsafe { self.pop_front().unwrap_unchecked() };
+            if keep {
+                // Can't fail, because an element has just been popped
+                let _ = self.push_back(value);
+            }
+        }
+    }
+
+    /// Returns a front-to-back iterator which removes and yields the elements for which the predicate returns `true`.
+    ///
+    /// The order of the remaining elements is preserved. If the iterator is dropped before it is exhausted,
+    /// the elements which weren't visited yet are kept, like in
+    /// [`Vec::extract_if`](alloc::vec::Vec::extract_if). If the predicate panics, no element is dropped twice
+    /// or leaked, and the element which was being visited is kept.
+    pub fn extract_if<F: FnMut(&T) -> bool>(&mut self, pred: F) -> ExtractIf<'_, T, S, F> {
+        let remaining = self.len;
+        ExtractIf {
+            queue: self,
+            pred,
+            remaining,
+        }
+    }
+
+    /// Drops the elements in the two storage ranges, which must not be part of the queue anymore.
+    fn drop_ranges(&mut self, first: Range<u32>, second: Range<u32>) {
         if needs_drop::<T>() {
             unsafe {
                 ptr::drop_in_place(self.storage.subslice_mut(first.start, first.end));
@@ -261,6 +608,23 @@ impl<T, S: Storage<T>> GenericQueue<T, S> {
         }
     }
 
+    /// Translates a logical index, counted from the front of the queue, into an index in the storage,
+    /// or `None` if the index is out of bounds.
+    fn physical_index(&self, index: usize) -> Option<u32> {
+        if index < self.len() {
+            // Cast to u64 to avoid overflow
+            let capacity = self.storage.capacity() as u64;
+            let pos = self.front_index as u64 + index as u64;
+            if pos < capacity {
+                Some(pos as u32)
+            } else {
+                Some((pos - capacity) as u32)
+            }
+        } else {
+            None
+        }
+    }
+
     /// Returns the index of the last element (the one which would be returned by [`pop_back()`](Self::pop_back)),
     /// or `None` if the queue is empty.
     fn back_index(&self) -> Option<u32> {
@@ -278,6 +642,83 @@ impl<T, S: Storage<T>> GenericQueue<T, S> {
     }
 }
 
+impl<T, S: GrowableStorage<T>> GenericQueue<T, S> {
+    /// Tries to reserve capacity for at least `additional` more elements.
+    ///
+    /// The storage is reallocated if the current capacity is insufficient, growing to at least twice the current capacity.
+    /// After growing, the elements are stored contiguously, starting at the beginning of the storage.
+    ///
+    /// Returns `Err(InsufficientCapacity)` if the required capacity exceeds `u32::MAX` or the allocation failed;
+    /// the queue is left unchanged in that case.
+    pub fn try_reserve(&mut self, additional: u32) -> Result<(), InsufficientCapacity> {
+        let capacity = self.storage.capacity();
+        let required = self.len.checked_add(additional).ok_or(InsufficientCapacity)?;
+        if required <= capacity {
+            return Ok(());
+        }
+
+        let new_capacity = required.max(capacity.saturating_mul(2));
+        // SAFETY: `new_capacity >= required > capacity`
+        if !unsafe { self.storage.try_grow(new_capacity) } {
+            return Err(InsufficientCapacity);
+        }
+
+        // Rotate the old capacity range, so that the front element moves to index 0.
+        // Uninitialized elements are only moved, never read.
+        // SAFETY: `0 <= capacity <= self.storage.capacity()`
+        let elements = unsafe { self.storage.subslice_mut(0, capacity) };
+        // SAFETY: `MaybeUninit<T>` has the same memory layout as `T`, and `elements` is valid for reads and writes
+        let elements = unsafe { &mut *(elements as *mut [MaybeUninit<T>]) };
+        elements.rotate_left(self.front_index as usize);
+        self.front_index = 0;
+        Ok(())
+    }
+
+    /// Splits the queue into two at the given index.
+    ///
+    /// Returns a new queue containing the elements `[at, len)`, in order, and leaves `self` with the elements `[0, at)`.
+    /// The new queue has a capacity of exactly `len - at`; the capacity of `self` is unchanged.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `at > len`.
+    /// - Panics if the memory allocation fails.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.len(), "`at` out of bounds");
+        let mut other = Self::new(self.len - at as u32);
+        // Each element is owned by exactly one of the queues at all times, so nothing is dropped or duplicated.
+        while self.len() > at {
+            if let Some(value) = self.pop_back() {
+                let result = other.push_front(value);
+                debug_assert!(result.is_ok());
+            }
+        }
+        other
+    }
+}
+
+impl<T: Clone, S: Storage<T>> Clone for GenericQueue<T, S> {
+    /// Creates a queue with the same capacity and a clone of each element.
+    ///
+    /// The elements of the new queue are stored starting at the beginning of the storage.
+    fn clone(&self) -> Self {
+        let mut clone = Self::new(self.storage.capacity());
+        for value in self.iter() {
+            // SAFETY: clone.len < self.len <= capacity, so the index is in bounds and points to an uninitialized slot
+            unsafe { clone.storage.element_mut(clone.len).write(value.clone()) };
+            // Only count the element once it's written, in case `value.clone()` panics
+            clone.len += 1;
+        }
+        clone
+    }
+}
+
+impl<T: fmt::Debug, S: Storage<T>> fmt::Debug for GenericQueue<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
 pub struct Iter<'a, T> {
     first: slice::Iter<'a, T>,
     second: slice::Iter<'a, T>,
@@ -380,6 +821,86 @@ impl<'a, T> ExactSizeIterator for IterMut<'a, T> {
 
 impl<T> FusedIterator for IterMut<'_, T> {}
 
+pub struct Drain<'a, T, S: Storage<T>> {
+    queue: &'a mut GenericQueue<T, S>,
+}
+
+impl<T, S: Storage<T>> Iterator for Drain<'_, T, S> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.queue.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, S: Storage<T>> DoubleEndedIterator for Drain<'_, T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.queue.pop_back()
+    }
+}
+
+impl<T, S: Storage<T>> ExactSizeIterator for Drain<'_, T, S> {
+    fn len(&self) -> usize {
+        self.queue.len()
+    }
+}
+
+impl<T, S: Storage<T>> FusedIterator for Drain<'_, T, S> {}
+
+impl<T, S: Storage<T>> Drop for Drain<'_, T, S> {
+    fn drop(&mut self) {
+        // Drop the elements which weren't consumed
+        self.queue.clear();
+    }
+}
+
+pub struct ExtractIf<'a, T, S: Storage<T>, F: FnMut(&T) -> bool> {
+    queue: &'a mut GenericQueue<T, S>,
+    pred: F,
+    /// Number of elements at the front of the queue which weren't visited yet.
+    /// The visited elements which were kept are at the back of the queue, in order.
+    remaining: u32,
+}
+
+impl<T, S: Storage<T>, F: FnMut(&T) -> bool> Iterator for ExtractIf<'_, T, S, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Rotate the unvisited elements to the back one by one, like in retain(), so that the queue
+        // stays consistent at every step
+        while self.remaining > 0 {
+            // SAFETY: the unvisited elements are at the front of the queue, so the queue isn't empty here
+            let extract = (self.pred)(unsafe { self.queue.front().unwrap_unchecked() });
+            let value = unsafe { self.queue.pop_front().unwrap_unchecked() };
+            self.remaining -= 1;
+            if extract {
+                return Some(value);
+            }
+            // Can't fail, because an element has just been popped
+            let _ = self.queue.push_back(value);
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining as usize))
+    }
+}
+
+impl<T, S: Storage<T>, F: FnMut(&T) -> bool> FusedIterator for ExtractIf<'_, T, S, F> {}
+
+impl<T, S: Storage<T>, F: FnMut(&T) -> bool> Drop for ExtractIf<'_, T, S, F> {
+    fn drop(&mut self) {
+        // Keep the elements which weren't visited, moving them behind the visited ones to restore the order
+        self.queue.move_front_to_back(self.remaining as usize);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{collections::VecDeque, mem::MaybeUninit};
@@ -435,6 +956,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn get_and_get_mut() {
+        fn check_get(queue: &mut GenericQueue<i64, Vec<MaybeUninit<i64>>>, control: &mut VecDeque<i64>) {
+            assert_eq!(queue.get(0), queue.front());
+            for i in 0..queue.capacity() + 2 {
+                assert_eq!(queue.get(i), control.get(i));
+                assert_eq!(queue.get_mut(i), control.get_mut(i));
+            }
+            assert_eq!(queue.get(usize::MAX), None);
+        }
+
+        fn run_test(n: usize) {
+            let mut queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::new(n as u32);
+            let mut control = VecDeque::new();
+
+            // Completely fill and empty the queue n times, but move the internal start point
+            // ahead by one each time
+            for _ in 0..n {
+                check_get(&mut queue, &mut control);
+
+                for i in 0..n {
+                    let value = i as i64 * 123 + 456;
+                    queue.push_back(value).unwrap();
+                    control.push_back(value);
+                    check_get(&mut queue, &mut control);
+                }
+
+                // Modify elements through `get_mut`
+                for i in 0..n {
+                    *queue.get_mut(i).unwrap() += 1;
+                    *control.get_mut(i).unwrap() += 1;
+                }
+                check_get(&mut queue, &mut control);
+
+                for _ in 0..n {
+                    control.pop_front().unwrap();
+                    queue.pop_front().unwrap();
+                    check_get(&mut queue, &mut control);
+                }
+
+                // One push and one pop to move the internal start point ahead
+                queue.push_back(987).unwrap();
+                queue.pop_front().unwrap();
+                check_get(&mut queue, &mut control);
+            }
+        }
+
+        for i in 0..6 {
+            run_test(i);
+        }
+    }
+
     #[test]
     fn iter() {
         fn check_iter(queue: &mut GenericQueue<i64, Vec<MaybeUninit<i64>>>, control: &mut VecDeque<i64>) {
@@ -533,19 +1106,161 @@ mod tests {
     }
 
     #[test]
-    fn push_front_and_pop_back() {
+    fn push_back_or_return() {
+        let mut queue = GenericQueue::<String, Vec<MaybeUninit<String>>>::new(2);
+        assert_eq!(
+            queue.push_back_or_return("a".to_string()).map(|s| s.clone()),
+            Ok("a".to_string())
+        );
+        assert!(queue.push_back_or_return("b".to_string()).is_ok());
+
+        // Full queue hands the value back instead of dropping it.
+        let rejected = queue.push_back_or_return("c".to_string()).unwrap_err();
+        assert_eq!(rejected, "c");
+
+        // Retry with the recovered value after making room.
+        assert_eq!(queue.pop_front().as_deref(), Some("a"));
+        assert_eq!(*queue.push_back_or_return(rejected).unwrap(), "c");
+        assert_eq!(queue.pop_front().as_deref(), Some("b"));
+        assert_eq!(queue.pop_front().as_deref(), Some("c"));
+
+        let mut queue = GenericQueue::<String, Vec<MaybeUninit<String>>>::new(0);
+        assert_eq!(queue.push_back_or_return("d".to_string()), Err("d".to_string()));
+    }
+
+    #[test]
+    fn append() {
+        fn queue_from(capacity: u32, values: &[&str]) -> GenericQueue<String, Vec<MaybeUninit<String>>> {
+            let mut queue = GenericQueue::new(capacity);
+            // Move the internal start point, so that the elements wrap around.
+            for _ in 0..capacity / 2 {
+                queue.push_back(String::new()).unwrap();
+                queue.pop_front().unwrap();
+            }
+            for value in values {
+                queue.push_back(value.to_string()).unwrap();
+            }
+            queue
+        }
+
+        fn contents<S: Storage<String>>(queue: &GenericQueue<String, S>) -> Vec<String> {
+            queue.iter().cloned().collect()
+        }
+
+        // Fitting merge
+        let mut queue = queue_from(5, &["a", "b"]);
+        let mut other = queue_from(3, &["c", "d", "e"]);
+        assert!(queue.append(&mut other).is_ok());
+        assert_eq!(contents(&queue), ["a", "b", "c", "d", "e"]);
+        assert!(other.is_empty());
+
+        // Appending an empty queue
+        assert!(queue.append(&mut other).is_ok());
+        assert_eq!(contents(&queue), ["a", "b", "c", "d", "e"]);
+
+        // Non-fitting merge leaves both queues untouched
+        let mut queue = queue_from(4, &["a", "b"]);
+        let mut other = queue_from(4, &["c", "d", "e"]);
+        assert!(queue.append(&mut other).is_err());
+        assert_eq!(contents(&queue), ["a", "b"]);
+        assert_eq!(contents(&other), ["c", "d", "e"]);
+
+        // Both queues are still usable
+        other.pop_back().unwrap();
+        assert!(queue.append(&mut other).is_ok());
+        assert_eq!(contents(&queue), ["a", "b", "c", "d"]);
+        assert!(other.is_empty());
+    }
+
+    #[test]
+    fn pop_front_n() {
         fn run_test(n: usize) {
             let mut queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::new(n as u32);
             let mut control = VecDeque::new();
 
-            // Completely fill and empty the queue n times, but move the internal start point
-            // ahead by one each time
+            // Fill and empty the queue n times, but move the internal start point ahead by one each time
             for _ in 0..n {
-                let result = queue.pop_back();
-                assert_eq!(result, None);
+                for out_len in 0..=n + 1 {
+                    for i in 0..n {
+                        let value = i as i64 * 123 + 456;
+                        queue.push_back(value).unwrap();
+                        control.push_back(value);
+                    }
+
+                    let mut out = vec![MaybeUninit::uninit(); out_len];
+                    let count = queue.pop_front_n(&mut out);
+                    let expected: Vec<i64> = control.drain(..out_len.min(control.len())).collect();
+                    assert_eq!(count, expected.len());
+                    let popped: Vec<i64> = out[..count].iter().map(|v| unsafe { v.assume_init() }).collect();
+                    assert_eq!(popped, expected);
+                    assert_eq!(queue.len(), control.len());
+                    assert!(queue.iter().eq(control.iter()));
+
+                    // The queue can be filled up again
+                    while !queue.is_full() {
+                        queue.push_back(789).unwrap();
+                        control.push_back(789);
+                    }
+                    assert!(queue.iter().eq(control.iter()));
+
+                    queue.clear();
+                    control.clear();
+                }
 
-                for i in 0..n {
-                    let value = i as i64 * 123 + 456;
+                // One push and one pop to move the internal start point ahead
+                queue.push_back(987).unwrap();
+                queue.pop_front().unwrap();
+            }
+        }
+
+        for i in 0..6 {
+            run_test(i);
+        }
+    }
+
+    #[test]
+    fn pop_front_n_moves_ownership() {
+        use std::rc::Rc;
+
+        let value = Rc::new(0);
+        let mut queue = GenericQueue::<Rc<i32>, Vec<MaybeUninit<Rc<i32>>>>::new(5);
+        // Move the internal start point, so the elements wrap around
+        for _ in 0..3 {
+            queue.push_back(value.clone()).unwrap();
+            queue.pop_front().unwrap();
+        }
+        for _ in 0..5 {
+            queue.push_back(value.clone()).unwrap();
+        }
+        assert_eq!(Rc::strong_count(&value), 6);
+
+        let mut out = [const { MaybeUninit::uninit() }; 4];
+        assert_eq!(queue.pop_front_n(&mut out), 4);
+        assert_eq!(queue.len(), 1);
+        // Nothing was dropped or cloned, the references are only moved
+        assert_eq!(Rc::strong_count(&value), 6);
+        for element in &mut out {
+            unsafe { element.assume_init_drop() };
+        }
+        assert_eq!(Rc::strong_count(&value), 2);
+        queue.clear();
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn push_front_and_pop_back() {
+        fn run_test(n: usize) {
+            let mut queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::new(n as u32);
+            let mut control = VecDeque::new();
+
+            // Completely fill and empty the queue n times, but move the internal start point
+            // ahead by one each time
+            for _ in 0..n {
+                let result = queue.pop_back();
+                assert_eq!(result, None);
+
+                for i in 0..n {
+                    let value = i as i64 * 123 + 456;
                     let result = queue.push_front(value);
                     assert_eq!(*result.unwrap(), value);
                     control.push_front(value);
@@ -611,4 +1326,789 @@ mod tests {
             run_test(i);
         }
     }
+
+    #[test]
+    fn drain() {
+        fn run_test(n: usize) {
+            let mut queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::new(n as u32);
+            let mut control = VecDeque::new();
+
+            // Fill and drain the queue n times, but move the internal start point ahead by one each time
+            for _ in 0..n {
+                for consumed in 0..=n {
+                    for i in 0..n {
+                        let value = i as i64 * 123 + 456;
+                        queue.push_back(value).unwrap();
+                        control.push_back(value);
+                    }
+
+                    // Consume only part of the elements before dropping the iterator
+                    let mut drain = queue.drain();
+                    let mut control_drain = control.drain(..);
+                    assert_eq!(drain.len(), control_drain.len());
+                    for _ in 0..consumed {
+                        assert_eq!(drain.next(), control_drain.next());
+                    }
+                    drop(drain);
+                    drop(control_drain);
+
+                    assert!(queue.is_empty());
+                    assert_eq!(queue.capacity(), n);
+                }
+
+                // One push and one pop to move the internal start point ahead
+                queue.push_back(987).unwrap();
+                queue.pop_front().unwrap();
+            }
+        }
+
+        for i in 0..6 {
+            run_test(i);
+        }
+    }
+
+    #[test]
+    fn drain_rev() {
+        let mut queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::new(4);
+        let mut control = VecDeque::new();
+        queue.push_back(1).unwrap();
+        queue.pop_front().unwrap();
+        for value in [1, 2, 3, 4] {
+            queue.push_back(value).unwrap();
+            control.push_back(value);
+        }
+        assert_eq!(
+            queue.drain().rev().collect::<Vec<_>>(),
+            control.drain(..).rev().collect::<Vec<_>>()
+        );
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn drain_drops_remaining_once() {
+        use std::rc::Rc;
+
+        let value = Rc::new(0);
+        let mut queue = GenericQueue::<Rc<i32>, Vec<MaybeUninit<Rc<i32>>>>::new(4);
+        // Move the internal start point, so the elements wrap around
+        for _ in 0..3 {
+            queue.push_back(value.clone()).unwrap();
+            queue.pop_front().unwrap();
+        }
+        for _ in 0..4 {
+            queue.push_back(value.clone()).unwrap();
+        }
+        assert_eq!(Rc::strong_count(&value), 5);
+
+        let mut drain = queue.drain();
+        drop(drain.next());
+        drop(drain);
+
+        assert_eq!(Rc::strong_count(&value), 1);
+        assert!(queue.is_empty());
+        queue.push_back(value.clone()).unwrap();
+        assert_eq!(queue.len(), 1);
+        queue.clear();
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn clone() {
+        use std::rc::Rc;
+
+        let mut queue = GenericQueue::<Rc<i64>, Vec<MaybeUninit<Rc<i64>>>>::new(5);
+        // Move the internal start point, so the elements wrap around
+        for _ in 0..3 {
+            queue.push_back(Rc::new(0)).unwrap();
+            queue.pop_front().unwrap();
+        }
+        for value in [1, 2, 3, 4] {
+            queue.push_back(Rc::new(value)).unwrap();
+        }
+        let (first, second) = queue.as_slices();
+        assert!(!first.is_empty() && !second.is_empty());
+
+        let mut clone = queue.clone();
+        assert_eq!(clone.capacity(), queue.capacity());
+        assert_eq!(clone.as_slices().0.len(), 4);
+        assert!(clone.iter().eq(queue.iter()));
+        assert!(clone
+            .iter()
+            .zip(queue.iter())
+            .all(|(a, b)| Rc::strong_count(a) == 2 && Rc::ptr_eq(a, b)));
+
+        // Modifying and dropping the elements of one queue doesn't affect the other
+        clone.pop_front().unwrap();
+        clone.push_back(Rc::new(5)).unwrap();
+        assert!(queue.iter().map(|v| **v).eq([1, 2, 3, 4]));
+        assert!(clone.iter().map(|v| **v).eq([2, 3, 4, 5]));
+        clone.clear();
+        assert!(queue.iter().all(|v| Rc::strong_count(v) == 1));
+        assert!(queue.iter().map(|v| **v).eq([1, 2, 3, 4]));
+        queue.clear();
+    }
+
+    #[test]
+    fn extend_back() {
+        fn run_test(n: usize) {
+            let mut queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::new(n as u32);
+            let mut control = VecDeque::new();
+
+            // Fill and empty the queue n times, but move the internal start point ahead by one each time
+            for _ in 0..n {
+                // Exactly fill the queue in two steps
+                let values: Vec<i64> = (0..n as i64).map(|i| i * 123 + 456).collect();
+                let (first, second) = values.split_at(n / 2);
+                assert!(queue.extend_back(first.iter().copied()).is_ok());
+                assert!(queue.extend_back(second.iter().copied()).is_ok());
+                control.extend(values.iter().copied());
+                assert!(queue.iter().eq(control.iter()));
+                assert!(queue.extend_back([]).is_ok());
+
+                // Pushing into a full queue fails without changing it
+                assert!(queue.extend_back([1]).is_err());
+                assert!(queue.iter().eq(control.iter()));
+
+                queue.clear();
+                control.clear();
+
+                // One push and one pop to move the internal start point ahead
+                queue.push_back(987).unwrap();
+                queue.pop_front().unwrap();
+            }
+        }
+
+        for i in 0..6 {
+            run_test(i);
+        }
+    }
+
+    #[test]
+    fn extend_back_overflow() {
+        let mut queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::new(4);
+        queue.push_back(1).unwrap();
+        assert!(queue.extend_back([2, 3, 4, 5, 6]).is_err());
+        assert!(queue.iter().copied().eq([1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn retain() {
+        fn run_test(n: usize) {
+            let mut queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::new(n as u32);
+            let mut control = VecDeque::new();
+
+            // Fill and empty the queue n times, but move the internal start point ahead by one each time
+            for _ in 0..n {
+                for predicate in [
+                    |_: &i64| true,
+                    |_: &i64| false,
+                    |v: &i64| v % 2 == 0,
+                    |v: &i64| v % 3 != 1,
+                ] {
+                    for i in 0..n {
+                        let value = i as i64 + 456;
+                        queue.push_back(value).unwrap();
+                        control.push_back(value);
+                    }
+
+                    queue.retain(predicate);
+                    control.retain(predicate);
+                    assert!(queue.iter().eq(control.iter()));
+                    assert_eq!(queue.len(), control.len());
+
+                    queue.clear();
+                    control.clear();
+                }
+
+                // One push and one pop to move the internal start point ahead
+                queue.push_back(987).unwrap();
+                queue.pop_front().unwrap();
+            }
+        }
+
+        for i in 0..6 {
+            run_test(i);
+        }
+    }
+
+    #[test]
+    fn peek_nth_and_last() {
+        fn run_test(n: usize) {
+            let mut queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::new(n as u32);
+            let mut control = VecDeque::new();
+
+            // Fill the queue n times, but move the internal start point ahead by one each time
+            for _ in 0..n {
+                assert_eq!(queue.peek_nth(0), None);
+                assert_eq!(queue.last(), None);
+
+                for i in 0..n {
+                    let value = i as i64 * 123 + 456;
+                    queue.push_back(value).unwrap();
+                    control.push_back(value);
+                }
+
+                assert_eq!(queue.peek_nth(0), queue.front());
+                assert_eq!(queue.last(), control.back());
+                for i in 0..n {
+                    assert_eq!(queue.peek_nth(i), control.get(i));
+                }
+                assert_eq!(queue.peek_nth(n), None);
+                assert_eq!(queue.peek_nth(usize::MAX), None);
+
+                queue.clear();
+                control.clear();
+
+                // One push and one pop to move the internal start point ahead
+                queue.push_back(987).unwrap();
+                queue.pop_front().unwrap();
+            }
+        }
+
+        for i in 0..6 {
+            run_test(i);
+        }
+    }
+
+    #[test]
+    fn contains_and_position() {
+        fn run_test(n: usize) {
+            let mut queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::new(n as u32);
+            let mut control = VecDeque::new();
+
+            // Fill the queue n times, but move the internal start point ahead by one each time
+            for _ in 0..n {
+                for i in 0..n {
+                    let value = i as i64 * 3;
+                    queue.push_back(value).unwrap();
+                    control.push_back(value);
+                }
+
+                for value in -1..n as i64 * 3 + 1 {
+                    assert_eq!(queue.contains(&value), control.contains(&value));
+                    assert_eq!(
+                        queue.position(|&v| v == value),
+                        control.iter().position(|&v| v == value)
+                    );
+                }
+                assert_eq!(queue.position(|&v| v > 4), control.iter().position(|&v| v > 4));
+
+                queue.clear();
+                control.clear();
+
+                // One push and one pop to move the internal start point ahead
+                queue.push_back(987).unwrap();
+                queue.pop_front().unwrap();
+            }
+        }
+
+        for i in 0..6 {
+            run_test(i);
+        }
+    }
+
+    #[test]
+    fn retain_across_wrap() {
+        let mut queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::new(6);
+        let mut control = VecDeque::new();
+        for _ in 0..4 {
+            queue.push_back(0).unwrap();
+            queue.pop_front().unwrap();
+        }
+        for value in 1..=6 {
+            queue.push_back(value).unwrap();
+            control.push_back(value);
+        }
+        let (first, second) = queue.as_slices();
+        assert_eq!(first, [1, 2]);
+        assert_eq!(second, [3, 4, 5, 6]);
+
+        // Remove the elements in the middle, on both sides of the wrap boundary
+        queue.retain(|v| !(2..=3).contains(v));
+        control.retain(|v| !(2..=3).contains(v));
+        assert!(queue.iter().eq(control.iter()));
+
+        // The freed slots can be reused
+        queue.extend_back([7, 8]).unwrap();
+        control.extend([7, 8]);
+        assert!(queue.iter().eq(control.iter()));
+        assert!(queue.is_full());
+    }
+
+    #[test]
+    fn retain_panic() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        let value = Rc::new(0);
+        let mut queue = GenericQueue::<Rc<i32>, Vec<MaybeUninit<Rc<i32>>>>::new(5);
+        for _ in 0..3 {
+            queue.push_back(value.clone()).unwrap();
+            queue.pop_front().unwrap();
+        }
+        for _ in 0..5 {
+            queue.push_back(value.clone()).unwrap();
+        }
+
+        let mut calls = 0;
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            queue.retain(|_| {
+                calls += 1;
+                if calls == 4 {
+                    panic!("predicate panicked");
+                }
+                calls % 2 == 0
+            })
+        }));
+        assert!(result.is_err());
+
+        // Two elements were removed before the panic; the others are still in the queue
+        assert_eq!(queue.len(), 3);
+        assert_eq!(Rc::strong_count(&value), 4);
+        queue.clear();
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn is_contiguous() {
+        let mut queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::new(4);
+        assert!(queue.is_contiguous());
+        for i in 0..4 {
+            queue.push_back(i).unwrap();
+            assert!(queue.is_contiguous());
+        }
+
+        // Wrap around the end of the storage
+        queue.pop_front().unwrap();
+        queue.push_back(4).unwrap();
+        assert!(!queue.is_contiguous());
+        assert_eq!(queue.as_slices(), (&[1, 2, 3][..], &[4][..]));
+
+        // Front moves past the end of the storage, so the contents are contiguous again
+        for _ in 0..3 {
+            queue.pop_front().unwrap();
+        }
+        assert!(queue.is_contiguous());
+        assert_eq!(queue.as_slices(), (&[4][..], &[][..]));
+
+        queue.push_front(3).unwrap();
+        assert!(!queue.is_contiguous());
+        queue.clear();
+        assert!(queue.is_contiguous());
+    }
+
+    #[test]
+    fn swap() {
+        fn run_test(n: usize) {
+            let mut queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::new(n as u32);
+            let mut control = VecDeque::new();
+
+            // Move the internal start point ahead by one in each round, so that the swaps cross the wrap boundary
+            for _ in 0..n {
+                for len in 0..=n {
+                    queue.clear();
+                    control.clear();
+                    for i in 0..len {
+                        let value = i as i64 * 123 + 456;
+                        queue.push_back(value).unwrap();
+                        control.push_back(value);
+                    }
+                    for i in 0..len {
+                        for j in 0..len {
+                            queue.swap(i, j);
+                            control.swap(i, j);
+                            assert_eq!(to_vec(queue.as_slices()), to_vec(control.as_slices()));
+                        }
+                    }
+                }
+
+                queue.clear();
+                queue.push_back(987).unwrap();
+                queue.pop_front().unwrap();
+            }
+        }
+
+        for i in 0..6 {
+            run_test(i);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "swap indices out of bounds: the len is 2 but the indices are 1 and 2")]
+    fn swap_out_of_bounds() {
+        let mut queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::new(3);
+        queue.push_back(1).unwrap();
+        queue.push_back(2).unwrap();
+        queue.swap(1, 2);
+    }
+
+    #[test]
+    fn extract_if() {
+        fn run_test(n: usize) {
+            let mut queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::new(n as u32);
+
+            // Move the internal start point ahead by one in each round
+            for _ in 0..n {
+                for len in 0..=n {
+                    for modulus in 1..4 {
+                        // Extract the elements divisible by `modulus`, stopping after `take` extracted elements
+                        for take in 0..=len {
+                            queue.clear();
+                            let mut control = Vec::new();
+                            for i in 0..len as i64 {
+                                queue.push_back(i).unwrap();
+                                control.push(i);
+                            }
+
+                            let extracted: Vec<_> = queue.extract_if(|x| x % modulus == 0).take(take).collect();
+
+                            let mut control_extracted = Vec::new();
+                            let mut control_kept = Vec::new();
+                            for x in control {
+                                if x % modulus == 0 && control_extracted.len() < take {
+                                    control_extracted.push(x);
+                                } else {
+                                    control_kept.push(x);
+                                }
+                            }
+                            assert_eq!(extracted, control_extracted);
+                            assert_eq!(to_vec(queue.as_slices()), control_kept);
+                        }
+                    }
+                }
+
+                queue.clear();
+                queue.push_back(987).unwrap();
+                queue.pop_front().unwrap();
+            }
+        }
+
+        for i in 0..6 {
+            run_test(i);
+        }
+    }
+
+    #[test]
+    fn extract_if_dropped_early() {
+        use std::rc::Rc;
+
+        let value = Rc::new(0);
+        let mut queue = GenericQueue::<(i32, Rc<i32>), Vec<MaybeUninit<(i32, Rc<i32>)>>>::new(5);
+        for _ in 0..3 {
+            queue.push_back((0, value.clone())).unwrap();
+            queue.pop_front().unwrap();
+        }
+        for i in 0..5 {
+            queue.push_back((i, value.clone())).unwrap();
+        }
+
+        // The matching elements which weren't visited yet are kept
+        let mut iter = queue.extract_if(|(i, _)| i % 2 == 0);
+        assert_eq!(iter.next().map(|(i, _)| i), Some(0));
+        drop(iter);
+        assert_eq!(queue.iter().map(|(i, _)| *i).collect::<Vec<_>>(), [1, 2, 3, 4]);
+        assert_eq!(Rc::strong_count(&value), 5);
+
+        // The extracted elements are owned and dropped by the caller
+        assert_eq!(queue.extract_if(|_| true).count(), 4);
+        assert!(queue.is_empty());
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn extract_if_panic() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        let value = Rc::new(0);
+        let mut queue = GenericQueue::<(i32, Rc<i32>), Vec<MaybeUninit<(i32, Rc<i32>)>>>::new(5);
+        for _ in 0..3 {
+            queue.push_back((0, value.clone())).unwrap();
+            queue.pop_front().unwrap();
+        }
+        for i in 0..5 {
+            queue.push_back((i, value.clone())).unwrap();
+        }
+
+        let mut calls = 0;
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            queue
+                .extract_if(|_| {
+                    calls += 1;
+                    if calls == 4 {
+                        panic!("predicate panicked");
+                    }
+                    calls % 2 == 0
+                })
+                .count()
+        }));
+        assert!(result.is_err());
+
+        // One element was extracted before the panic; the others are still in the queue, in order
+        assert_eq!(queue.iter().map(|(i, _)| *i).collect::<Vec<_>>(), [0, 2, 3, 4]);
+        assert_eq!(Rc::strong_count(&value), 5);
+        queue.clear();
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn try_new() {
+        let queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::try_new(5).unwrap();
+        assert_eq!(queue.capacity(), 5);
+        assert!(queue.is_empty());
+
+        // The total size of the elements exceeds `isize::MAX` bytes, so the storage can't be allocated
+        type Huge = [u64; 1 << 30];
+        assert!(GenericQueue::<Huge, Vec<MaybeUninit<Huge>>>::try_new(u32::MAX).is_none());
+    }
+
+    #[test]
+    fn resize_with() {
+        fn run_test(n: usize) {
+            let mut queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::new(n as u32);
+            let mut control = VecDeque::new();
+
+            // Move the internal start point ahead by one in each round
+            for _ in 0..n {
+                for len in 0..=n {
+                    for new_len in 0..=n {
+                        queue.clear();
+                        control.clear();
+                        for i in 0..len {
+                            let value = i as i64 * 123 + 456;
+                            queue.push_back(value).unwrap();
+                            control.push_back(value);
+                        }
+
+                        let mut next = 0;
+                        let result = queue.resize_with(new_len, || {
+                            next += 1;
+                            next
+                        });
+                        assert!(result.is_ok());
+                        let mut next = 0;
+                        control.resize_with(new_len, || {
+                            next += 1;
+                            next
+                        });
+                        assert!(queue.iter().eq(control.iter()));
+                    }
+                }
+
+                queue.clear();
+                queue.push_back(987).unwrap();
+                queue.pop_front().unwrap();
+            }
+
+            // Exceeding the capacity leaves the queue unchanged
+            queue.clear();
+            let _ = queue.push_back(1);
+            let len = queue.len();
+            assert!(queue.resize_with(n + 1, || panic!("must not be called")).is_err());
+            assert_eq!(queue.len(), len);
+        }
+
+        for i in 0..6 {
+            run_test(i);
+        }
+    }
+
+    #[test]
+    fn resize_with_panic() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        let value = Rc::new(0);
+        let mut queue = GenericQueue::<Rc<i32>, Vec<MaybeUninit<Rc<i32>>>>::new(5);
+        for _ in 0..3 {
+            queue.push_back(value.clone()).unwrap();
+            queue.pop_front().unwrap();
+        }
+        queue.push_back(value.clone()).unwrap();
+
+        let mut calls = 0;
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            queue.resize_with(5, || {
+                calls += 1;
+                if calls == 3 {
+                    panic!("generator panicked");
+                }
+                value.clone()
+            })
+        }));
+        assert!(result.is_err());
+
+        // Two elements were pushed before the panic
+        assert_eq!(queue.len(), 3);
+        assert_eq!(Rc::strong_count(&value), 4);
+
+        // Shrinking drops the removed elements
+        assert!(queue.resize_with(1, || panic!("must not be called")).is_ok());
+        assert_eq!(Rc::strong_count(&value), 2);
+        queue.clear();
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn debug() {
+        fn run_test(n: usize) {
+            let mut queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::new(n as u32);
+            let mut control = VecDeque::new();
+
+            // Fill and empty the queue n times, but move the internal start point ahead by one each time
+            for _ in 0..n {
+                assert_eq!(format!("{:?}", queue), format!("{:?}", control));
+                for i in 0..n {
+                    let value = i as i64 * 123 + 456;
+                    queue.push_back(value).unwrap();
+                    control.push_back(value);
+                    assert_eq!(format!("{:?}", queue), format!("{:?}", control));
+                    assert_eq!(format!("{:#?}", queue), format!("{:#?}", control));
+                }
+
+                queue.clear();
+                control.clear();
+
+                // One push and one pop to move the internal start point ahead
+                queue.push_back(987).unwrap();
+                queue.pop_front().unwrap();
+            }
+        }
+
+        for i in 0..6 {
+            run_test(i);
+        }
+    }
+
+    #[test]
+    fn truncate_back_and_front() {
+        fn run_test(n: usize) {
+            let mut queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::new(n as u32);
+            let mut control = VecDeque::new();
+
+            // Fill and empty the queue n times, but move the internal start point ahead by one each time
+            for _ in 0..n {
+                for len in 0..=n + 1 {
+                    for back in [true, false] {
+                        for i in 0..n {
+                            let value = i as i64 * 123 + 456;
+                            queue.push_back(value).unwrap();
+                            control.push_back(value);
+                        }
+
+                        if back {
+                            queue.truncate_back(len);
+                            control.truncate(len);
+                        } else {
+                            queue.truncate_front(len);
+                            let count = control.len().saturating_sub(len);
+                            control.drain(..count);
+                        }
+                        assert_eq!(queue.len(), control.len());
+                        assert!(queue.iter().eq(control.iter()));
+
+                        // The queue can be filled up again
+                        while !queue.is_full() {
+                            queue.push_back(789).unwrap();
+                            control.push_back(789);
+                        }
+                        assert!(queue.iter().eq(control.iter()));
+
+                        queue.clear();
+                        control.clear();
+                    }
+                }
+
+                // One push and one pop to move the internal start point ahead
+                queue.push_back(987).unwrap();
+                queue.pop_front().unwrap();
+            }
+        }
+
+        for i in 0..6 {
+            run_test(i);
+        }
+    }
+
+    #[test]
+    fn truncate_drops_once() {
+        use std::rc::Rc;
+
+        let value = Rc::new(0);
+        let mut queue = GenericQueue::<Rc<i32>, Vec<MaybeUninit<Rc<i32>>>>::new(6);
+        // Move the internal start point, so the elements wrap around
+        for _ in 0..4 {
+            queue.push_back(value.clone()).unwrap();
+            queue.pop_front().unwrap();
+        }
+        for _ in 0..6 {
+            queue.push_back(value.clone()).unwrap();
+        }
+        assert_eq!(Rc::strong_count(&value), 7);
+
+        queue.truncate_back(4);
+        assert_eq!(Rc::strong_count(&value), 5);
+        queue.truncate_front(1);
+        assert_eq!(Rc::strong_count(&value), 2);
+        queue.truncate_front(3);
+        queue.truncate_back(3);
+        assert_eq!(Rc::strong_count(&value), 2);
+        queue.truncate_back(0);
+        assert_eq!(Rc::strong_count(&value), 1);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn rotate_left_and_right() {
+        fn run_test(n: usize) {
+            let mut queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::new(n as u32);
+            let mut control = VecDeque::new();
+
+            // Fill and empty the queue n times, but move the internal start point ahead by one each time
+            for _ in 0..n {
+                // Check both full and partially filled queues
+                for len in [n, n.saturating_sub(2)] {
+                    for mid in 0..=len {
+                        for i in 0..len {
+                            let value = i as i64 * 123 + 456;
+                            queue.push_back(value).unwrap();
+                            control.push_back(value);
+                        }
+
+                        queue.rotate_left(mid);
+                        control.rotate_left(mid);
+                        assert!(queue.iter().eq(control.iter()));
+                        assert_eq!(to_vec(queue.as_slices()), to_vec(control.as_slices()));
+
+                        queue.rotate_right(mid);
+                        control.rotate_right(mid);
+                        assert!(queue.iter().eq(control.iter()));
+
+                        queue.rotate_right(len - mid);
+                        control.rotate_right(len - mid);
+                        assert!(queue.iter().eq(control.iter()));
+
+                        queue.clear();
+                        control.clear();
+                    }
+                }
+
+                // One push and one pop to move the internal start point ahead
+                queue.push_back(987).unwrap();
+                queue.pop_front().unwrap();
+            }
+        }
+
+        for i in 0..6 {
+            run_test(i);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "rotation point out of bounds")]
+    fn rotate_left_out_of_bounds() {
+        let mut queue = GenericQueue::<i64, Vec<MaybeUninit<i64>>>::new(4);
+        queue.push_back(1).unwrap();
+        queue.rotate_left(2);
+    }
 }
@@ -0,0 +1,319 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use crate::storage::Storage;
+
+/// A bounded single-producer/single-consumer queue.
+///
+/// The queue is split into a [`Producer`] and a [`Consumer`] handle with [`split()`](Self::split),
+/// which can be moved to different threads. Neither handle ever blocks or takes a lock.
+///
+/// `head` and `tail` are position counters in the range `0..2 * capacity`; the element index of a position
+/// is `position % capacity`. Using twice the capacity as the counter range lets the queue tell apart
+/// "empty" (`head == tail`) from "full" (`tail - head == capacity`) without sacrificing a slot.
+///
+/// # Memory ordering
+///
+/// - `tail` is only written by the producer. It publishes a pushed element with a `Release` store,
+///   which the consumer pairs with an `Acquire` load before reading the element.
+/// - `head` is only written by the consumer. It releases a popped slot with a `Release` store,
+///   which the producer pairs with an `Acquire` load before overwriting the slot.
+/// - Each handle reads its own counter with `Relaxed` ordering, since no other thread modifies it.
+pub struct SpscQueue<T, S: Storage<T>> {
+    /// The position of the next element to be popped; only modified by the consumer.
+    head: AtomicU32,
+    /// The position of the next element to be pushed; only modified by the producer.
+    tail: AtomicU32,
+    storage: S,
+    _marker: PhantomData<T>,
+}
+
+impl<T, S: Storage<T>> SpscQueue<T, S> {
+    /// Creates an empty queue.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity > u32::MAX / 2`.
+    pub fn new(capacity: u32) -> Self {
+        assert!(
+            capacity <= u32::MAX / 2,
+            "SpscQueue can hold at most u32::MAX / 2 elements"
+        );
+        Self {
+            head: AtomicU32::new(0),
+            tail: AtomicU32::new(0),
+            storage: S::new(capacity),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the maximum number of elements that the queue can hold.
+    pub fn capacity(&self) -> usize {
+        self.storage.capacity() as usize
+    }
+
+    /// Returns the current number of elements in the queue.
+    ///
+    /// While the queue is split, the result is only a snapshot and may be outdated immediately.
+    pub fn len(&self) -> usize {
+        let head = self.head.load(Ordering::Acquire);
+        let tail = self.tail.load(Ordering::Acquire);
+        distance(head, tail, self.storage.capacity()) as usize
+    }
+
+    /// Returns `true` if and only if the queue doesn't contain any elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if and only if the queue has reached its capacity.
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// Splits the queue into a producer and a consumer handle.
+    ///
+    /// The handles borrow the queue, so they can be sent to scoped threads.
+    pub fn split(&mut self) -> (Producer<'_, T, S>, Consumer<'_, T, S>) {
+        let capacity = self.storage.capacity();
+        // SAFETY: `0 <= capacity <= self.capacity()`
+        let elements = unsafe { self.storage.subslice_mut(0, capacity) }.cast::<T>();
+        let queue: &Self = self;
+        (
+            Producer {
+                queue,
+                elements,
+                capacity,
+            },
+            Consumer {
+                queue,
+                elements,
+                capacity,
+            },
+        )
+    }
+
+    /// Removes all elements from the queue.
+    pub fn clear(&mut self) {
+        let (_, mut consumer) = self.split();
+        while consumer.try_pop().is_some() {}
+    }
+}
+
+/// The sending half of a [`SpscQueue`].
+pub struct Producer<'a, T, S: Storage<T>> {
+    queue: &'a SpscQueue<T, S>,
+    elements: *mut T,
+    capacity: u32,
+}
+
+// SAFETY: Only the producer writes elements into the queue and advances `tail`,
+// and the values are handed over to the consumer, so it can be sent to another thread if `T` can.
+unsafe impl<T: Send, S: Storage<T>> Send for Producer<'_, T, S> {}
+
+impl<T, S: Storage<T>> Producer<'_, T, S> {
+    /// Tries to push an element to the back of the queue.
+    ///
+    /// If the queue is full, returns `Err(value)`.
+    pub fn try_push(&mut self, value: T) -> Result<(), T> {
+        let tail = self.queue.tail.load(Ordering::Relaxed);
+        // Acquire: the consumer must have finished reading a slot before it is overwritten.
+        let head = self.queue.head.load(Ordering::Acquire);
+        if distance(head, tail, self.capacity) == self.capacity {
+            return Err(value);
+        }
+        // SAFETY: the queue isn't full, so the slot at `tail` is in-bounds, uninitialized,
+        // and not accessed by the consumer until `tail` is advanced.
+        unsafe { self.elements.add(index(tail, self.capacity) as usize).write(value) };
+        // Release: publish the written element to the consumer.
+        self.queue.tail.store(advance(tail, self.capacity), Ordering::Release);
+        Ok(())
+    }
+
+    /// Returns the number of elements that can currently be pushed without failing.
+    pub fn free_slots(&self) -> usize {
+        let tail = self.queue.tail.load(Ordering::Relaxed);
+        let head = self.queue.head.load(Ordering::Acquire);
+        (self.capacity - distance(head, tail, self.capacity)) as usize
+    }
+}
+
+/// The receiving half of a [`SpscQueue`].
+pub struct Consumer<'a, T, S: Storage<T>> {
+    queue: &'a SpscQueue<T, S>,
+    elements: *mut T,
+    capacity: u32,
+}
+
+// SAFETY: Only the consumer reads elements from the queue and advances `head`,
+// and the values are received from the producer, so it can be sent to another thread if `T` can.
+unsafe impl<T: Send, S: Storage<T>> Send for Consumer<'_, T, S> {}
+
+impl<T, S: Storage<T>> Consumer<'_, T, S> {
+    /// Tries to pop an element from the front of the queue.
+    ///
+    /// If the queue is empty, returns `None`.
+    pub fn try_pop(&mut self) -> Option<T> {
+        let head = self.queue.head.load(Ordering::Relaxed);
+        // Acquire: the producer's write of the element must be visible before it is read.
+        let tail = self.queue.tail.load(Ordering::Acquire);
+        if head == tail {
+            return None;
+        }
+        // SAFETY: the queue isn't empty, so the slot at `head` is in-bounds, initialized,
+        // and not accessed by the producer until `head` is advanced.
+        let value = unsafe { self.elements.add(index(head, self.capacity) as usize).read() };
+        // Release: hand the slot back to the producer.
+        self.queue.head.store(advance(head, self.capacity), Ordering::Release);
+        Some(value)
+    }
+
+    /// Returns the number of elements that can currently be popped.
+    pub fn len(&self) -> usize {
+        let head = self.queue.head.load(Ordering::Relaxed);
+        let tail = self.queue.tail.load(Ordering::Acquire);
+        distance(head, tail, self.capacity) as usize
+    }
+
+    /// Returns `true` if and only if there are currently no elements to pop.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Returns the number of elements between positions `head` and `tail`.
+fn distance(head: u32, tail: u32, capacity: u32) -> u32 {
+    if tail >= head {
+        tail - head
+    } else {
+        tail + 2 * capacity - head
+    }
+}
+
+/// Returns the element index of position `position`.
+fn index(position: u32, capacity: u32) -> u32 {
+    if position >= capacity {
+        position - capacity
+    } else {
+        position
+    }
+}
+
+/// Returns the position following `position`.
+fn advance(position: u32, capacity: u32) -> u32 {
+    if position + 1 == 2 * capacity {
+        0
+    } else {
+        position + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::MaybeUninit;
+    use std::rc::Rc;
+    use std::thread;
+
+    use super::*;
+
+    type TestQueue<T> = SpscQueue<T, Vec<MaybeUninit<T>>>;
+
+    #[test]
+    fn push_and_pop() {
+        for capacity in 0..6 {
+            let mut queue = TestQueue::<u32>::new(capacity);
+            let (mut producer, mut consumer) = queue.split();
+            // Wrap around several times
+            for round in 0..3 {
+                assert_eq!(consumer.try_pop(), None);
+                for i in 0..capacity {
+                    assert_eq!(producer.try_push(round * 100 + i), Ok(()));
+                }
+                assert_eq!(producer.try_push(999), Err(999));
+                assert_eq!(producer.free_slots(), 0);
+                assert_eq!(consumer.len(), capacity as usize);
+                for i in 0..capacity {
+                    assert_eq!(consumer.try_pop(), Some(round * 100 + i));
+                }
+                assert!(consumer.is_empty());
+                assert_eq!(producer.free_slots(), capacity as usize);
+            }
+        }
+    }
+
+    #[test]
+    fn len_and_is_full() {
+        let mut queue = TestQueue::<u32>::new(2);
+        assert!(queue.is_empty());
+        {
+            let (mut producer, _) = queue.split();
+            producer.try_push(1).unwrap();
+            producer.try_push(2).unwrap();
+        }
+        assert_eq!(queue.len(), 2);
+        assert!(queue.is_full());
+        queue.clear();
+        assert!(queue.is_empty());
+        assert_eq!(queue.capacity(), 2);
+    }
+
+    #[test]
+    fn clear_drops_elements() {
+        let rc = Rc::new(());
+        let mut queue = TestQueue::<Rc<()>>::new(3);
+        let (mut producer, mut consumer) = queue.split();
+        for _ in 0..3 {
+            producer.try_push(rc.clone()).unwrap();
+        }
+        drop(consumer.try_pop());
+        producer.try_push(rc.clone()).unwrap();
+        assert_eq!(Rc::strong_count(&rc), 4);
+        queue.clear();
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn stress_across_threads() {
+        const COUNT: u64 = 50_000;
+
+        let mut queue = TestQueue::<u64>::new(64);
+        let (mut producer, mut consumer) = queue.split();
+        thread::scope(|scope| {
+            scope.spawn(move || {
+                for i in 0..COUNT {
+                    let mut value = i;
+                    while let Err(rejected) = producer.try_push(value) {
+                        value = rejected;
+                        thread::yield_now();
+                    }
+                }
+            });
+            scope.spawn(move || {
+                let mut expected = 0;
+                while expected < COUNT {
+                    match consumer.try_pop() {
+                        Some(value) => {
+                            assert_eq!(value, expected);
+                            expected += 1;
+                        },
+                        None => thread::yield_now(),
+                    }
+                }
+            });
+        });
+        assert!(queue.is_empty());
+    }
+}
@@ -11,6 +11,7 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
+use core::fmt;
 use core::ops;
 
 use crate::generic::queue::GenericQueue;
@@ -40,7 +41,13 @@ pub struct InlineQueue<T: Copy, const CAPACITY: usize> {
 }
 
 impl<T: Copy, const CAPACITY: usize> InlineQueue<T, CAPACITY> {
-    const CHECK_CAPACITY: () = assert!(0 < CAPACITY && CAPACITY <= u32::MAX as usize);
+    const CHECK_CAPACITY: () = {
+        assert!(CAPACITY > 0, "InlineQueue requires a non-zero CAPACITY");
+        assert!(
+            CAPACITY <= u32::MAX as usize,
+            "InlineQueue requires CAPACITY <= u32::MAX"
+        );
+    };
 
     /// Creates an empty queue.
     #[must_use]
@@ -51,6 +58,25 @@ impl<T: Copy, const CAPACITY: usize> InlineQueue<T, CAPACITY> {
             inner: GenericQueue::new(CAPACITY as u32),
         }
     }
+
+    /// Creates a full queue from the given array, with the first array element at the front.
+    ///
+    /// The array is moved into the queue as a whole, instead of pushing the elements one-by-one.
+    #[must_use]
+    pub fn from_array(array: [T; CAPACITY]) -> Self {
+        let () = Self::CHECK_CAPACITY;
+
+        Self {
+            // SAFETY: all elements of the storage are initialized from the array.
+            inner: unsafe { GenericQueue::from_full_storage(Inline::from_array(array)) },
+        }
+    }
+}
+
+impl<T: Copy, const CAPACITY: usize> From<[T; CAPACITY]> for InlineQueue<T, CAPACITY> {
+    fn from(array: [T; CAPACITY]) -> Self {
+        Self::from_array(array)
+    }
 }
 
 impl<T: Copy, const CAPACITY: usize> Default for InlineQueue<T, CAPACITY> {
@@ -73,6 +99,12 @@ impl<T: Copy, const CAPACITY: usize> ops::DerefMut for InlineQueue<T, CAPACITY>
     }
 }
 
+impl<T: Copy + fmt::Debug, const CAPACITY: usize> fmt::Debug for InlineQueue<T, CAPACITY> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::VecDeque;
@@ -85,6 +117,23 @@ mod tests {
         elements
     }
 
+    #[test]
+    fn from_array() {
+        let mut queue = InlineQueue::from_array([1, 2, 3]);
+        assert_eq!(queue.len(), 3);
+        assert!(queue.is_full());
+        assert!(queue.push_back(4).is_err());
+        assert_eq!(queue.pop_front(), Some(1));
+        assert_eq!(queue.pop_front(), Some(2));
+        assert!(queue.push_back(4).is_ok());
+        assert_eq!(queue.pop_front(), Some(3));
+        assert_eq!(queue.pop_front(), Some(4));
+        assert_eq!(queue.pop_front(), None);
+
+        let queue: InlineQueue<u8, 2> = [7, 8].into();
+        assert_eq!(to_vec(queue.as_slices()), [7, 8]);
+    }
+
     #[test]
     fn front_and_back() {
         fn check_front_and_back<const N: usize>(queue: &mut InlineQueue<i64, N>, control: &mut VecDeque<i64>) {
@@ -258,4 +307,14 @@ mod tests {
         run_test::<4>();
         run_test::<5>();
     }
+
+    #[test]
+    fn debug() {
+        let mut queue = InlineQueue::<i64, 3>::new();
+        assert_eq!(format!("{:?}", queue), "[]");
+        queue.push_back(2).unwrap();
+        queue.push_back(3).unwrap();
+        queue.push_front(1).unwrap();
+        assert_eq!(format!("{:?}", queue), "[1, 2, 3]");
+    }
 }
@@ -42,7 +42,13 @@ pub struct InlineString<const CAPACITY: usize> {
 }
 
 impl<const CAPACITY: usize> InlineString<CAPACITY> {
-    const CHECK_CAPACITY: () = assert!(0 < CAPACITY && CAPACITY <= u32::MAX as usize);
+    const CHECK_CAPACITY: () = {
+        assert!(CAPACITY > 0, "InlineString requires a non-zero CAPACITY");
+        assert!(
+            CAPACITY <= u32::MAX as usize,
+            "InlineString requires CAPACITY <= u32::MAX"
+        );
+    };
 
     /// Creates an empty string.
     pub fn new() -> Self {
@@ -40,7 +40,10 @@ pub struct InlineVec<T: Copy, const CAPACITY: usize> {
 }
 
 impl<T: Copy, const CAPACITY: usize> InlineVec<T, CAPACITY> {
-    const CHECK_CAPACITY: () = assert!(0 < CAPACITY && CAPACITY <= u32::MAX as usize);
+    const CHECK_CAPACITY: () = {
+        assert!(CAPACITY > 0, "InlineVec requires a non-zero CAPACITY");
+        assert!(CAPACITY <= u32::MAX as usize, "InlineVec requires CAPACITY <= u32::MAX");
+    };
 
     /// Creates an empty vector.
     pub fn new() -> Self {
@@ -20,6 +20,9 @@ pub(crate) mod generic;
 pub mod inline;
 pub(crate) mod storage;
 
+pub use fixed_capacity::HeapQueue;
+pub use inline::InlineQueue;
+
 use core::fmt;
 
 /// Indicates that an operation failed because the container doesn't have enough remaining capacity.
@@ -35,3 +38,19 @@ impl fmt::Display for InsufficientCapacity {
 }
 
 impl core::error::Error for InsufficientCapacity {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insufficient_capacity_error() {
+        assert_eq!(
+            InsufficientCapacity.to_string(),
+            "insufficient capacity for this operation"
+        );
+
+        let error: Box<dyn core::error::Error> = Box::new(InsufficientCapacity);
+        assert_eq!(error.to_string(), "insufficient capacity for this operation");
+    }
+}
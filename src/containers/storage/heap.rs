@@ -12,14 +12,16 @@
 // *******************************************************************************
 
 use alloc::alloc::alloc;
+use alloc::alloc::alloc_zeroed;
 use alloc::alloc::dealloc;
+use alloc::alloc::realloc;
 use alloc::alloc::Layout;
 use core::marker::PhantomData;
 use core::mem::MaybeUninit;
 use core::ptr;
 use core::ptr::NonNull;
 
-use super::Storage;
+use super::{GrowableStorage, Storage};
 
 /// Fixed-capacity, heap-allocated storage.
 pub struct Heap<T> {
@@ -41,6 +43,23 @@ impl<T> Heap<T> {
             .checked_mul(size_of::<T>())
             .and_then(|size| Layout::from_size_align(size, align_of::<T>()).ok())
     }
+
+    /// Allocates memory for exactly `capacity` elements using the given allocation function.
+    fn try_allocate(capacity: u32, allocate: unsafe fn(Layout) -> *mut u8) -> Option<Self> {
+        let storage = if capacity > 0 {
+            let layout = Self::layout(capacity)?;
+            // SAFETY: `layout` has a non-zero size (because `capacity` is > 0)
+            NonNull::new(unsafe { allocate(layout) })?.cast::<T>()
+        } else {
+            // Dangling, but well-aligned for `T`, so that empty slices can be created from it
+            NonNull::dangling()
+        };
+        Some(Self {
+            capacity,
+            elements: storage,
+            _marker: PhantomData,
+        })
+    }
 }
 
 impl<T> Storage<T> for Heap<T> {
@@ -62,18 +81,15 @@ impl<T> Storage<T> for Heap<T> {
     ///
     /// Returns `None` if the memory allocation failed.
     fn try_new(capacity: u32) -> Option<Self> {
-        let storage = if capacity > 0 {
-            let layout = Self::layout(capacity)?;
-            // SAFETY: `layout` has a non-zero size (because `capacity` is > 0)
-            NonNull::new(unsafe { alloc(layout) })?
-        } else {
-            NonNull::dangling()
-        };
-        Some(Self {
-            capacity,
-            elements: storage.cast::<T>(),
-            _marker: PhantomData,
-        })
+        Self::try_allocate(capacity, alloc)
+    }
+
+    /// Tries to create a new instance with capacity for exactly the given number of elements,
+    /// allocating zero-initialized memory.
+    ///
+    /// Returns `None` if the memory allocation failed.
+    fn try_new_zeroed(capacity: u32) -> Option<Self> {
+        Self::try_allocate(capacity, alloc_zeroed)
     }
 
     fn capacity(&self) -> u32 {
@@ -119,6 +135,41 @@ impl<T> Storage<T> for Heap<T> {
     }
 }
 
+impl<T> GrowableStorage<T> for Heap<T> {
+    unsafe fn try_grow(&mut self, new_capacity: u32) -> bool {
+        debug_assert!(new_capacity >= self.capacity);
+        if new_capacity == self.capacity {
+            return true;
+        }
+        if self.capacity == 0 {
+            return match Self::try_new(new_capacity) {
+                Some(grown) => {
+                    // The old instance holds no allocation, so it can be replaced without deallocating.
+                    *self = grown;
+                    true
+                },
+                None => false,
+            };
+        }
+
+        let (Some(old_layout), Some(new_layout)) = (Self::layout(self.capacity), Self::layout(new_capacity)) else {
+            return false;
+        };
+        // SAFETY:
+        // - `self.elements` has previously been allocated with `old_layout`
+        // - `new_layout` has the same alignment and a non-zero size (because `new_capacity > self.capacity > 0`)
+        let elements = unsafe { realloc(self.elements.as_ptr().cast::<u8>(), old_layout, new_layout.size()) };
+        match NonNull::new(elements) {
+            Some(elements) => {
+                self.elements = elements.cast::<T>();
+                self.capacity = new_capacity;
+                true
+            },
+            None => false,
+        }
+    }
+}
+
 impl<T> Drop for Heap<T> {
     fn drop(&mut self) {
         if self.capacity > 0 {
@@ -274,4 +325,39 @@ mod tests {
             run_test(cap);
         }
     }
+
+    #[test]
+    fn try_grow() {
+        for capacity in [0, 1, 3, 8] {
+            let mut instance = Heap::<u32>::new(capacity);
+            for i in 0..capacity {
+                unsafe { instance.element_mut(i).write(i * 10) };
+            }
+            assert!(unsafe { instance.try_grow(capacity * 2 + 1) });
+            assert_eq!(instance.capacity(), capacity * 2 + 1);
+            for i in 0..capacity {
+                assert_eq!(unsafe { instance.element(i).assume_init() }, i * 10);
+            }
+        }
+    }
+
+    #[test]
+    fn try_new_zero_capacity() {
+        // No memory is allocated, the storage is backed by a dangling pointer.
+        let instance = Heap::<u64>::try_new(0).unwrap();
+        assert_eq!(instance.capacity(), 0);
+        assert_eq!(instance.elements, NonNull::dangling());
+        let empty_slice = unsafe { instance.subslice(0, 0) };
+        assert_eq!(empty_slice.len(), 0);
+    }
+
+    #[test]
+    fn try_new_zeroed() {
+        for capacity in [0, 1, 2, 3, 4, 5, 4096] {
+            let instance = Heap::<u8>::try_new_zeroed(capacity).unwrap();
+            assert_eq!(instance.capacity(), capacity);
+            let bytes = unsafe { &*instance.subslice(0, capacity) };
+            assert!(bytes.iter().all(|&b| b == 0));
+        }
+    }
 }
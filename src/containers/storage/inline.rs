@@ -11,7 +11,7 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
-use core::mem::MaybeUninit;
+use core::mem::{ManuallyDrop, MaybeUninit};
 use core::ptr;
 
 use super::Storage;
@@ -27,7 +27,26 @@ pub struct Inline<T, const CAPACITY: usize> {
 impl<T, const CAPACITY: usize> Inline<T, CAPACITY> {
     // Compile-time check. This condition _must_ be referenced in every function that depends on it,
     // otherwise it will be removed during monomorphization.
-    const CHECK_CAPACITY: () = assert!(0 < CAPACITY && CAPACITY <= (u32::MAX as usize));
+    const CHECK_CAPACITY: () = {
+        assert!(CAPACITY > 0, "Inline storage requires a non-zero CAPACITY");
+        assert!(
+            CAPACITY <= u32::MAX as usize,
+            "Inline storage requires CAPACITY <= u32::MAX"
+        );
+    };
+
+    /// Creates a new instance with all elements initialized from the given array.
+    ///
+    /// The array is moved into the storage as a whole, without copying the elements one-by-one.
+    pub fn from_array(array: [T; CAPACITY]) -> Self {
+        let () = Self::CHECK_CAPACITY;
+
+        let array = ManuallyDrop::new(array);
+        // SAFETY: `MaybeUninit<T>` has the same layout as `T`, so `[MaybeUninit<T>; CAPACITY]` has the same layout as `[T; CAPACITY]`.
+        // The original array is wrapped in `ManuallyDrop`, so its elements are only owned by the new instance.
+        let elements = unsafe { ptr::read((&raw const *array).cast::<[MaybeUninit<T>; CAPACITY]>()) };
+        Self { elements }
+    }
 }
 
 impl<T, const CAPACITY: usize> Storage<T> for Inline<T, CAPACITY> {
@@ -274,4 +293,21 @@ mod tests {
         run_test::<4>();
         run_test::<5>();
     }
+
+    #[test]
+    fn try_new_zeroed() {
+        let instance = Inline::<u32, 5>::try_new_zeroed(5).unwrap();
+        let elements = unsafe { &*instance.subslice(0, 5) };
+        assert_eq!(elements, [0; 5]);
+
+        assert!(Inline::<u32, 5>::try_new_zeroed(4).is_none());
+    }
+
+    #[test]
+    fn from_array() {
+        let storage = Inline::<u32, 4>::from_array([1, 2, 3, 4]);
+        assert_eq!(storage.capacity(), 4);
+        let elements = unsafe { &*storage.subslice(0, 4) };
+        assert_eq!(elements, [1, 2, 3, 4]);
+    }
 }
@@ -18,6 +18,7 @@ pub use self::heap::Heap;
 pub use self::inline::Inline;
 
 use core::mem::MaybeUninit;
+use core::ptr;
 
 /// Interface to abstract over element storage kinds.
 ///
@@ -40,6 +41,23 @@ pub trait Storage<T> {
     where
         Self: Sized;
 
+    /// Tries to create a new instance with enough capacity for the given number of elements,
+    /// with all bytes of the elements set to zero.
+    ///
+    /// Returns `None` if the allocation failed for any reason.
+    fn try_new_zeroed(capacity: u32) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let mut instance = Self::try_new(capacity)?;
+        let capacity = instance.capacity();
+        // SAFETY: `0 <= capacity <= self.capacity()`
+        let elements = unsafe { instance.subslice_mut(0, capacity) };
+        // SAFETY: `elements` points to `capacity` elements of the storage, which are valid for writes
+        unsafe { ptr::write_bytes(elements.cast::<T>(), 0, capacity as usize) };
+        Some(instance)
+    }
+
     /// Returns the allocated capacity.
     fn capacity(&self) -> u32;
 
@@ -72,12 +90,24 @@ pub trait Storage<T> {
     unsafe fn subslice_mut(&mut self, start: u32, end: u32) -> *mut [T];
 }
 
+/// Interface for storage kinds that can be reallocated with a larger capacity.
+pub trait GrowableStorage<T>: Storage<T> {
+    /// Tries to grow the storage to hold `new_capacity` elements.
+    ///
+    /// The elements at indices `0..self.capacity()` keep their values and positions.
+    /// Returns `false` if the allocation failed, in which case the storage is left unchanged.
+    ///
+    /// # Safety
+    ///
+    /// `new_capacity >= self.capacity()` must hold.
+    unsafe fn try_grow(&mut self, new_capacity: u32) -> bool;
+}
+
 #[cfg(test)]
 mod test_utils {
     //! A simple impl of [`Storage`] for [`Vec`], to be used for tests of generic containers.
 
     use super::*;
-    use core::ptr;
 
     impl<T> Storage<T> for Vec<MaybeUninit<T>> {
         fn new(capacity: u32) -> Self {
@@ -0,0 +1,23 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! Tests for diagnostics of errors detected at compile time.
+
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    // Capacity checks are evaluated during monomorphization, which `cargo check` skips.
+    // With at least one `pass` case, trybuild builds all cases instead of only checking them.
+    t.pass("tests/ui/pass/*.rs");
+    t.compile_fail("tests/ui/fail/*.rs");
+}
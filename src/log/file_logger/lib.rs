@@ -0,0 +1,347 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! File Rust backend for `score_log`.
+//! Records are written to a file, which is rotated when it exceeds the size limit.
+
+use score_log::fmt::{score_write, FmtWriteAdapter};
+use score_log::{LevelFilter, Log, Metadata, Record};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+
+/// Builder for the `FileLogger`.
+pub struct FileLoggerBuilder {
+    path: PathBuf,
+    context: String,
+    log_level: LevelFilter,
+    max_bytes: u64,
+    max_files: usize,
+}
+
+impl FileLoggerBuilder {
+    /// Create builder writing to the provided file path, with default parameters.
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            context: "DFLT".to_string(),
+            log_level: LevelFilter::Info,
+            max_bytes: 10 * 1024 * 1024,
+            max_files: 5,
+        }
+    }
+
+    /// Set context for the `FileLogger`.
+    pub fn context(mut self, context: &str) -> Self {
+        self.context = context.to_string();
+        self
+    }
+
+    /// Filter logs by level.
+    pub fn log_level(mut self, log_level: LevelFilter) -> Self {
+        self.log_level = log_level;
+        self
+    }
+
+    /// Maximum size of the log file in bytes.
+    ///
+    /// File is rotated before a line which would exceed the limit is written.
+    /// Lines are never split, a single line longer than the limit is written to an empty file.
+    pub fn max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Maximum number of rotated files kept.
+    ///
+    /// Rotated files are named by appending an index to the file path, e.g., `app.log.1`, `app.log.2`.
+    /// The lowest index contains the most recent records.
+    /// Log file is truncated on rotation if set to zero.
+    pub fn max_files(mut self, max_files: usize) -> Self {
+        self.max_files = max_files;
+        self
+    }
+
+    /// Build the `FileLogger` with provided configuration.
+    ///
+    /// Records are appended if the file already exists.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the file cannot be opened.
+    pub fn build(self) -> io::Result<FileLogger> {
+        let file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        let written = file.metadata()?.len();
+        Ok(FileLogger {
+            context: self.context,
+            log_level: self.log_level,
+            output: Mutex::new(Output {
+                path: self.path,
+                writer: BufWriter::new(file),
+                written,
+                max_bytes: self.max_bytes,
+                max_files: self.max_files,
+            }),
+        })
+    }
+}
+
+/// Output file state.
+struct Output {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    written: u64,
+    max_bytes: u64,
+    max_files: usize,
+}
+
+impl Output {
+    /// Path of the rotated file with provided index.
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{index}"));
+        PathBuf::from(path)
+    }
+
+    /// Rotate files and start writing to an empty file.
+    fn rotate(&mut self) -> io::Result<()> {
+        self.writer.flush()?;
+
+        if self.max_files > 0 {
+            // Remove the oldest file, then shift the remaining ones.
+            let oldest = self.rotated_path(self.max_files);
+            if oldest.exists() {
+                fs::remove_file(oldest)?;
+            }
+            for index in (1..self.max_files).rev() {
+                let from = self.rotated_path(index);
+                if from.exists() {
+                    fs::rename(from, self.rotated_path(index + 1))?;
+                }
+            }
+            fs::rename(&self.path, self.rotated_path(1))?;
+        }
+
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&self.path)?;
+        self.writer = BufWriter::new(file);
+        self.written = 0;
+        Ok(())
+    }
+
+    /// Write a single line, rotating files if required.
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        let len = line.len() as u64 + 1;
+        if self.written > 0 && self.written + len > self.max_bytes {
+            self.rotate()?;
+        }
+        self.writer.write_all(line.as_bytes())?;
+        self.writer.write_all(b"\n")?;
+        self.written += len;
+        Ok(())
+    }
+}
+
+/// File logger implementation.
+///
+/// Lines are written under a lock, lines logged from multiple threads are not interleaved.
+pub struct FileLogger {
+    context: String,
+    log_level: LevelFilter,
+    output: Mutex<Output>,
+}
+
+impl FileLogger {
+    /// Current log level.
+    pub fn log_level(&self) -> LevelFilter {
+        self.log_level
+    }
+
+    /// Write log line for the record into the buffer.
+    fn write_record(&self, buf: &mut String, record: &Record) {
+        let mut writer = FmtWriteAdapter::new(buf);
+        let pid = std::process::id();
+        let _ = score_write!(
+            &mut writer,
+            "[{}][{}][{}] {}",
+            pid,
+            record.context(),
+            record.level().as_str(),
+            record.args()
+        );
+    }
+}
+
+impl Log for FileLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.log_level
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        self.log_level
+    }
+
+    fn context(&self) -> &str {
+        &self.context
+    }
+
+    fn log(&self, record: &Record) {
+        // Finish early if not enabled for requested level.
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        // Render line before locking the output.
+        let mut line = String::new();
+        self.write_record(&mut line, record);
+
+        let mut output = self.output.lock().unwrap_or_else(PoisonError::into_inner);
+        let _ = output.write_line(&line);
+    }
+
+    fn flush(&self) {
+        let mut output = self.output.lock().unwrap_or_else(PoisonError::into_inner);
+        if output.writer.flush().is_ok() {
+            let _ = output.writer.get_ref().sync_all();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use score_log::{format_args, Level};
+    use std::sync::Arc;
+
+    /// Create an empty directory for the test.
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("file_logger_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn log_line(logger: &FileLogger, value: usize) {
+        let args = format_args!("line_{}", value);
+        let record = Record::new(args, Metadata::new(Level::Info, "CTX"), "module", "file.rs", 42);
+        logger.log(&record);
+    }
+
+    fn expected_line(value: usize) -> String {
+        format!("[{}][CTX][INFO] line_{}\n", std::process::id(), value)
+    }
+
+    #[test]
+    fn test_write_and_flush() {
+        let dir = test_dir("write");
+        let path = dir.join("app.log");
+        let logger = FileLoggerBuilder::new(&path).build().unwrap();
+
+        log_line(&logger, 1);
+        log_line(&logger, 2);
+        logger.flush();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert_eq!(content, expected_line(1) + &expected_line(2));
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_rotation() {
+        let dir = test_dir("rotation");
+        let path = dir.join("app.log");
+        let line_len = expected_line(0).len() as u64;
+        let logger = FileLoggerBuilder::new(&path)
+            .max_bytes(3 * line_len)
+            .max_files(2)
+            .build()
+            .unwrap();
+
+        // Three lines fit into a file, fourth one triggers rotation.
+        for i in 0..4 {
+            log_line(&logger, i);
+        }
+        logger.flush();
+
+        let rotated = fs::read_to_string(dir.join("app.log.1")).unwrap();
+        assert_eq!(rotated, expected_line(0) + &expected_line(1) + &expected_line(2));
+        assert_eq!(fs::read_to_string(&path).unwrap(), expected_line(3));
+        assert!(!dir.join("app.log.2").exists());
+
+        // Oldest files are removed once limit is reached.
+        for i in 4..10 {
+            log_line(&logger, i);
+        }
+        logger.flush();
+        assert_eq!(fs::read_to_string(&path).unwrap(), expected_line(9));
+        assert_eq!(
+            fs::read_to_string(dir.join("app.log.1")).unwrap(),
+            expected_line(6) + &expected_line(7) + &expected_line(8)
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("app.log.2")).unwrap(),
+            expected_line(3) + &expected_line(4) + &expected_line(5)
+        );
+        assert!(!dir.join("app.log.3").exists());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_concurrent_lines_not_interleaved() {
+        let dir = test_dir("concurrent");
+        let path = dir.join("app.log");
+        let logger = Arc::new(FileLoggerBuilder::new(&path).build().unwrap());
+
+        let handles: Vec<_> = (0..4)
+            .map(|t| {
+                let logger = logger.clone();
+                std::thread::spawn(move || {
+                    for i in 0..100 {
+                        log_line(&logger, t * 1000 + i);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        logger.flush();
+
+        let content = fs::read_to_string(&path).unwrap();
+        let prefix = format!("[{}][CTX][INFO] line_", std::process::id());
+        assert_eq!(content.lines().count(), 400);
+        assert!(content.lines().all(|line| line
+            .strip_prefix(&prefix)
+            .is_some_and(|value| value.parse::<usize>().is_ok())));
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_enabled() {
+        let dir = test_dir("enabled");
+        let logger = FileLoggerBuilder::new(dir.join("app.log"))
+            .context("FILE")
+            .log_level(LevelFilter::Warn)
+            .build()
+            .unwrap();
+        assert_eq!(logger.context(), "FILE");
+        assert_eq!(logger.max_level(), LevelFilter::Warn);
+        assert!(logger.enabled(&Metadata::new(Level::Error, "CTX")));
+        assert!(!logger.enabled(&Metadata::new(Level::Info, "CTX")));
+        let _ = fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,332 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! JSON-lines Rust backend for `score_log`.
+//! Each record is written to stdout as a single-line JSON object.
+
+use core::cell::RefCell;
+use core::fmt::Write;
+use score_log::fmt::{write, FmtWriteAdapter, FormatSpec};
+use score_log::{LevelFilter, Log, Metadata, Record};
+use std::time::UNIX_EPOCH;
+
+/// Writer escaping data as JSON string contents.
+struct JsonEscaper<'a>(&'a mut String);
+
+impl Write for JsonEscaper<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for c in s.chars() {
+            match c {
+                '"' => self.0.push_str("\\\""),
+                '\\' => self.0.push_str("\\\\"),
+                '\n' => self.0.push_str("\\n"),
+                '\r' => self.0.push_str("\\r"),
+                '\t' => self.0.push_str("\\t"),
+                c if c.is_control() && (c as u32) < 0x20 => write!(self.0, "\\u{:04x}", c as u32)?,
+                c => self.0.push(c),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Builder for the `JsonLogger`.
+pub struct JsonLoggerBuilder(JsonLogger);
+
+impl JsonLoggerBuilder {
+    /// Create builder with default parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set context for the `JsonLogger`.
+    pub fn context(mut self, context: &str) -> Self {
+        self.0.context = context.to_string();
+        self
+    }
+
+    /// Filter logs by level.
+    pub fn log_level(mut self, log_level: LevelFilter) -> Self {
+        self.0.log_level = log_level;
+        self
+    }
+
+    /// Build the `JsonLogger` with provided context and configuration.
+    pub fn build(self) -> JsonLogger {
+        self.0
+    }
+
+    /// Build the `JsonLogger` and set it as the default logger.
+    pub fn set_as_default_logger(self) {
+        if let Err(e) = self.try_set_as_default_logger() {
+            panic!("unable to set logger: {e}");
+        }
+    }
+
+    /// Build the `JsonLogger` and try to set it as the default logger.
+    pub fn try_set_as_default_logger(self) -> Result<(), score_log::SetLoggerError> {
+        let logger = self.build();
+        let level = logger.log_level();
+        score_log::set_global_logger(Box::new(logger))?;
+        score_log::set_max_level(level);
+        Ok(())
+    }
+}
+
+impl Default for JsonLoggerBuilder {
+    fn default() -> Self {
+        Self(JsonLogger {
+            context: "DFLT".to_string(),
+            log_level: LevelFilter::Info,
+        })
+    }
+}
+
+thread_local! {
+    static BUFFER: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// JSON-lines logger implementation.
+///
+/// Each record is written as an object with `level`, `context`, `module`, `file`, `line` and `msg` fields.
+/// Structured key-value fields of the record are written as string fields of a nested `fields` object, before `msg`.
+/// Nesting keeps field keys from colliding with the fixed keys.
+pub struct JsonLogger {
+    context: String,
+    log_level: LevelFilter,
+}
+
+impl JsonLogger {
+    /// Current log level.
+    pub fn log_level(&self) -> LevelFilter {
+        self.log_level
+    }
+
+    /// Write JSON object for the record into the buffer.
+    fn write_record(&self, buf: &mut String, record: &Record) {
+        fn write_field(buf: &mut String, name: &str, value: &str) {
+            buf.push('"');
+            buf.push_str(name);
+            buf.push_str("\":\"");
+            let _ = JsonEscaper(buf).write_str(value);
+            buf.push_str("\",");
+        }
+
+        buf.push('{');
+        write_field(buf, "level", record.level().as_str());
+        write_field(buf, "context", record.context());
+        write_field(buf, "module", record.module_path());
+        write_field(buf, "file", record.file());
+        let _ = write!(buf, "\"line\":{},", record.line());
+
+        // Timestamp is written as seconds since the Unix epoch, only if captured with the record.
+        if let Some(Ok(since_epoch)) = record.timestamp().map(|t| t.duration_since(UNIX_EPOCH)) {
+            let _ = write!(
+                buf,
+                "\"timestamp\":{}.{:09},",
+                since_epoch.as_secs(),
+                since_epoch.subsec_nanos()
+            );
+        }
+
+        // Field values are rendered directly into escaped string fields of the nested object, if any.
+        let mut fields = record.fields_iter().peekable();
+        if fields.peek().is_some() {
+            buf.push_str("\"fields\":{");
+            for (i, (key, value)) in fields.enumerate() {
+                if i > 0 {
+                    buf.push(',');
+                }
+                buf.push('"');
+                let _ = JsonEscaper(buf).write_str(key);
+                buf.push_str("\":\"");
+                let mut writer = FmtWriteAdapter::new(JsonEscaper(buf));
+                let _ = value.fmt(&mut writer, &FormatSpec::new());
+                buf.push('"');
+            }
+            buf.push_str("},");
+        }
+
+        // Message is rendered directly into the escaped string field.
+        buf.push_str("\"msg\":\"");
+        let mut writer = FmtWriteAdapter::new(JsonEscaper(buf));
+        let _ = write(&mut writer, *record.args());
+        buf.push_str("\"}");
+    }
+}
+
+impl Log for JsonLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.log_level
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        self.log_level
+    }
+
+    fn context(&self) -> &str {
+        &self.context
+    }
+
+    fn log(&self, record: &Record) {
+        // Finish early if not enabled for requested level.
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        // Operate in a scope of borrowed buffer.
+        BUFFER.with_borrow_mut(|buf| {
+            self.write_record(buf, record);
+            println!("{}", buf);
+            buf.clear();
+        });
+    }
+
+    fn flush(&self) {
+        use std::io::Write;
+        let mut stdout = std::io::stdout();
+        let _ = stdout.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use score_log::fmt::ScoreDebug;
+    use score_log::{format_args, Level};
+    use std::time::SystemTime;
+
+    fn write_record_at(
+        level: Level,
+        args: score_log::fmt::Arguments,
+        context: &str,
+        file: &str,
+        timestamp: Option<SystemTime>,
+        fields: &[(&str, &dyn ScoreDebug)],
+    ) -> String {
+        let logger = JsonLoggerBuilder::new().log_level(LevelFilter::Trace).build();
+        let record = Record::builder()
+            .args(args)
+            .metadata(Metadata::new(level, context))
+            .module_path("module::path")
+            .file(file)
+            .line(42)
+            .timestamp(timestamp)
+            .fields(fields)
+            .build();
+        let mut buf = String::new();
+        logger.write_record(&mut buf, &record);
+
+        // Every record must be a valid JSON object.
+        let value: serde_json::Value = serde_json::from_str(&buf).expect("invalid JSON");
+        assert!(value.is_object());
+        buf
+    }
+
+    fn write_record(level: Level, args: score_log::fmt::Arguments, context: &str, file: &str) -> String {
+        write_record_at(level, args, context, file, None, &[])
+    }
+
+    #[test]
+    fn test_write_record() {
+        let line = write_record(Level::Warn, format_args!("test_{}_{}", 123, true), "CTX", "file.rs");
+        assert_eq!(
+            line,
+            r#"{"level":"WARN","context":"CTX","module":"module::path","file":"file.rs","line":42,"msg":"test_123_true"}"#
+        );
+    }
+
+    #[test]
+    fn test_write_record_escaping() {
+        let line = write_record(
+            Level::Info,
+            format_args!("quote_\"_{}_newline_\n_tab_\t_{}", "back\\slash", "\u{1}"),
+            "C\"TX",
+            "dir\\file.rs",
+        );
+        assert_eq!(
+            line,
+            r#"{"level":"INFO","context":"C\"TX","module":"module::path","file":"dir\\file.rs","line":42,"msg":"quote_\"_back\\slash_newline_\n_tab_\t_\u0001"}"#
+        );
+    }
+
+    #[test]
+    fn test_write_record_timestamp() {
+        let timestamp = UNIX_EPOCH + core::time::Duration::from_millis(1_500);
+        let line = write_record_at(
+            Level::Info,
+            format_args!("test"),
+            "CTX",
+            "file.rs",
+            Some(timestamp),
+            &[],
+        );
+        assert_eq!(
+            line,
+            r#"{"level":"INFO","context":"CTX","module":"module::path","file":"file.rs","line":42,"timestamp":1.500000000,"msg":"test"}"#
+        );
+    }
+
+    #[test]
+    fn test_write_record_fields() {
+        let fields: [(&str, &dyn ScoreDebug); 4] = [
+            ("id", &42u32),
+            ("name", &"sensor \"A\"\n"),
+            ("valid", &true),
+            ("k\\ey", &-1.5f64),
+        ];
+        let line = write_record_at(Level::Info, format_args!("test"), "CTX", "file.rs", None, &fields);
+        assert_eq!(
+            line,
+            r#"{"level":"INFO","context":"CTX","module":"module::path","file":"file.rs","line":42,"fields":{"id":"42","name":"sensor \"A\"\n","valid":"true","k\\ey":"-1.5"},"msg":"test"}"#
+        );
+
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["fields"]["id"], "42");
+        assert_eq!(value["fields"]["name"], "sensor \"A\"\n");
+        assert_eq!(value["fields"]["valid"], "true");
+        assert_eq!(value["fields"]["k\\ey"], "-1.5");
+        assert_eq!(value["msg"], "test");
+    }
+
+    #[test]
+    fn test_write_record_reserved_field_keys() {
+        let fields: [(&str, &dyn ScoreDebug); 3] = [("msg", &"field"), ("level", &"custom"), ("line", &7u32)];
+        let line = write_record_at(Level::Info, format_args!("message"), "CTX", "file.rs", None, &fields);
+        assert_eq!(
+            line,
+            r#"{"level":"INFO","context":"CTX","module":"module::path","file":"file.rs","line":42,"fields":{"msg":"field","level":"custom","line":"7"},"msg":"message"}"#
+        );
+
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["msg"], "message");
+        assert_eq!(value["level"], "INFO");
+        assert_eq!(value["line"], 42);
+        assert_eq!(value["fields"]["msg"], "field");
+        assert_eq!(value["fields"]["level"], "custom");
+        assert_eq!(value["fields"]["line"], "7");
+    }
+
+    #[test]
+    fn test_enabled() {
+        let logger = JsonLoggerBuilder::new()
+            .context("JSON")
+            .log_level(LevelFilter::Warn)
+            .build();
+        assert_eq!(logger.context(), "JSON");
+        assert_eq!(logger.log_level(), LevelFilter::Warn);
+        assert_eq!(logger.max_level(), LevelFilter::Warn);
+        assert!(logger.enabled(&Metadata::new(Level::Warn, "CTX")));
+        assert!(!logger.enabled(&Metadata::new(Level::Info, "CTX")));
+    }
+}
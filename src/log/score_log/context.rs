@@ -0,0 +1,132 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! Thread-local context stack.
+
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::marker::PhantomData;
+use std::sync::{PoisonError, RwLock};
+
+thread_local! {
+    static CONTEXT_STACK: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Context used by the logging macros when the logger provides an empty one.
+static DEFAULT_CONTEXT: RwLock<&'static str> = RwLock::new("");
+
+/// Guard returned by [`push_context`].
+///
+/// Restores the previous context of the current thread when dropped.
+#[must_use = "context is popped immediately if the guard is not held"]
+pub struct ContextGuard {
+    /// Stack depth before the context was pushed.
+    depth: usize,
+    /// Guard must be dropped on the thread it was created on.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        // Truncate instead of pop, so that guards dropped out of order also remove nested contexts.
+        CONTEXT_STACK.with_borrow_mut(|stack| stack.truncate(self.depth));
+    }
+}
+
+/// Sets the context used by the logging macros on the current thread until the returned guard is dropped.
+///
+/// The context applies to macro invocations without an explicit `context` argument,
+/// taking precedence over the context of the logger.
+///
+/// ```
+/// use score_log::{info, push_context};
+///
+/// {
+///     let _guard = push_context("NET");
+///     info!("logged with NET context");
+/// }
+/// info!("logged with logger context");
+/// ```
+pub fn push_context(context: &'static str) -> ContextGuard {
+    let depth = CONTEXT_STACK.with_borrow_mut(|stack| {
+        stack.push(context);
+        stack.len() - 1
+    });
+    ContextGuard {
+        depth,
+        _not_send: PhantomData,
+    }
+}
+
+/// Returns the context most recently pushed with [`push_context`] on the current thread, if any.
+pub fn current_context() -> Option<&'static str> {
+    CONTEXT_STACK.with_borrow(|stack| stack.last().copied())
+}
+
+/// Sets the context used by the logging macros in place of an empty logger context,
+/// e.g. of the no-op logger used before the global logger is set.
+///
+/// Applies to all threads, to macro invocations without an explicit `context` argument
+/// and without a context set with [`push_context`].
+///
+/// ```
+/// score_log::set_default_context("APP");
+/// assert_eq!(score_log::default_context(), "APP");
+/// ```
+pub fn set_default_context(context: &'static str) {
+    *DEFAULT_CONTEXT.write().unwrap_or_else(PoisonError::into_inner) = context;
+}
+
+/// Returns the context set with [`set_default_context`], or an empty string if none is set.
+pub fn default_context() -> &'static str {
+    *DEFAULT_CONTEXT.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_guards() {
+        assert_eq!(current_context(), None);
+        {
+            let _outer = push_context("OUTER");
+            assert_eq!(current_context(), Some("OUTER"));
+            {
+                let _inner = push_context("INNER");
+                assert_eq!(current_context(), Some("INNER"));
+            }
+            assert_eq!(current_context(), Some("OUTER"));
+        }
+        assert_eq!(current_context(), None);
+    }
+
+    #[test]
+    fn test_out_of_order_drop() {
+        let outer = push_context("OUTER");
+        let inner = push_context("INNER");
+        drop(outer);
+        assert_eq!(current_context(), None);
+        drop(inner);
+        assert_eq!(current_context(), None);
+    }
+
+    #[test]
+    fn test_thread_local() {
+        let _guard = push_context("MAIN");
+        std::thread::spawn(|| assert_eq!(current_context(), None))
+            .join()
+            .unwrap();
+        assert_eq!(current_context(), Some("MAIN"));
+    }
+}
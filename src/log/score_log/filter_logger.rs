@@ -0,0 +1,250 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! Logger filtering records by context and target before forwarding them to an inner logger.
+
+use crate::{LevelFilter, Log, Metadata, Record};
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Logger suppressing records based on their context and target.
+///
+/// Records with a denied context are suppressed.
+/// If any context is allowed, records with context which is not allowed are suppressed as well.
+/// Deny takes precedence over allow.
+/// Targets are filtered the same way, but only for records that have a target.
+/// Remaining records are forwarded to the inner logger, which performs its own filtering.
+pub struct FilterLogger {
+    inner: Box<dyn Log>,
+    allowed: Vec<String>,
+    denied: Vec<String>,
+    allowed_targets: Vec<String>,
+    denied_targets: Vec<String>,
+}
+
+impl FilterLogger {
+    /// Create [`FilterLogger`] forwarding all records to the inner logger.
+    pub fn new(inner: Box<dyn Log>) -> Self {
+        Self {
+            inner,
+            allowed: Vec::new(),
+            denied: Vec::new(),
+            allowed_targets: Vec::new(),
+            denied_targets: Vec::new(),
+        }
+    }
+
+    /// Create [`FilterLoggerBuilder`] with provided inner logger.
+    pub fn builder<L: Log + 'static>(inner: L) -> FilterLoggerBuilder {
+        FilterLoggerBuilder::new(Box::new(inner))
+    }
+
+    /// Inner logger.
+    pub fn inner(&self) -> &dyn Log {
+        self.inner.as_ref()
+    }
+
+    /// Check if records with provided context are forwarded.
+    pub fn context_allowed(&self, context: &str) -> bool {
+        name_allowed(&self.allowed, &self.denied, context)
+    }
+
+    /// Check if records with provided target are forwarded.
+    ///
+    /// Records without target are not affected by target filters.
+    pub fn target_allowed(&self, target: Option<&str>) -> bool {
+        target.is_none_or(|target| name_allowed(&self.allowed_targets, &self.denied_targets, target))
+    }
+
+    /// Check if records with provided metadata are forwarded.
+    fn metadata_allowed(&self, metadata: &Metadata) -> bool {
+        self.context_allowed(metadata.context()) && self.target_allowed(metadata.target())
+    }
+}
+
+/// Check name against allow and deny lists, with deny taking precedence.
+fn name_allowed(allowed: &[String], denied: &[String], name: &str) -> bool {
+    if denied.iter().any(|denied| denied == name) {
+        return false;
+    }
+    allowed.is_empty() || allowed.iter().any(|allowed| allowed == name)
+}
+
+impl Log for FilterLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.metadata_allowed(metadata) && self.inner.enabled(metadata)
+    }
+
+    fn context(&self) -> &str {
+        self.inner.context()
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        self.inner.max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.metadata_allowed(record.metadata()) {
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+/// Builder for the [`FilterLogger`].
+pub struct FilterLoggerBuilder(FilterLogger);
+
+impl FilterLoggerBuilder {
+    /// Create builder with provided inner logger and no filters.
+    pub fn new(inner: Box<dyn Log>) -> Self {
+        Self(FilterLogger::new(inner))
+    }
+
+    /// Allow records with provided context.
+    ///
+    /// Once any context is allowed, records with other contexts are suppressed.
+    pub fn allow(mut self, context: &str) -> Self {
+        self.0.allowed.push(context.to_string());
+        self
+    }
+
+    /// Deny records with provided context.
+    pub fn deny(mut self, context: &str) -> Self {
+        self.0.denied.push(context.to_string());
+        self
+    }
+
+    /// Allow records with provided target.
+    ///
+    /// Once any target is allowed, records with other targets are suppressed.
+    /// Records without target are not affected.
+    pub fn allow_target(mut self, target: &str) -> Self {
+        self.0.allowed_targets.push(target.to_string());
+        self
+    }
+
+    /// Deny records with provided target.
+    pub fn deny_target(mut self, target: &str) -> Self {
+        self.0.denied_targets.push(target.to_string());
+        self
+    }
+
+    /// Build the [`FilterLogger`] with provided filters.
+    pub fn build(self) -> FilterLogger {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{record_with, StubLogger};
+    use crate::Level;
+    use core::sync::atomic::Ordering;
+
+    fn log_with_context(logger: &dyn Log, context: &str) {
+        record_with(context, |record| logger.log(record));
+    }
+
+    #[test]
+    fn test_pass_through() {
+        let (inner, counters) = StubLogger::new("STUB", LevelFilter::Info);
+        let logger = FilterLogger::new(Box::new(inner));
+
+        assert!(logger.enabled(&Metadata::new(Level::Info, "ANY")));
+        assert!(!logger.enabled(&Metadata::new(Level::Debug, "ANY")));
+        assert_eq!(logger.context(), "STUB");
+        assert_eq!(logger.inner().context(), "STUB");
+        assert_eq!(logger.max_level(), LevelFilter::Info);
+
+        log_with_context(&logger, "ANY");
+        logger.flush();
+        assert_eq!(counters.log.load(Ordering::Relaxed), 1);
+        assert_eq!(counters.flush.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_deny() {
+        let (inner, counters) = StubLogger::new("STUB", LevelFilter::Info);
+        let logger = FilterLogger::builder(inner).deny("NOISY").build();
+
+        assert!(!logger.enabled(&Metadata::new(Level::Fatal, "NOISY")));
+        assert!(logger.enabled(&Metadata::new(Level::Info, "OTHER")));
+
+        log_with_context(&logger, "NOISY");
+        assert_eq!(counters.log.load(Ordering::Relaxed), 0);
+        log_with_context(&logger, "OTHER");
+        assert_eq!(counters.log.load(Ordering::Relaxed), 1);
+
+        // Flush is forwarded unconditionally.
+        logger.flush();
+        assert_eq!(counters.flush.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_allow() {
+        let (inner, counters) = StubLogger::new("STUB", LevelFilter::Info);
+        let logger = FilterLogger::builder(inner).allow("NET").allow("DB").build();
+
+        assert!(logger.context_allowed("NET"));
+        assert!(logger.context_allowed("DB"));
+        assert!(!logger.context_allowed("OTHER"));
+        assert!(!logger.context_allowed("NETWORK"));
+
+        for context in ["NET", "DB", "OTHER"] {
+            log_with_context(&logger, context);
+        }
+        assert_eq!(counters.log.load(Ordering::Relaxed), 2);
+    }
+
+    fn log_with_target(logger: &dyn Log, target: Option<&str>) {
+        let record = Record::builder().context("CTX").target(target).build();
+        logger.log(&record);
+    }
+
+    #[test]
+    fn test_target() {
+        let (inner, counters) = StubLogger::new("STUB", LevelFilter::Info);
+        let logger = FilterLogger::builder(inner)
+            .allow_target("net")
+            .allow_target("db")
+            .deny_target("db")
+            .build();
+
+        assert!(logger.target_allowed(Some("net")));
+        assert!(!logger.target_allowed(Some("db")));
+        assert!(!logger.target_allowed(Some("other")));
+        assert!(logger.target_allowed(None));
+
+        let metadata = Metadata::builder().context("CTX").target(Some("other")).build();
+        assert!(!logger.enabled(&metadata));
+        assert!(logger.enabled(&Metadata::new(Level::Info, "CTX")));
+
+        for target in [Some("net"), Some("db"), Some("other"), None] {
+            log_with_target(&logger, target);
+        }
+        assert_eq!(counters.log.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn test_deny_precedence() {
+        let (inner, _) = StubLogger::new("STUB", LevelFilter::Info);
+        let logger = FilterLogger::builder(inner).allow("NET").deny("NET").build();
+        assert!(!logger.context_allowed("NET"));
+        assert!(!logger.enabled(&Metadata::new(Level::Info, "NET")));
+    }
+}
@@ -18,23 +18,50 @@
 extern crate alloc;
 
 use core::str::FromStr;
-use core::sync::atomic::{AtomicUsize, Ordering};
-use core::{cmp, mem};
+use core::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+use core::{cmp, mem, ptr};
 pub use score_log_fmt as fmt;
 use score_log_fmt::Arguments;
 pub use score_log_fmt_macro::{score_log_format_args as format_args, ScoreDebug};
-use std::sync::{LazyLock, OnceLock};
+use std::time::SystemTime;
 
 #[macro_use]
 mod macros;
+mod context;
+mod filter_logger;
+mod multi_logger;
+
+pub use context::{current_context, default_context, push_context, set_default_context, ContextGuard};
+pub use filter_logger::{FilterLogger, FilterLoggerBuilder};
+pub use multi_logger::{MultiLogger, MultiLoggerBuilder};
+
+#[cfg(test)]
+mod test_utils;
 
 /// Global logger.
-static LOGGER: OnceLock<Box<dyn Log>> = OnceLock::new();
+///
+/// Points to a leaked `Box<dyn Log>`, or is null if a logger has not been set.
+/// Set loggers are never freed, so references to them are `'static`.
+static LOGGER: AtomicPtr<Box<dyn Log>> = AtomicPtr::new(ptr::null_mut());
 
 static MAX_LOG_LEVEL_FILTER: AtomicUsize = AtomicUsize::new(0);
 
+/// Suppresses the warning printed when the global logger is used before initialization.
+static QUIET_UNINITIALIZED: AtomicBool = AtomicBool::new(false);
+
+/// Set once the uninitialized logger warning has been handled.
+static UNINITIALIZED_WARNED: AtomicBool = AtomicBool::new(false);
+
 static LOG_LEVEL_NAMES: [&str; 7] = ["OFF", "FATAL", "ERROR", "WARN", "INFO", "DEBUG", "TRACE"];
 
+/// Parse numeric level discriminant, which is a single ASCII digit without sign or leading zeros.
+fn parse_level_digit(level: &str) -> Option<usize> {
+    match level.as_bytes() {
+        &[digit @ b'0'..=b'9'] => Some(usize::from(digit - b'0')),
+        _ => None,
+    }
+}
+
 /// An enum representing the available verbosity levels of the logger.
 #[repr(usize)]
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
@@ -67,17 +94,23 @@ impl PartialOrd<LevelFilter> for Level {
     }
 }
 
+/// Accepts level names (case-insensitive) and single digit numeric discriminants (`1` to `6`).
 impl FromStr for Level {
     type Err = ParseLevelError;
     fn from_str(level: &str) -> Result<Level, Self::Err> {
         LOG_LEVEL_NAMES
             .iter()
             .position(|&name| name.eq_ignore_ascii_case(level))
-            .into_iter()
-            .filter(|&idx| idx != 0)
-            .map(|idx| Level::from_usize(idx).unwrap())
-            .next()
-            .ok_or(ParseLevelError(()))
+            .or_else(|| parse_level_digit(level))
+            .and_then(Level::from_usize)
+            .ok_or_else(|| ParseLevelError::new(level))
+    }
+}
+
+impl TryFrom<usize> for Level {
+    type Error = TryFromLevelError;
+    fn try_from(level: usize) -> Result<Level, TryFromLevelError> {
+        Level::from_usize(level).ok_or(TryFromLevelError(()))
     }
 }
 
@@ -106,6 +139,21 @@ impl Level {
         Level::Trace
     }
 
+    /// Iterate through all levels, from the least to the most verbose.
+    ///
+    /// ```
+    /// use score_log::Level;
+    ///
+    /// let levels: Vec<Level> = Level::iter().collect();
+    /// assert_eq!(
+    ///     levels,
+    ///     [Level::Fatal, Level::Error, Level::Warn, Level::Info, Level::Debug, Level::Trace]
+    /// );
+    /// ```
+    pub fn iter() -> impl Iterator<Item = Level> {
+        (1..LOG_LEVEL_NAMES.len()).map(|i| Level::from_usize(i).unwrap())
+    }
+
     /// Converts the [`Level`] to the equivalent [`LevelFilter`].
     #[inline]
     pub fn to_level_filter(&self) -> LevelFilter {
@@ -156,14 +204,23 @@ impl PartialOrd<Level> for LevelFilter {
     }
 }
 
+/// Accepts level names (case-insensitive) and single digit numeric discriminants (`0` to `6`).
 impl FromStr for LevelFilter {
     type Err = ParseLevelError;
     fn from_str(level: &str) -> Result<LevelFilter, Self::Err> {
         LOG_LEVEL_NAMES
             .iter()
             .position(|&name| name.eq_ignore_ascii_case(level))
-            .map(|p| LevelFilter::from_usize(p).unwrap())
-            .ok_or(ParseLevelError(()))
+            .or_else(|| parse_level_digit(level))
+            .and_then(LevelFilter::from_usize)
+            .ok_or_else(|| ParseLevelError::new(level))
+    }
+}
+
+impl TryFrom<usize> for LevelFilter {
+    type Error = TryFromLevelError;
+    fn try_from(level: usize) -> Result<LevelFilter, TryFromLevelError> {
+        LevelFilter::from_usize(level).ok_or(TryFromLevelError(()))
     }
 }
 
@@ -193,6 +250,29 @@ impl LevelFilter {
         LevelFilter::Trace
     }
 
+    /// Iterate through all level filters, from [`LevelFilter::Off`] to the most verbose.
+    ///
+    /// ```
+    /// use score_log::LevelFilter;
+    ///
+    /// let level_filters: Vec<LevelFilter> = LevelFilter::iter().collect();
+    /// assert_eq!(
+    ///     level_filters,
+    ///     [
+    ///         LevelFilter::Off,
+    ///         LevelFilter::Fatal,
+    ///         LevelFilter::Error,
+    ///         LevelFilter::Warn,
+    ///         LevelFilter::Info,
+    ///         LevelFilter::Debug,
+    ///         LevelFilter::Trace
+    ///     ]
+    /// );
+    /// ```
+    pub fn iter() -> impl Iterator<Item = LevelFilter> {
+        (0..LOG_LEVEL_NAMES.len()).map(|i| LevelFilter::from_usize(i).unwrap())
+    }
+
     /// Converts [`LevelFilter`] to the equivalent [`Level`].
     ///
     /// Returns [`None`] if [`LevelFilter`] is [`LevelFilter::Off`].
@@ -209,6 +289,112 @@ impl LevelFilter {
     }
 }
 
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::{Level, LevelFilter, LOG_LEVEL_NAMES};
+    use core::fmt;
+    use core::marker::PhantomData;
+    use core::str::FromStr;
+    use serde::de::{self, Deserialize, Deserializer, Visitor};
+    use serde::ser::{Serialize, Serializer};
+
+    impl Serialize for Level {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl Serialize for LevelFilter {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    /// Visitor parsing level names using [`FromStr`].
+    struct NameVisitor<T> {
+        names: &'static [&'static str],
+        _marker: PhantomData<T>,
+    }
+
+    impl<T> NameVisitor<T> {
+        fn new(names: &'static [&'static str]) -> Self {
+            Self {
+                names,
+                _marker: PhantomData,
+            }
+        }
+    }
+
+    impl<T: FromStr> Visitor<'_> for NameVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a log level name")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            v.parse().map_err(|_| E::unknown_variant(v, self.names))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Level {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            // Skip "OFF".
+            deserializer.deserialize_str(NameVisitor::new(&LOG_LEVEL_NAMES[1..]))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for LevelFilter {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_str(NameVisitor::new(&LOG_LEVEL_NAMES))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_level_round_trip() {
+            for level in Level::iter() {
+                let json = serde_json::to_string(&level).unwrap();
+                assert_eq!(json, format!("\"{}\"", level.as_str()));
+                assert_eq!(serde_json::from_str::<Level>(&json).unwrap(), level);
+            }
+        }
+
+        #[test]
+        fn test_level_filter_round_trip() {
+            for level_filter in LevelFilter::iter() {
+                let json = serde_json::to_string(&level_filter).unwrap();
+                assert_eq!(json, format!("\"{}\"", level_filter.as_str()));
+                assert_eq!(serde_json::from_str::<LevelFilter>(&json).unwrap(), level_filter);
+            }
+        }
+
+        #[test]
+        fn test_deserialize_case_insensitive() {
+            assert_eq!(serde_json::from_str::<Level>("\"debug\"").unwrap(), Level::Debug);
+            assert_eq!(
+                serde_json::from_str::<LevelFilter>("\"Off\"").unwrap(),
+                LevelFilter::Off
+            );
+        }
+
+        #[test]
+        fn test_deserialize_invalid() {
+            let error = serde_json::from_str::<Level>("\"off\"").unwrap_err();
+            assert!(error.to_string().starts_with(
+                "unknown variant `off`, expected one of `FATAL`, `ERROR`, `WARN`, `INFO`, `DEBUG`, `TRACE`"
+            ));
+            assert!(serde_json::from_str::<Level>("\"asdf\"").is_err());
+            assert!(serde_json::from_str::<Level>("4").is_err());
+            assert!(serde_json::from_str::<LevelFilter>("\"asdf\"").is_err());
+            assert!(serde_json::from_str::<LevelFilter>("null").is_err());
+        }
+    }
+}
+
 /// The "payload" of a log message.
 #[derive(Clone)]
 pub struct Record<'a> {
@@ -217,10 +403,14 @@ pub struct Record<'a> {
     module_path: &'a str,
     file: &'a str,
     line: u32,
+    timestamp: Option<SystemTime>,
+    fields: &'a [(&'a str, &'a dyn fmt::ScoreDebug)],
 }
 
 impl<'a> Record<'a> {
     /// Create `Record`.
+    ///
+    /// If the `timestamp` feature is enabled, the current system time is captured as the record timestamp.
     #[inline]
     pub fn new(args: Arguments<'a>, metadata: Metadata<'a>, module_path: &'a str, file: &'a str, line: u32) -> Self {
         Self {
@@ -229,9 +419,17 @@ impl<'a> Record<'a> {
             module_path,
             file,
             line,
+            timestamp: capture_timestamp(),
+            fields: &[],
         }
     }
 
+    /// Create [`RecordBuilder`].
+    #[inline]
+    pub fn builder() -> RecordBuilder<'a> {
+        RecordBuilder::new()
+    }
+
     /// The message body.
     #[inline]
     pub fn args(&self) -> &Arguments<'a> {
@@ -256,6 +454,12 @@ impl<'a> Record<'a> {
         self.metadata.context()
     }
 
+    /// The target of the directive.
+    #[inline]
+    pub fn target(&self) -> Option<&'a str> {
+        self.metadata.target()
+    }
+
     /// The module path of the message.
     #[inline]
     pub fn module_path(&self) -> &'a str {
@@ -273,6 +477,142 @@ impl<'a> Record<'a> {
     pub fn line(&self) -> u32 {
         self.line
     }
+
+    /// The time the record was created at.
+    ///
+    /// Only captured if the `timestamp` feature is enabled.
+    /// Backends should fall back to their own clock if it is not available.
+    #[inline]
+    pub fn timestamp(&self) -> Option<SystemTime> {
+        self.timestamp
+    }
+
+    /// Iterator over the structured key-value fields attached to the message.
+    ///
+    /// Fields are borrowed from the record, so backends can serialize them without cloning.
+    /// Empty if no fields are set.
+    #[inline]
+    pub fn fields_iter(&self) -> impl Iterator<Item = (&'a str, &'a dyn fmt::ScoreDebug)> {
+        self.fields.iter().map(|&(key, value)| (key, value))
+    }
+}
+
+/// Current system time if timestamps are captured.
+#[cfg(feature = "timestamp")]
+#[inline]
+fn capture_timestamp() -> Option<SystemTime> {
+    Some(SystemTime::now())
+}
+
+/// Current system time if timestamps are captured.
+#[cfg(not(feature = "timestamp"))]
+#[inline]
+fn capture_timestamp() -> Option<SystemTime> {
+    None
+}
+
+/// Builder for the [`Record`].
+///
+/// Unset fields default to an empty message, [`Metadata`] built by [`MetadataBuilder`],
+/// empty module path and file, line `0`, no timestamp and no fields.
+pub struct RecordBuilder<'a>(Record<'a>);
+
+impl<'a> RecordBuilder<'a> {
+    /// Create builder with default parameters.
+    #[inline]
+    pub fn new() -> Self {
+        Self(Record {
+            args: Arguments(&[]),
+            metadata: MetadataBuilder::new().build(),
+            module_path: "",
+            file: "",
+            line: 0,
+            timestamp: None,
+            fields: &[],
+        })
+    }
+
+    /// Set message body.
+    #[inline]
+    pub fn args(mut self, args: Arguments<'a>) -> Self {
+        self.0.args = args;
+        self
+    }
+
+    /// Set metadata.
+    #[inline]
+    pub fn metadata(mut self, metadata: Metadata<'a>) -> Self {
+        self.0.metadata = metadata;
+        self
+    }
+
+    /// Set verbosity level of the metadata.
+    #[inline]
+    pub fn level(mut self, level: Level) -> Self {
+        self.0.metadata.level = level;
+        self
+    }
+
+    /// Set context of the metadata.
+    #[inline]
+    pub fn context(mut self, context: &'a str) -> Self {
+        self.0.metadata.context = context;
+        self
+    }
+
+    /// Set target of the metadata.
+    #[inline]
+    pub fn target(mut self, target: Option<&'a str>) -> Self {
+        self.0.metadata.target = target;
+        self
+    }
+
+    /// Set module path.
+    #[inline]
+    pub fn module_path(mut self, module_path: &'a str) -> Self {
+        self.0.module_path = module_path;
+        self
+    }
+
+    /// Set source file.
+    #[inline]
+    pub fn file(mut self, file: &'a str) -> Self {
+        self.0.file = file;
+        self
+    }
+
+    /// Set line.
+    #[inline]
+    pub fn line(mut self, line: u32) -> Self {
+        self.0.line = line;
+        self
+    }
+
+    /// Set timestamp.
+    #[inline]
+    pub fn timestamp(mut self, timestamp: Option<SystemTime>) -> Self {
+        self.0.timestamp = timestamp;
+        self
+    }
+
+    /// Set structured key-value fields.
+    #[inline]
+    pub fn fields(mut self, fields: &'a [(&'a str, &'a dyn fmt::ScoreDebug)]) -> Self {
+        self.0.fields = fields;
+        self
+    }
+
+    /// Build the [`Record`].
+    #[inline]
+    pub fn build(self) -> Record<'a> {
+        self.0
+    }
+}
+
+impl Default for RecordBuilder<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 /// Metadata about a log message.
@@ -280,13 +620,26 @@ impl<'a> Record<'a> {
 pub struct Metadata<'a> {
     level: Level,
     context: &'a str,
+    target: Option<&'a str>,
+    module_path: Option<&'a str>,
 }
 
 impl<'a> Metadata<'a> {
-    /// Create `Metadata`.
+    /// Create `Metadata` without target and module path.
     #[inline]
     pub fn new(level: Level, context: &'a str) -> Self {
-        Self { level, context }
+        Self {
+            level,
+            context,
+            target: None,
+            module_path: None,
+        }
+    }
+
+    /// Create [`MetadataBuilder`].
+    #[inline]
+    pub fn builder() -> MetadataBuilder<'a> {
+        MetadataBuilder::new()
     }
 
     /// The verbosity level of the message.
@@ -300,6 +653,73 @@ impl<'a> Metadata<'a> {
     pub fn context(&self) -> &'a str {
         self.context
     }
+
+    /// The target of the directive, used for routing independently of the context.
+    #[inline]
+    pub fn target(&self) -> Option<&'a str> {
+        self.target
+    }
+
+    /// The module path of the directive, if known.
+    ///
+    /// Set by the logging macros, so loggers can filter by module before the message arguments are evaluated.
+    #[inline]
+    pub fn module_path(&self) -> Option<&'a str> {
+        self.module_path
+    }
+}
+
+/// Builder for the [`Metadata`].
+///
+/// Unset fields default to [`Level::Info`], empty context, no target and no module path.
+pub struct MetadataBuilder<'a>(Metadata<'a>);
+
+impl<'a> MetadataBuilder<'a> {
+    /// Create builder with default parameters.
+    #[inline]
+    pub fn new() -> Self {
+        Self(Metadata::new(Level::Info, ""))
+    }
+
+    /// Set verbosity level.
+    #[inline]
+    pub fn level(mut self, level: Level) -> Self {
+        self.0.level = level;
+        self
+    }
+
+    /// Set context.
+    #[inline]
+    pub fn context(mut self, context: &'a str) -> Self {
+        self.0.context = context;
+        self
+    }
+
+    /// Set target.
+    #[inline]
+    pub fn target(mut self, target: Option<&'a str>) -> Self {
+        self.0.target = target;
+        self
+    }
+
+    /// Set module path.
+    #[inline]
+    pub fn module_path(mut self, module_path: Option<&'a str>) -> Self {
+        self.0.module_path = module_path;
+        self
+    }
+
+    /// Build the [`Metadata`].
+    #[inline]
+    pub fn build(self) -> Metadata<'a> {
+        self.0
+    }
+}
+
+impl Default for MetadataBuilder<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 /// A trait encapsulating the operations required of a logger.
@@ -310,6 +730,14 @@ pub trait Log: Sync + Send {
     /// Default logger context name.
     fn context(&self) -> &str;
 
+    /// The most verbose level this logger may log, e.g., to compute a combined level of multiple loggers.
+    ///
+    /// Records with a more verbose level are not logged.
+    /// Defaults to [`LevelFilter::Trace`], i.e., no records are excluded based on level alone.
+    fn max_level(&self) -> LevelFilter {
+        LevelFilter::Trace
+    }
+
     /// Logs the [`Record`].
     ///
     /// # For implementors
@@ -334,6 +762,10 @@ impl Log for NopLogger {
         ""
     }
 
+    fn max_level(&self) -> LevelFilter {
+        LevelFilter::Off
+    }
+
     fn log(&self, _: &Record) {}
 
     fn flush(&self) {}
@@ -348,6 +780,10 @@ impl<T: ?Sized + Log> Log for &'_ T {
         (**self).context()
     }
 
+    fn max_level(&self) -> LevelFilter {
+        (**self).max_level()
+    }
+
     fn log(&self, record: &Record) {
         (**self).log(record);
     }
@@ -366,6 +802,10 @@ impl<T: ?Sized + Log> Log for alloc::boxed::Box<T> {
         self.as_ref().context()
     }
 
+    fn max_level(&self) -> LevelFilter {
+        self.as_ref().max_level()
+    }
+
     fn log(&self, record: &Record) {
         self.as_ref().log(record);
     }
@@ -404,6 +844,7 @@ pub fn max_level() -> LevelFilter {
 ///
 /// This function may only be called once in the lifetime of a program.
 /// Any log events that occur before the call to [`set_global_logger`] completes will be ignored.
+/// Use [`replace_global_logger`] to change an already set logger.
 ///
 /// This function does not typically need to be called manually.
 /// Logger implementations should provide an initialization method that installs the logger internally.
@@ -412,7 +853,36 @@ pub fn max_level() -> LevelFilter {
 ///
 /// An error is returned if a logger has already been set.
 pub fn set_global_logger(logger: Box<dyn Log>) -> Result<(), SetLoggerError> {
-    LOGGER.set(logger).map_err(|_| SetLoggerError(()))
+    let logger = Box::into_raw(Box::new(logger));
+    match LOGGER.compare_exchange(ptr::null_mut(), logger, Ordering::AcqRel, Ordering::Acquire) {
+        Ok(_) => Ok(()),
+        Err(_) => {
+            // SAFETY: pointer was created above and not published, so it's still uniquely owned.
+            drop(unsafe { Box::from_raw(logger) });
+            Err(SetLoggerError(()))
+        },
+    }
+}
+
+/// Replaces the global logger with a `Box<dyn Log>`, returning a forwarding handle to the previous one.
+///
+/// Unlike [`set_global_logger`], this function may be called any number of times.
+/// A no-op logger is returned if a logger has not been set.
+///
+/// References returned by [`global_logger`] are `'static`, so the previous logger is never dropped,
+/// and resources it owns, e.g., open files, are not released.
+/// The previous logger is flushed before returning, so buffered records are not lost.
+/// The returned handle forwards to the previous logger, dropping it has no effect on the logger itself.
+pub fn replace_global_logger(logger: Box<dyn Log>) -> Box<dyn Log> {
+    let previous = LOGGER.swap(Box::into_raw(Box::new(logger)), Ordering::AcqRel);
+    // SAFETY: set loggers are never freed.
+    match unsafe { previous.as_ref() } {
+        Some(previous) => {
+            previous.flush();
+            Box::new(previous)
+        },
+        None => Box::new(NopLogger),
+    }
 }
 
 /// The type returned by [`set_global_logger`] if [`set_global_logger`] has already been called.
@@ -425,24 +895,94 @@ impl core::fmt::Display for SetLoggerError {
 }
 
 /// The type returned by [`core::str::FromStr::from_str`] implementations when the string doesn't match any of the log levels.
-#[derive(PartialEq, Eq)]
-pub struct ParseLevelError(());
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseLevelError(Box<str>);
+
+impl ParseLevelError {
+    /// Create error for the string that failed to parse.
+    pub fn new(input: &str) -> Self {
+        Self(input.into())
+    }
+
+    /// The string that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.0
+    }
+}
 
 impl core::fmt::Display for ParseLevelError {
     fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
-        fmt.write_str("attempted to convert a string that doesn't match an existing log level")
+        write!(fmt, "invalid log level: '{}'", self.0)
+    }
+}
+
+/// The type returned by [`TryFrom<usize>`] implementations when the integer doesn't match any of the log levels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TryFromLevelError(());
+
+impl core::fmt::Display for TryFromLevelError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmt.write_str("attempted to convert an integer that doesn't match an existing log level")
     }
 }
 
+impl core::error::Error for TryFromLevelError {}
+
 /// Returns a reference to the logger.
 ///
 /// If a logger has not been set, a no-op implementation is returned.
+/// A warning is printed to stderr the first time it is returned, unless suppressed with [`set_quiet_uninitialized`].
 pub fn global_logger() -> &'static dyn Log {
-    static NOP_LOGGER: LazyLock<Box<dyn Log>> = LazyLock::new(|| {
-        eprintln!("warn: logger not initialized");
-        Box::new(NopLogger)
-    });
-    LOGGER.get().unwrap_or_else(|| &NOP_LOGGER)
+    static NOP_LOGGER: NopLogger = NopLogger;
+    // SAFETY: set loggers are never freed.
+    match unsafe { LOGGER.load(Ordering::Acquire).as_ref() } {
+        Some(logger) => logger.as_ref(),
+        None => {
+            // Plain load first, so the hot path doesn't write to the shared flag.
+            if !UNINITIALIZED_WARNED.load(Ordering::Relaxed)
+                && !UNINITIALIZED_WARNED.swap(true, Ordering::Relaxed)
+                && !QUIET_UNINITIALIZED.load(Ordering::Relaxed)
+            {
+                eprintln!("warn: logger not initialized");
+            }
+            &NOP_LOGGER
+        },
+    }
+}
+
+/// Suppresses the warning printed to stderr when the global logger is used before it is set.
+///
+/// Useful for tests and libraries, where logging without an initialized logger is expected.
+/// Must be called before the global logger is first used to take effect.
+pub fn set_quiet_uninitialized(quiet: bool) {
+    QUIET_UNINITIALIZED.store(quiet, Ordering::Relaxed);
+}
+
+/// Installs a panic hook flushing the global logger.
+///
+/// The previously installed panic hook is preserved and called before flushing,
+/// so records logged by it are flushed as well.
+///
+/// Panics are not the only way to lose buffered records.
+/// The global logger should also be flushed explicitly on regular program exit.
+///
+/// ```
+/// score_log::install_flush_on_panic();
+///
+/// // Application logic.
+///
+/// score_log::global_logger().flush();
+/// ```
+pub fn install_flush_on_panic() {
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        previous_hook(info);
+
+        // SAFETY: set loggers are never freed.
+        if let Some(logger) = unsafe { LOGGER.load(Ordering::Acquire).as_ref() } {
+            logger.flush();
+        }
+    }));
 }
 
 #[cfg(test)]
@@ -450,14 +990,6 @@ mod tests {
     use super::*;
     use std::collections::HashSet;
 
-    fn level_iter() -> impl Iterator<Item = Level> {
-        (1..7).map(|i| Level::from_usize(i).unwrap())
-    }
-
-    fn level_filter_iter() -> impl Iterator<Item = LevelFilter> {
-        (0..7).map(|i| LevelFilter::from_usize(i).unwrap())
-    }
-
     #[test]
     fn test_level_partial_eq_with_level_filter() {
         // Pairs that should result in true.
@@ -471,9 +1003,9 @@ mod tests {
         ]);
 
         // Iterate through levels.
-        for level in level_iter() {
+        for level in Level::iter() {
             // Iterate through level filters.
-            for level_filter in level_filter_iter() {
+            for level_filter in LevelFilter::iter() {
                 let is_matching = matching_pairs.contains(&(level, level_filter));
                 assert_eq!(level.eq(&level_filter), is_matching);
             }
@@ -483,10 +1015,10 @@ mod tests {
     #[test]
     fn test_level_partial_cmp_with_level_filter() {
         // Iterate through levels.
-        for level in level_iter() {
+        for level in Level::iter() {
             let matching_filter = level.to_level_filter();
             // Iterate through level filters.
-            for level_filter in level_filter_iter() {
+            for level_filter in LevelFilter::iter() {
                 if matching_filter < level_filter {
                     assert!(level < level_filter);
                 } else if matching_filter > level_filter {
@@ -501,7 +1033,7 @@ mod tests {
     #[test]
     fn test_level_from_str() {
         let tests = [
-            ("OFF", Err(ParseLevelError(()))),
+            ("OFF", Err(ParseLevelError::new("OFF"))),
             ("fatal", Ok(Level::Fatal)),
             ("error", Ok(Level::Error)),
             ("warn", Ok(Level::Warn)),
@@ -514,7 +1046,17 @@ mod tests {
             ("INFO", Ok(Level::Info)),
             ("DEBUG", Ok(Level::Debug)),
             ("TRACE", Ok(Level::Trace)),
-            ("asdf", Err(ParseLevelError(()))),
+            ("asdf", Err(ParseLevelError::new("asdf"))),
+            ("1", Ok(Level::Fatal)),
+            ("4", Ok(Level::Info)),
+            ("6", Ok(Level::Trace)),
+            ("0", Err(ParseLevelError::new("0"))),
+            ("7", Err(ParseLevelError::new("7"))),
+            ("-1", Err(ParseLevelError::new("-1"))),
+            ("+4", Err(ParseLevelError::new("+4"))),
+            ("04", Err(ParseLevelError::new("04"))),
+            (" 4", Err(ParseLevelError::new(" 4"))),
+            ("", Err(ParseLevelError::new(""))),
         ];
         for &(s, ref expected) in &tests {
             assert!(expected == &s.parse());
@@ -548,6 +1090,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_level_try_from_usize() {
+        for level in Level::iter() {
+            assert_eq!(Level::try_from(level as usize), Ok(level));
+        }
+        assert_eq!(Level::try_from(0), Err(TryFromLevelError(())));
+        assert_eq!(Level::try_from(7), Err(TryFromLevelError(())));
+        assert_eq!(Level::try_from(usize::MAX), Err(TryFromLevelError(())));
+        assert_eq!(Level::try_from(4).unwrap(), Level::Info);
+        let error: &dyn core::error::Error = &Level::try_from(0).unwrap_err();
+        assert!(error.source().is_none());
+    }
+
+    #[test]
+    fn test_level_iter() {
+        assert_eq!(Level::iter().count(), 6);
+        assert!(Level::iter().zip(Level::iter().skip(1)).all(|(a, b)| a < b));
+        assert_eq!(Level::iter().last(), Some(Level::max()));
+    }
+
     #[test]
     fn test_level_max() {
         assert_eq!(Level::max(), Level::Trace);
@@ -596,9 +1158,9 @@ mod tests {
         ]);
 
         // Iterate through level filters.
-        for level_filter in level_filter_iter() {
+        for level_filter in LevelFilter::iter() {
             // Iterate through levels.
-            for level in level_iter() {
+            for level in Level::iter() {
                 let is_matching = matching_pairs.contains(&(level_filter, level));
                 assert_eq!(level_filter.eq(&level), is_matching)
             }
@@ -609,10 +1171,10 @@ mod tests {
     fn test_level_filter_partial_cmp_with_level() {
         // Iterate through level filters.
         // Skip `LevelFilter::Off`.
-        for level_filter in level_filter_iter().skip(1) {
+        for level_filter in LevelFilter::iter().skip(1) {
             let matching_level = level_filter.to_level().unwrap();
             // Iterate through levels.
-            for level in level_iter() {
+            for level in Level::iter() {
                 if matching_level < level {
                     assert!(level_filter < level);
                 } else if matching_level > level {
@@ -624,6 +1186,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_level_filter_try_from_usize() {
+        for level in LevelFilter::iter() {
+            assert_eq!(LevelFilter::try_from(level as usize), Ok(level));
+        }
+        assert_eq!(LevelFilter::try_from(7), Err(TryFromLevelError(())));
+        assert_eq!(
+            TryFromLevelError(()).to_string(),
+            "attempted to convert an integer that doesn't match an existing log level"
+        );
+    }
+
     #[test]
     fn test_level_filter_from_str() {
         let tests = [
@@ -641,7 +1215,14 @@ mod tests {
             ("INFO", Ok(LevelFilter::Info)),
             ("DEBUG", Ok(LevelFilter::Debug)),
             ("TRACE", Ok(LevelFilter::Trace)),
-            ("asdf", Err(ParseLevelError(()))),
+            ("asdf", Err(ParseLevelError::new("asdf"))),
+            ("0", Ok(LevelFilter::Off)),
+            ("4", Ok(LevelFilter::Info)),
+            ("6", Ok(LevelFilter::Trace)),
+            ("7", Err(ParseLevelError::new("7"))),
+            ("+4", Err(ParseLevelError::new("+4"))),
+            ("04", Err(ParseLevelError::new("04"))),
+            ("00", Err(ParseLevelError::new("00"))),
         ];
         for &(s, ref expected) in &tests {
             assert!(expected == &s.parse());
@@ -676,6 +1257,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_level_filter_iter() {
+        assert_eq!(LevelFilter::iter().count(), 7);
+        assert!(LevelFilter::iter().zip(LevelFilter::iter().skip(1)).all(|(a, b)| a < b));
+        assert_eq!(LevelFilter::iter().next(), Some(LevelFilter::Off));
+        assert_eq!(LevelFilter::iter().last(), Some(LevelFilter::max()));
+    }
+
     #[test]
     fn test_level_filter_max() {
         assert_eq!(LevelFilter::max(), LevelFilter::Trace);
@@ -741,6 +1330,19 @@ mod tests {
         assert_eq!(record.line(), line_num);
     }
 
+    #[test]
+    fn test_record_timestamp() {
+        let before = SystemTime::now();
+        let record = Record::new(Arguments(&[]), Metadata::new(Level::Info, "context"), "", "", 0);
+        let after = SystemTime::now();
+        if cfg!(feature = "timestamp") {
+            let timestamp = record.timestamp().unwrap();
+            assert!(before <= timestamp && timestamp <= after);
+        } else {
+            assert_eq!(record.timestamp(), None);
+        }
+    }
+
     #[test]
     fn test_metadata_new_and_params() {
         let level = Level::Info;
@@ -750,6 +1352,107 @@ mod tests {
         assert_eq!(metadata.context(), context);
     }
 
+    #[test]
+    fn test_record_builder() {
+        // Local import to avoid name clash.
+        use super::format_args;
+        // Fix for self-reference in `score_log_format_args`.
+        use crate as score_log;
+
+        let args = format_args!("test_string_{}", 123);
+        let record = Record::builder()
+            .args(args)
+            .level(Level::Warn)
+            .context("context")
+            .module_path("module_path")
+            .file("file")
+            .line(123)
+            .timestamp(Some(SystemTime::UNIX_EPOCH))
+            .build();
+        assert_eq!(record.args().0.len(), 2);
+        assert_eq!(record.level(), Level::Warn);
+        assert_eq!(record.context(), "context");
+        assert_eq!(record.module_path(), "module_path");
+        assert_eq!(record.file(), "file");
+        assert_eq!(record.line(), 123);
+        assert_eq!(record.timestamp(), Some(SystemTime::UNIX_EPOCH));
+
+        let metadata = Metadata::builder().level(Level::Debug).context("other").build();
+        let record = RecordBuilder::default().metadata(metadata.clone()).build();
+        assert!(*record.metadata() == metadata);
+        assert_eq!(record.args().0.len(), 0);
+        assert_eq!(record.module_path(), "");
+        assert_eq!(record.file(), "");
+        assert_eq!(record.line(), 0);
+        assert_eq!(record.timestamp(), None);
+        assert_eq!(record.fields_iter().count(), 0);
+    }
+
+    #[test]
+    fn test_record_fields_iter() {
+        let record = Record::new(Arguments(&[]), Metadata::new(Level::Info, "context"), "", "", 0);
+        assert!(record.fields_iter().next().is_none());
+
+        let fields: [(&str, &dyn fmt::ScoreDebug); 3] = [("id", &42u32), ("name", &"sensor"), ("valid", &true)];
+        let record = Record::builder().fields(&fields).build();
+        let rendered: Vec<(&str, String)> = record
+            .fields_iter()
+            .map(|(key, value)| {
+                let mut writer = fmt::FmtWriteAdapter::new(String::new());
+                assert!(value.fmt(&mut writer, &fmt::FormatSpec::new()).is_ok());
+                (key, writer.into_inner())
+            })
+            .collect();
+        assert_eq!(
+            rendered,
+            [
+                ("id", "42".to_string()),
+                ("name", "sensor".to_string()),
+                ("valid", "true".to_string())
+            ]
+        );
+
+        // Fields are kept by cloned records.
+        assert_eq!(record.clone().fields_iter().count(), 3);
+    }
+
+    #[test]
+    fn test_metadata_builder() {
+        let metadata = Metadata::builder().level(Level::Trace).context("context").build();
+        assert_eq!(metadata.level(), Level::Trace);
+        assert_eq!(metadata.context(), "context");
+        assert_eq!(metadata.target(), None);
+        assert_eq!(metadata.module_path(), None);
+
+        let metadata = MetadataBuilder::default().build();
+        assert_eq!(metadata.level(), Level::Info);
+        assert_eq!(metadata.context(), "");
+        assert_eq!(metadata.target(), None);
+        assert_eq!(metadata.module_path(), None);
+
+        let metadata = Metadata::builder().module_path(Some("my_crate::net")).build();
+        assert_eq!(metadata.module_path(), Some("my_crate::net"));
+    }
+
+    #[test]
+    fn test_metadata_target() {
+        let metadata = Metadata::new(Level::Info, "context");
+        assert_eq!(metadata.target(), None);
+
+        let with_target = Metadata::builder()
+            .level(Level::Info)
+            .context("context")
+            .target(Some("net::tcp"))
+            .build();
+        assert_eq!(with_target.target(), Some("net::tcp"));
+        assert!(with_target != metadata);
+
+        let record = Record::builder().metadata(with_target).build();
+        assert_eq!(record.target(), Some("net::tcp"));
+        let record = Record::builder().target(None).build();
+        assert_eq!(record.target(), None);
+    }
+
     struct StubLogger<'a> {
         context: &'a str,
     }
@@ -767,9 +1470,8 @@ mod tests {
             unimplemented!()
         }
 
-        fn flush(&self) {
-            unimplemented!()
-        }
+        // Called when replaced.
+        fn flush(&self) {}
     }
 
     #[test]
@@ -801,6 +1503,68 @@ mod tests {
             let old_logger = global_logger();
             assert_eq!(old_logger.context(), StubLogger { context: "ctx1" }.context());
         }
+
+        // Replace logger.
+        {
+            let new_logger = Box::new(StubLogger { context: "ctx3" });
+            let old_logger = replace_global_logger(new_logger);
+            assert_eq!(old_logger.context(), "ctx1");
+
+            // Make sure new logger is active.
+            let logger = global_logger();
+            assert_eq!(logger.context(), "ctx3");
+        }
+
+        // Replace logger for the second time.
+        {
+            let new_logger = Box::new(StubLogger { context: "ctx4" });
+            let old_logger = replace_global_logger(new_logger);
+            assert_eq!(old_logger.context(), "ctx3");
+            assert_eq!(global_logger().context(), "ctx4");
+        }
+
+        // Existing reference keeps the logger it was obtained for.
+        {
+            let handle = global_logger();
+            let old_logger = replace_global_logger(Box::new(StubLogger { context: "ctx5" }));
+            assert_eq!(handle.context(), "ctx4");
+            assert_eq!(old_logger.context(), "ctx4");
+            assert_eq!(global_logger().context(), "ctx5");
+        }
+    }
+
+    struct LevelLogger(LevelFilter);
+
+    impl Log for LevelLogger {
+        fn enabled(&self, metadata: &Metadata) -> bool {
+            metadata.level() <= self.0
+        }
+
+        fn context(&self) -> &str {
+            "LEVEL"
+        }
+
+        fn max_level(&self) -> LevelFilter {
+            self.0
+        }
+
+        fn log(&self, _: &Record) {}
+
+        fn flush(&self) {}
+    }
+
+    #[test]
+    fn test_log_max_level() {
+        // Default implementation.
+        assert_eq!(StubLogger { context: "ctx" }.max_level(), LevelFilter::Trace);
+        assert_eq!(NopLogger.max_level(), LevelFilter::Off);
+
+        // Override is forwarded by references and boxes.
+        let logger = LevelLogger(LevelFilter::Warn);
+        assert_eq!(logger.max_level(), LevelFilter::Warn);
+        assert_eq!(Log::max_level(&&logger), LevelFilter::Warn);
+        let boxed: Box<dyn Log> = Box::new(logger);
+        assert_eq!(boxed.max_level(), LevelFilter::Warn);
     }
 
     // Test that the `impl Log for Foo` blocks work
@@ -850,10 +1614,12 @@ mod tests {
 
     #[test]
     fn test_parse_level_error_message() {
-        let e = ParseLevelError(());
-        assert_eq!(
-            &e.to_string(),
-            "attempted to convert a string that doesn't match an existing log level"
-        );
+        let e = "xyz".parse::<Level>().unwrap_err();
+        assert_eq!(e.input(), "xyz");
+        assert_eq!(&e.to_string(), "invalid log level: 'xyz'");
+
+        let e = "verbose".parse::<LevelFilter>().unwrap_err();
+        assert_eq!(e, ParseLevelError::new("verbose"));
+        assert_eq!(&e.to_string(), "invalid log level: 'verbose'");
     }
 }
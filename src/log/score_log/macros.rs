@@ -25,7 +25,10 @@
 /// ```
 ///
 /// Optionally, you can specify a `context` argument to attach a specific context to the log record.
-/// By default, the context is provided by the logger.
+/// By default, the context set with [`push_context`](crate::push_context) on the current thread is used,
+/// or the context provided by the logger if none is set.
+/// If the logger provides an empty context, the one set with [`set_default_context`](crate::set_default_context)
+/// is used instead.
 ///
 /// ```
 /// use score_log::{log, Level};
@@ -72,15 +75,63 @@
 /// The `logger` argument accepts a value that implements the `Log` trait.
 /// The value will be borrowed within the macro.
 ///
+/// Optionally, you can specify a `target` argument after `logger` and `context`, which is attached to the
+/// record [`Metadata`](crate::Metadata) for routing. Records have no target by default.
+///
+/// ```
+/// use score_log::{log, Level};
+///
+/// log!(target: "net::tcp", Level::Info, "Connection established");
+/// log!(context: "app_events", target: "net::tcp", Level::Info, "Connection established");
+/// ```
+///
+/// Instead of the format string and arguments, `raw: expr` can be provided to log an already formatted string.
+/// The string is written verbatim, e.g., `{}` in user data is not interpreted as a placeholder.
+/// This form is accepted by all logging macros.
+///
+/// ```
+/// use score_log::{log, Level};
+///
+/// let user_data = String::from("name: {}");
+/// log!(Level::Info, raw: user_data);
+/// ```
+///
 /// Note that the global level set via Cargo features, or through `set_max_level` will still apply, even when a custom logger is supplied with the `logger` argument.
+///
+/// The message arguments are only evaluated if the level passes the [`max_level`](crate::max_level) check
+/// and the logger is [`enabled`](crate::Log::enabled) for the record metadata.
 #[macro_export]
 #[clippy::format_args]
 macro_rules! log {
+    // log!(logger: my_logger, context: "my_context", target: "my_target", Level::Info, "a {} event", "log");
+    (logger: $logger:expr, context: $context:expr, target: $target:expr, $level:expr, $($arg:tt)+) => ({
+        $crate::__log!(
+            logger: $crate::__log_logger!($logger),
+            context: $context,
+            target: Some($target),
+            $level,
+            $($arg)+
+        )
+    });
+
     // log!(logger: my_logger, context: "my_context", Level::Info, "a {} event", "log");
     (logger: $logger:expr, context: $context:expr, $level:expr, $($arg:tt)+) => ({
         $crate::__log!(
             logger: $crate::__log_logger!($logger),
             context: $context,
+            target: None,
+            $level,
+            $($arg)+
+        )
+    });
+
+    // log!(logger: my_logger, target: "my_target", Level::Info, "a log event")
+    (logger: $logger:expr, target: $target:expr, $level:expr, $($arg:tt)+) => ({
+        let logger = $crate::__log_logger!($logger);
+        $crate::__log!(
+            logger: logger,
+            context: $crate::__log_context!(logger),
+            target: Some($target),
             $level,
             $($arg)+
         )
@@ -91,7 +142,19 @@ macro_rules! log {
         let logger = $crate::__log_logger!($logger);
         $crate::__log!(
             logger: logger,
-            context: logger.context(),
+            context: $crate::__log_context!(logger),
+            target: None,
+            $level,
+            $($arg)+
+        )
+    });
+
+    // log!(context: "my_context", target: "my_target", Level::Info, "a log event")
+    (context: $context:expr, target: $target:expr, $level:expr, $($arg:tt)+) => ({
+        $crate::__log!(
+            logger: $crate::__log_logger!(__log_global_logger),
+            context: $context,
+            target: Some($target),
             $level,
             $($arg)+
         )
@@ -102,6 +165,19 @@ macro_rules! log {
         $crate::__log!(
             logger: $crate::__log_logger!(__log_global_logger),
             context: $context,
+            target: None,
+            $level,
+            $($arg)+
+        )
+    });
+
+    // log!(target: "my_target", Level::Info, "a log event")
+    (target: $target:expr, $level:expr, $($arg:tt)+) => ({
+        let logger = $crate::__log_logger!(__log_global_logger);
+        $crate::__log!(
+            logger: logger,
+            context: $crate::__log_context!(logger),
+            target: Some($target),
             $level,
             $($arg)+
         )
@@ -112,7 +188,8 @@ macro_rules! log {
         let logger = $crate::__log_logger!(__log_global_logger);
         $crate::__log!(
             logger: logger,
-            context: logger.context(),
+            context: $crate::__log_context!(logger),
+            target: None,
             $level,
             $($arg)+
         )
@@ -122,18 +199,69 @@ macro_rules! log {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __log {
-    // log!(logger: my_logger, context: "my_context", Level::Info, "a {} event", "log");
-    (logger: $logger:expr, context: $context:expr, $level:expr, $($arg:tt)+) => ({
-        let loc = core::panic::Location::caller();
-        $logger.log(
-            &$crate::Record::new(
-                $crate::format_args!($($arg)+),
-                $crate::Metadata::new($level, $context),
-                core::module_path!(),
-                loc.file(),
-                loc.line()
-            )
-        );
+    // log!(logger: my_logger, context: "my_context", target: Some("my_target"), Level::Info, "a {} event", "log");
+    (logger: $logger:expr, context: $context:expr, target: $target:expr, $level:expr, $($arg:tt)+) => ({
+        // Check level and logger before the message arguments are evaluated.
+        let logger = &$logger;
+        let level = $level;
+        let metadata = $crate::Metadata::builder()
+            .level(level)
+            .context($context)
+            .target($target)
+            .module_path(Some(core::module_path!()))
+            .build();
+        if level <= $crate::max_level() && logger.enabled(&metadata) {
+            let loc = core::panic::Location::caller();
+            logger.log(
+                &$crate::Record::new(
+                    $crate::format_args!($($arg)+),
+                    metadata,
+                    core::module_path!(),
+                    loc.file(),
+                    loc.line()
+                )
+            );
+        }
+    });
+}
+
+/// Logs a value produced by a closure, which is only called if the record will be logged.
+///
+/// The closure is invoked after the [`max_level`](crate::max_level) check and the logger
+/// [`enabled`](crate::Log::enabled) check pass, and its result is logged with the `{}` placeholder.
+/// This supports hot paths where even building the message arguments is costly.
+///
+/// The closure returns a single value implementing [`ScoreDebug`](crate::fmt::ScoreDebug), not format arguments.
+/// To log a formatted message, use [`macro@log`] instead.
+///
+/// ```
+/// use score_log::{log_lazy, Level};
+///
+/// # fn expensive_summary() -> u64 { 42 }
+/// log_lazy!(Level::Debug, || expensive_summary());
+/// ```
+///
+/// This macro accepts the same `context` and `logger` arguments as [`macro@log`].
+#[macro_export]
+macro_rules! log_lazy {
+    // log_lazy!(logger: my_logger, context: "my_context", Level::Info, || value)
+    (logger: $logger:expr, context: $context:expr, $level:expr, $f:expr $(,)?) => ({
+        $crate::log!(logger: $logger, context: $context, $level, "{}", ($f)())
+    });
+
+    // log_lazy!(logger: my_logger, Level::Info, || value)
+    (logger: $logger:expr, $level:expr, $f:expr $(,)?) => ({
+        $crate::log!(logger: $logger, $level, "{}", ($f)())
+    });
+
+    // log_lazy!(context: "my_context", Level::Info, || value)
+    (context: $context:expr, $level:expr, $f:expr $(,)?) => ({
+        $crate::log!(context: $context, $level, "{}", ($f)())
+    });
+
+    // log_lazy!(Level::Info, || value)
+    ($level:expr, $f:expr $(,)?) => ({
+        $crate::log!($level, "{}", ($f)())
     });
 }
 
@@ -368,6 +496,642 @@ macro_rules! trace {
     ($($arg:tt)+) => ($crate::log!($crate::Level::Trace, $($arg)+))
 }
 
+/// Logs a message at most once per call site for the lifetime of the program.
+///
+/// The first invocation that passes the [`max_level`](crate::max_level) check is logged using [`macro@log`].
+/// Subsequent invocations of the same call site are no-ops.
+///
+/// ```
+/// use score_log::{log_once, Level};
+///
+/// for attempt in 0..3 {
+///     log_once!(Level::Warn, "Device not ready, attempt: {}", attempt);
+/// }
+/// ```
+///
+/// This macro accepts the same `context` and `logger` arguments as [`macro@log`].
+#[macro_export]
+#[clippy::format_args]
+macro_rules! log_once {
+    // log_once!(logger: my_logger, context: "my_context", Level::Info, "a {} event", "log");
+    (logger: $logger:expr, context: $context:expr, $level:expr, $($arg:tt)+) => ({
+        let level = $level;
+        if level <= $crate::max_level() && $crate::__log_once!() {
+            $crate::log!(logger: $logger, context: $context, level, $($arg)+)
+        }
+    });
+
+    // log_once!(logger: my_logger, Level::Info, "a log event")
+    (logger: $logger:expr, $level:expr, $($arg:tt)+) => ({
+        let level = $level;
+        if level <= $crate::max_level() && $crate::__log_once!() {
+            $crate::log!(logger: $logger, level, $($arg)+)
+        }
+    });
+
+    // log_once!(context: "my_context", Level::Info, "a log event")
+    (context: $context:expr, $level:expr, $($arg:tt)+) => ({
+        let level = $level;
+        if level <= $crate::max_level() && $crate::__log_once!() {
+            $crate::log!(context: $context, level, $($arg)+)
+        }
+    });
+
+    // log_once!(Level::Info, "a log event")
+    ($level:expr, $($arg:tt)+) => ({
+        let level = $level;
+        if level <= $crate::max_level() && $crate::__log_once!() {
+            $crate::log!(level, $($arg)+)
+        }
+    });
+}
+
+// Returns `true` only on the first evaluation for a given call site.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_once {
+    () => {{
+        static LOGGED: core::sync::atomic::AtomicBool = core::sync::atomic::AtomicBool::new(false);
+        !LOGGED.swap(true, core::sync::atomic::Ordering::Relaxed)
+    }};
+}
+
+/// Logs a message at the fatal level at most once per call site.
+///
+/// Refer to [`macro@log_once`] for details.
+///
+/// # Examples
+///
+/// ```
+/// use score_log::fatal_once;
+///
+/// # let my_logger = score_log::global_logger();
+/// for attempt in 0..3 {
+///     fatal_once!("Attempt: {}", attempt);
+///     fatal_once!(context: "app_events", "Attempt: {}", attempt);
+///     fatal_once!(logger: my_logger, "Attempt: {}", attempt);
+/// }
+/// ```
+#[macro_export]
+#[clippy::format_args]
+macro_rules! fatal_once {
+    // fatal_once!(logger: my_logger, context: "my_context", "a {} event", "log")
+    (logger: $logger:expr, context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_once!(logger: $logger, context: $context, $crate::Level::Fatal, $($arg)+)
+    });
+
+    // fatal_once!(logger: my_logger, "a {} event", "log")
+    (logger: $logger:expr, $($arg:tt)+) => ({
+        $crate::log_once!(logger: $logger, $crate::Level::Fatal, $($arg)+)
+    });
+
+    // fatal_once!(context: "my_context", "a {} event", "log")
+    (context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_once!(context: $context, $crate::Level::Fatal, $($arg)+)
+    });
+
+    // fatal_once!("a {} event", "log")
+    ($($arg:tt)+) => ($crate::log_once!($crate::Level::Fatal, $($arg)+))
+}
+
+/// Logs a message at the error level at most once per call site.
+///
+/// Refer to [`macro@log_once`] for details.
+///
+/// # Examples
+///
+/// ```
+/// use score_log::error_once;
+///
+/// # let my_logger = score_log::global_logger();
+/// for attempt in 0..3 {
+///     error_once!("Attempt: {}", attempt);
+///     error_once!(context: "app_events", "Attempt: {}", attempt);
+///     error_once!(logger: my_logger, "Attempt: {}", attempt);
+/// }
+/// ```
+#[macro_export]
+#[clippy::format_args]
+macro_rules! error_once {
+    // error_once!(logger: my_logger, context: "my_context", "a {} event", "log")
+    (logger: $logger:expr, context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_once!(logger: $logger, context: $context, $crate::Level::Error, $($arg)+)
+    });
+
+    // error_once!(logger: my_logger, "a {} event", "log")
+    (logger: $logger:expr, $($arg:tt)+) => ({
+        $crate::log_once!(logger: $logger, $crate::Level::Error, $($arg)+)
+    });
+
+    // error_once!(context: "my_context", "a {} event", "log")
+    (context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_once!(context: $context, $crate::Level::Error, $($arg)+)
+    });
+
+    // error_once!("a {} event", "log")
+    ($($arg:tt)+) => ($crate::log_once!($crate::Level::Error, $($arg)+))
+}
+
+/// Logs a message at the warn level at most once per call site.
+///
+/// Refer to [`macro@log_once`] for details.
+///
+/// # Examples
+///
+/// ```
+/// use score_log::warn_once;
+///
+/// # let my_logger = score_log::global_logger();
+/// for attempt in 0..3 {
+///     warn_once!("Attempt: {}", attempt);
+///     warn_once!(context: "app_events", "Attempt: {}", attempt);
+///     warn_once!(logger: my_logger, "Attempt: {}", attempt);
+/// }
+/// ```
+#[macro_export]
+#[clippy::format_args]
+macro_rules! warn_once {
+    // warn_once!(logger: my_logger, context: "my_context", "a {} event", "log")
+    (logger: $logger:expr, context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_once!(logger: $logger, context: $context, $crate::Level::Warn, $($arg)+)
+    });
+
+    // warn_once!(logger: my_logger, "a {} event", "log")
+    (logger: $logger:expr, $($arg:tt)+) => ({
+        $crate::log_once!(logger: $logger, $crate::Level::Warn, $($arg)+)
+    });
+
+    // warn_once!(context: "my_context", "a {} event", "log")
+    (context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_once!(context: $context, $crate::Level::Warn, $($arg)+)
+    });
+
+    // warn_once!("a {} event", "log")
+    ($($arg:tt)+) => ($crate::log_once!($crate::Level::Warn, $($arg)+))
+}
+
+/// Logs a message at the info level at most once per call site.
+///
+/// Refer to [`macro@log_once`] for details.
+///
+/// # Examples
+///
+/// ```
+/// use score_log::info_once;
+///
+/// # let my_logger = score_log::global_logger();
+/// for attempt in 0..3 {
+///     info_once!("Attempt: {}", attempt);
+///     info_once!(context: "app_events", "Attempt: {}", attempt);
+///     info_once!(logger: my_logger, "Attempt: {}", attempt);
+/// }
+/// ```
+#[macro_export]
+#[clippy::format_args]
+macro_rules! info_once {
+    // info_once!(logger: my_logger, context: "my_context", "a {} event", "log")
+    (logger: $logger:expr, context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_once!(logger: $logger, context: $context, $crate::Level::Info, $($arg)+)
+    });
+
+    // info_once!(logger: my_logger, "a {} event", "log")
+    (logger: $logger:expr, $($arg:tt)+) => ({
+        $crate::log_once!(logger: $logger, $crate::Level::Info, $($arg)+)
+    });
+
+    // info_once!(context: "my_context", "a {} event", "log")
+    (context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_once!(context: $context, $crate::Level::Info, $($arg)+)
+    });
+
+    // info_once!("a {} event", "log")
+    ($($arg:tt)+) => ($crate::log_once!($crate::Level::Info, $($arg)+))
+}
+
+/// Logs a message at the debug level at most once per call site.
+///
+/// Refer to [`macro@log_once`] for details.
+///
+/// # Examples
+///
+/// ```
+/// use score_log::debug_once;
+///
+/// # let my_logger = score_log::global_logger();
+/// for attempt in 0..3 {
+///     debug_once!("Attempt: {}", attempt);
+///     debug_once!(context: "app_events", "Attempt: {}", attempt);
+///     debug_once!(logger: my_logger, "Attempt: {}", attempt);
+/// }
+/// ```
+#[macro_export]
+#[clippy::format_args]
+macro_rules! debug_once {
+    // debug_once!(logger: my_logger, context: "my_context", "a {} event", "log")
+    (logger: $logger:expr, context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_once!(logger: $logger, context: $context, $crate::Level::Debug, $($arg)+)
+    });
+
+    // debug_once!(logger: my_logger, "a {} event", "log")
+    (logger: $logger:expr, $($arg:tt)+) => ({
+        $crate::log_once!(logger: $logger, $crate::Level::Debug, $($arg)+)
+    });
+
+    // debug_once!(context: "my_context", "a {} event", "log")
+    (context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_once!(context: $context, $crate::Level::Debug, $($arg)+)
+    });
+
+    // debug_once!("a {} event", "log")
+    ($($arg:tt)+) => ($crate::log_once!($crate::Level::Debug, $($arg)+))
+}
+
+/// Logs a message at the trace level at most once per call site.
+///
+/// Refer to [`macro@log_once`] for details.
+///
+/// # Examples
+///
+/// ```
+/// use score_log::trace_once;
+///
+/// # let my_logger = score_log::global_logger();
+/// for attempt in 0..3 {
+///     trace_once!("Attempt: {}", attempt);
+///     trace_once!(context: "app_events", "Attempt: {}", attempt);
+///     trace_once!(logger: my_logger, "Attempt: {}", attempt);
+/// }
+/// ```
+#[macro_export]
+#[clippy::format_args]
+macro_rules! trace_once {
+    // trace_once!(logger: my_logger, context: "my_context", "a {} event", "log")
+    (logger: $logger:expr, context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_once!(logger: $logger, context: $context, $crate::Level::Trace, $($arg)+)
+    });
+
+    // trace_once!(logger: my_logger, "a {} event", "log")
+    (logger: $logger:expr, $($arg:tt)+) => ({
+        $crate::log_once!(logger: $logger, $crate::Level::Trace, $($arg)+)
+    });
+
+    // trace_once!(context: "my_context", "a {} event", "log")
+    (context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_once!(context: $context, $crate::Level::Trace, $($arg)+)
+    });
+
+    // trace_once!("a {} event", "log")
+    ($($arg:tt)+) => ($crate::log_once!($crate::Level::Trace, $($arg)+))
+}
+
+/// Logs a message every `n`-th time a call site is hit.
+///
+/// The first hit is logged, then every `n`-th after it.
+/// Only hits passing the [`max_level`](crate::max_level) check are counted.
+/// `n` equal to `0` is treated as `1`.
+///
+/// ```
+/// use score_log::{log_every_n, Level};
+///
+/// for i in 0..100 {
+///     log_every_n!(10, Level::Debug, "Processing item: {}", i);
+/// }
+/// ```
+///
+/// This macro accepts the same `context` and `logger` arguments as [`macro@log`], provided after `n`.
+#[macro_export]
+#[clippy::format_args]
+macro_rules! log_every_n {
+    // log_every_n!(n, logger: my_logger, context: "my_context", Level::Info, "a {} event", "log");
+    ($n:expr, logger: $logger:expr, context: $context:expr, $level:expr, $($arg:tt)+) => ({
+        let level = $level;
+        if level <= $crate::max_level() && $crate::__log_every_n!($n) {
+            $crate::log!(logger: $logger, context: $context, level, $($arg)+)
+        }
+    });
+
+    // log_every_n!(n, logger: my_logger, Level::Info, "a log event")
+    ($n:expr, logger: $logger:expr, $level:expr, $($arg:tt)+) => ({
+        let level = $level;
+        if level <= $crate::max_level() && $crate::__log_every_n!($n) {
+            $crate::log!(logger: $logger, level, $($arg)+)
+        }
+    });
+
+    // log_every_n!(n, context: "my_context", Level::Info, "a log event")
+    ($n:expr, context: $context:expr, $level:expr, $($arg:tt)+) => ({
+        let level = $level;
+        if level <= $crate::max_level() && $crate::__log_every_n!($n) {
+            $crate::log!(context: $context, level, $($arg)+)
+        }
+    });
+
+    // log_every_n!(n, Level::Info, "a log event")
+    ($n:expr, $level:expr, $($arg:tt)+) => ({
+        let level = $level;
+        if level <= $crate::max_level() && $crate::__log_every_n!($n) {
+            $crate::log!(level, $($arg)+)
+        }
+    });
+}
+
+// Returns `true` on every `n`-th evaluation for a given call site, starting with the first one.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_every_n {
+    ($n:expr) => {{
+        static COUNTER: core::sync::atomic::AtomicUsize = core::sync::atomic::AtomicUsize::new(0);
+        let n: usize = $n;
+        COUNTER.fetch_add(1, core::sync::atomic::Ordering::Relaxed) % n.max(1) == 0
+    }};
+}
+
+/// Logs a message at the fatal level every `n`-th time a call site is hit.
+///
+/// Refer to [`macro@log_every_n`] for details.
+///
+/// # Examples
+///
+/// ```
+/// use score_log::fatal_every_n;
+///
+/// # let my_logger = score_log::global_logger();
+/// for i in 0..100 {
+///     fatal_every_n!(10, "Item: {}", i);
+///     fatal_every_n!(10, context: "app_events", "Item: {}", i);
+///     fatal_every_n!(10, logger: my_logger, "Item: {}", i);
+/// }
+/// ```
+#[macro_export]
+#[clippy::format_args]
+macro_rules! fatal_every_n {
+    // fatal_every_n!(n, logger: my_logger, context: "my_context", "a {} event", "log")
+    ($n:expr, logger: $logger:expr, context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_every_n!($n, logger: $logger, context: $context, $crate::Level::Fatal, $($arg)+)
+    });
+
+    // fatal_every_n!(n, logger: my_logger, "a {} event", "log")
+    ($n:expr, logger: $logger:expr, $($arg:tt)+) => ({
+        $crate::log_every_n!($n, logger: $logger, $crate::Level::Fatal, $($arg)+)
+    });
+
+    // fatal_every_n!(n, context: "my_context", "a {} event", "log")
+    ($n:expr, context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_every_n!($n, context: $context, $crate::Level::Fatal, $($arg)+)
+    });
+
+    // fatal_every_n!(n, "a {} event", "log")
+    ($n:expr, $($arg:tt)+) => ($crate::log_every_n!($n, $crate::Level::Fatal, $($arg)+))
+}
+
+/// Logs a message at the error level every `n`-th time a call site is hit.
+///
+/// Refer to [`macro@log_every_n`] for details.
+///
+/// # Examples
+///
+/// ```
+/// use score_log::error_every_n;
+///
+/// # let my_logger = score_log::global_logger();
+/// for i in 0..100 {
+///     error_every_n!(10, "Item: {}", i);
+///     error_every_n!(10, context: "app_events", "Item: {}", i);
+///     error_every_n!(10, logger: my_logger, "Item: {}", i);
+/// }
+/// ```
+#[macro_export]
+#[clippy::format_args]
+macro_rules! error_every_n {
+    // error_every_n!(n, logger: my_logger, context: "my_context", "a {} event", "log")
+    ($n:expr, logger: $logger:expr, context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_every_n!($n, logger: $logger, context: $context, $crate::Level::Error, $($arg)+)
+    });
+
+    // error_every_n!(n, logger: my_logger, "a {} event", "log")
+    ($n:expr, logger: $logger:expr, $($arg:tt)+) => ({
+        $crate::log_every_n!($n, logger: $logger, $crate::Level::Error, $($arg)+)
+    });
+
+    // error_every_n!(n, context: "my_context", "a {} event", "log")
+    ($n:expr, context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_every_n!($n, context: $context, $crate::Level::Error, $($arg)+)
+    });
+
+    // error_every_n!(n, "a {} event", "log")
+    ($n:expr, $($arg:tt)+) => ($crate::log_every_n!($n, $crate::Level::Error, $($arg)+))
+}
+
+/// Logs a message at the warn level every `n`-th time a call site is hit.
+///
+/// Refer to [`macro@log_every_n`] for details.
+///
+/// # Examples
+///
+/// ```
+/// use score_log::warn_every_n;
+///
+/// # let my_logger = score_log::global_logger();
+/// for i in 0..100 {
+///     warn_every_n!(10, "Item: {}", i);
+///     warn_every_n!(10, context: "app_events", "Item: {}", i);
+///     warn_every_n!(10, logger: my_logger, "Item: {}", i);
+/// }
+/// ```
+#[macro_export]
+#[clippy::format_args]
+macro_rules! warn_every_n {
+    // warn_every_n!(n, logger: my_logger, context: "my_context", "a {} event", "log")
+    ($n:expr, logger: $logger:expr, context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_every_n!($n, logger: $logger, context: $context, $crate::Level::Warn, $($arg)+)
+    });
+
+    // warn_every_n!(n, logger: my_logger, "a {} event", "log")
+    ($n:expr, logger: $logger:expr, $($arg:tt)+) => ({
+        $crate::log_every_n!($n, logger: $logger, $crate::Level::Warn, $($arg)+)
+    });
+
+    // warn_every_n!(n, context: "my_context", "a {} event", "log")
+    ($n:expr, context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_every_n!($n, context: $context, $crate::Level::Warn, $($arg)+)
+    });
+
+    // warn_every_n!(n, "a {} event", "log")
+    ($n:expr, $($arg:tt)+) => ($crate::log_every_n!($n, $crate::Level::Warn, $($arg)+))
+}
+
+/// Logs a message at the info level every `n`-th time a call site is hit.
+///
+/// Refer to [`macro@log_every_n`] for details.
+///
+/// # Examples
+///
+/// ```
+/// use score_log::info_every_n;
+///
+/// # let my_logger = score_log::global_logger();
+/// for i in 0..100 {
+///     info_every_n!(10, "Item: {}", i);
+///     info_every_n!(10, context: "app_events", "Item: {}", i);
+///     info_every_n!(10, logger: my_logger, "Item: {}", i);
+/// }
+/// ```
+#[macro_export]
+#[clippy::format_args]
+macro_rules! info_every_n {
+    // info_every_n!(n, logger: my_logger, context: "my_context", "a {} event", "log")
+    ($n:expr, logger: $logger:expr, context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_every_n!($n, logger: $logger, context: $context, $crate::Level::Info, $($arg)+)
+    });
+
+    // info_every_n!(n, logger: my_logger, "a {} event", "log")
+    ($n:expr, logger: $logger:expr, $($arg:tt)+) => ({
+        $crate::log_every_n!($n, logger: $logger, $crate::Level::Info, $($arg)+)
+    });
+
+    // info_every_n!(n, context: "my_context", "a {} event", "log")
+    ($n:expr, context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_every_n!($n, context: $context, $crate::Level::Info, $($arg)+)
+    });
+
+    // info_every_n!(n, "a {} event", "log")
+    ($n:expr, $($arg:tt)+) => ($crate::log_every_n!($n, $crate::Level::Info, $($arg)+))
+}
+
+/// Logs a message at the debug level every `n`-th time a call site is hit.
+///
+/// Refer to [`macro@log_every_n`] for details.
+///
+/// # Examples
+///
+/// ```
+/// use score_log::debug_every_n;
+///
+/// # let my_logger = score_log::global_logger();
+/// for i in 0..100 {
+///     debug_every_n!(10, "Item: {}", i);
+///     debug_every_n!(10, context: "app_events", "Item: {}", i);
+///     debug_every_n!(10, logger: my_logger, "Item: {}", i);
+/// }
+/// ```
+#[macro_export]
+#[clippy::format_args]
+macro_rules! debug_every_n {
+    // debug_every_n!(n, logger: my_logger, context: "my_context", "a {} event", "log")
+    ($n:expr, logger: $logger:expr, context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_every_n!($n, logger: $logger, context: $context, $crate::Level::Debug, $($arg)+)
+    });
+
+    // debug_every_n!(n, logger: my_logger, "a {} event", "log")
+    ($n:expr, logger: $logger:expr, $($arg:tt)+) => ({
+        $crate::log_every_n!($n, logger: $logger, $crate::Level::Debug, $($arg)+)
+    });
+
+    // debug_every_n!(n, context: "my_context", "a {} event", "log")
+    ($n:expr, context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_every_n!($n, context: $context, $crate::Level::Debug, $($arg)+)
+    });
+
+    // debug_every_n!(n, "a {} event", "log")
+    ($n:expr, $($arg:tt)+) => ($crate::log_every_n!($n, $crate::Level::Debug, $($arg)+))
+}
+
+/// Logs a message at the trace level every `n`-th time a call site is hit.
+///
+/// Refer to [`macro@log_every_n`] for details.
+///
+/// # Examples
+///
+/// ```
+/// use score_log::trace_every_n;
+///
+/// # let my_logger = score_log::global_logger();
+/// for i in 0..100 {
+///     trace_every_n!(10, "Item: {}", i);
+///     trace_every_n!(10, context: "app_events", "Item: {}", i);
+///     trace_every_n!(10, logger: my_logger, "Item: {}", i);
+/// }
+/// ```
+#[macro_export]
+#[clippy::format_args]
+macro_rules! trace_every_n {
+    // trace_every_n!(n, logger: my_logger, context: "my_context", "a {} event", "log")
+    ($n:expr, logger: $logger:expr, context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_every_n!($n, logger: $logger, context: $context, $crate::Level::Trace, $($arg)+)
+    });
+
+    // trace_every_n!(n, logger: my_logger, "a {} event", "log")
+    ($n:expr, logger: $logger:expr, $($arg:tt)+) => ({
+        $crate::log_every_n!($n, logger: $logger, $crate::Level::Trace, $($arg)+)
+    });
+
+    // trace_every_n!(n, context: "my_context", "a {} event", "log")
+    ($n:expr, context: $context:expr, $($arg:tt)+) => ({
+        $crate::log_every_n!($n, context: $context, $crate::Level::Trace, $($arg)+)
+    });
+
+    // trace_every_n!(n, "a {} event", "log")
+    ($n:expr, $($arg:tt)+) => ($crate::log_every_n!($n, $crate::Level::Trace, $($arg)+))
+}
+
+/// Asserts that a boolean expression is `true`, logging a fatal record and panicking otherwise.
+///
+/// If the condition is `false`, the message is logged at the fatal level using [`macro@log`],
+/// then the macro panics with the same message.
+/// Without a message, `assertion failed: <condition>` is used, same as for [`assert!`].
+///
+/// ```should_panic
+/// use score_log::log_assert;
+///
+/// let (expected, actual) = (4, 5);
+/// log_assert!(expected == 4);
+/// log_assert!(context: "app_events", actual > 0, "Invalid value: {}", actual);
+/// log_assert!(actual == expected, "Mismatch: {} != {}", actual, expected);
+/// ```
+///
+/// This macro accepts the same `context` and `logger` arguments as [`macro@log`].
+/// The message arguments are only evaluated if the condition is `false`.
+#[macro_export]
+macro_rules! log_assert {
+    // log_assert!(logger: my_logger, context: "my_context", cond, "a {} event", "log")
+    (logger: $logger:expr, context: $context:expr, $cond:expr $(, $($arg:tt)*)?) => ({
+        $crate::__log_assert!((logger: $logger, context: $context,), $cond $(, $($arg)*)?)
+    });
+
+    // log_assert!(logger: my_logger, cond, "a {} event", "log")
+    (logger: $logger:expr, $cond:expr $(, $($arg:tt)*)?) => ({
+        $crate::__log_assert!((logger: $logger,), $cond $(, $($arg)*)?)
+    });
+
+    // log_assert!(context: "my_context", cond, "a {} event", "log")
+    (context: $context:expr, $cond:expr $(, $($arg:tt)*)?) => ({
+        $crate::__log_assert!((context: $context,), $cond $(, $($arg)*)?)
+    });
+
+    // log_assert!(cond, "a {} event", "log")
+    ($cond:expr $(, $($arg:tt)*)?) => ({
+        $crate::__log_assert!((), $cond $(, $($arg)*)?)
+    });
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_assert {
+    // Default message.
+    (($($prefix:tt)*), $cond:expr $(,)?) => ({
+        $crate::__log_assert!(($($prefix)*), $cond, "assertion failed: {}", core::stringify!($cond))
+    });
+
+    (($($prefix:tt)*), $cond:expr, $($arg:tt)+) => ({
+        if !$cond {
+            // Message is formatted once, for both the record and the panic.
+            let message = $crate::fmt::format($crate::format_args!($($arg)+));
+            $crate::log!($($prefix)* $crate::Level::Fatal, "{}", message.as_str());
+            core::panic!("{}", message);
+        }
+    });
+}
+
 /// Determines if a message logged at the specified level in that module will be logged.
 ///
 /// This can be used to avoid expensive computation of data provided as a log message argument.
@@ -407,7 +1171,7 @@ macro_rules! log_enabled {
     // log_enabled!(logger: my_logger, Level::Info)
     (logger: $logger:expr, $level:expr) => ({
         let logger = $crate::__log_logger!($logger);
-        $crate::__log_enabled!(logger: logger, context: logger.context(), $level)
+        $crate::__log_enabled!(logger: logger, context: $crate::__log_context!(logger), $level)
     });
 
     // log_enabled!(context: "my_context", Level::Info)
@@ -418,7 +1182,7 @@ macro_rules! log_enabled {
     // log_enabled!(Level::Info)
     ($level:expr) => ({
         let logger = $crate::__log_logger!(__log_global_logger);
-        $crate::__log_enabled!(logger: logger, context: logger.context(), $level)
+        $crate::__log_enabled!(logger: logger, context: $crate::__log_context!(logger), $level)
     });
 }
 
@@ -428,7 +1192,24 @@ macro_rules! __log_enabled {
     // log_enabled!(logger: my_logger, context: "my_context", Level::Info)
     (logger: $logger:expr, context: $context:expr, $level:expr) => {{
         let level = $level;
-        level <= $crate::max_level() && $logger.enabled(&$crate::Metadata::new(level, $context))
+        let metadata = $crate::Metadata::builder()
+            .level(level)
+            .context($context)
+            .module_path(Some(core::module_path!()))
+            .build();
+        level <= $crate::max_level() && $logger.enabled(&metadata)
+    }};
+}
+
+// Determine the context to use when none is given explicitly.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_context {
+    ($logger:expr) => {{
+        $crate::current_context().unwrap_or_else(|| match $logger.context() {
+            "" => $crate::default_context(),
+            context => context,
+        })
     }};
 }
 
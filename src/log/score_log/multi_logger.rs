@@ -0,0 +1,182 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! Logger forwarding records to multiple inner loggers.
+
+use crate::{LevelFilter, Log, Metadata, Record};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// Logger forwarding each operation to all inner loggers.
+///
+/// Inner loggers are called in the order they were added.
+/// Each inner logger performs its own filtering.
+pub struct MultiLogger {
+    loggers: Vec<Box<dyn Log>>,
+}
+
+impl MultiLogger {
+    /// Create [`MultiLogger`] from provided loggers.
+    pub fn new(loggers: Vec<Box<dyn Log>>) -> Self {
+        Self { loggers }
+    }
+
+    /// Create [`MultiLoggerBuilder`].
+    pub fn builder() -> MultiLoggerBuilder {
+        MultiLoggerBuilder::new()
+    }
+
+    /// Inner loggers.
+    pub fn loggers(&self) -> &[Box<dyn Log>] {
+        &self.loggers
+    }
+}
+
+impl Log for MultiLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.loggers.iter().any(|logger| logger.enabled(metadata))
+    }
+
+    fn context(&self) -> &str {
+        self.loggers.first().map_or("", |logger| logger.context())
+    }
+
+    /// Most verbose level across all inner loggers, [`LevelFilter::Off`] if there are none.
+    fn max_level(&self) -> LevelFilter {
+        self.loggers
+            .iter()
+            .map(|logger| logger.max_level())
+            .max()
+            .unwrap_or(LevelFilter::Off)
+    }
+
+    fn log(&self, record: &Record) {
+        for logger in self.loggers.iter() {
+            logger.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        for logger in self.loggers.iter() {
+            logger.flush();
+        }
+    }
+}
+
+/// Builder for the [`MultiLogger`].
+#[derive(Default)]
+pub struct MultiLoggerBuilder {
+    loggers: Vec<Box<dyn Log>>,
+}
+
+impl MultiLoggerBuilder {
+    /// Create builder with no inner loggers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add inner logger.
+    pub fn logger<L: Log + 'static>(mut self, logger: L) -> Self {
+        self.loggers.push(Box::new(logger));
+        self
+    }
+
+    /// Add boxed inner logger.
+    pub fn boxed_logger(mut self, logger: Box<dyn Log>) -> Self {
+        self.loggers.push(logger);
+        self
+    }
+
+    /// Build the [`MultiLogger`] with provided inner loggers.
+    pub fn build(self) -> MultiLogger {
+        MultiLogger::new(self.loggers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{record_with, StubLogger};
+    use crate::Level;
+    use core::sync::atomic::Ordering;
+
+    #[test]
+    fn test_log_forwarded_to_all() {
+        let (logger1, counters1) = StubLogger::new("ctx1", LevelFilter::Trace);
+        let (logger2, counters2) = StubLogger::new("ctx2", LevelFilter::Trace);
+        let multi_logger = MultiLogger::builder().logger(logger1).logger(logger2).build();
+
+        record_with("ctx", |record| multi_logger.log(record));
+        multi_logger.flush();
+
+        for counters in [counters1, counters2] {
+            assert_eq!(counters.log.load(Ordering::Relaxed), 1);
+            assert_eq!(counters.flush.load(Ordering::Relaxed), 1);
+        }
+    }
+
+    #[test]
+    fn test_enabled_any() {
+        let metadata = Metadata::new(Level::Info, "ctx");
+
+        let (logger1, _) = StubLogger::new("ctx1", LevelFilter::Off);
+        let (logger2, _) = StubLogger::new("ctx2", LevelFilter::Trace);
+        let multi_logger = MultiLogger::builder().logger(logger1).logger(logger2).build();
+        assert!(multi_logger.enabled(&metadata));
+
+        let (logger1, _) = StubLogger::new("ctx1", LevelFilter::Off);
+        let (logger2, _) = StubLogger::new("ctx2", LevelFilter::Off);
+        let multi_logger = MultiLogger::builder()
+            .logger(logger1)
+            .boxed_logger(Box::new(logger2))
+            .build();
+        assert!(!multi_logger.enabled(&metadata));
+    }
+
+    #[test]
+    fn test_context() {
+        let (logger1, _) = StubLogger::new("ctx1", LevelFilter::Trace);
+        let (logger2, _) = StubLogger::new("ctx2", LevelFilter::Trace);
+        let multi_logger = MultiLogger::builder().logger(logger1).logger(logger2).build();
+        assert_eq!(multi_logger.context(), "ctx1");
+        assert_eq!(multi_logger.loggers().len(), 2);
+    }
+
+    #[test]
+    fn test_max_level() {
+        let (logger1, _) = StubLogger::new("ctx1", LevelFilter::Warn);
+        let (logger2, _) = StubLogger::new("ctx2", LevelFilter::Debug);
+        let (logger3, _) = StubLogger::new("ctx3", LevelFilter::Error);
+        let multi_logger = MultiLogger::builder()
+            .logger(logger1)
+            .logger(logger2)
+            .logger(logger3)
+            .build();
+        assert_eq!(multi_logger.max_level(), LevelFilter::Debug);
+
+        let (logger1, _) = StubLogger::new("ctx1", LevelFilter::Warn);
+        let (logger2, _) = StubLogger::new("ctx2", LevelFilter::Trace);
+        let multi_logger = MultiLogger::builder().logger(logger1).logger(logger2).build();
+        assert_eq!(multi_logger.max_level(), LevelFilter::Trace);
+    }
+
+    #[test]
+    fn test_empty() {
+        let multi_logger = MultiLogger::builder().build();
+        assert_eq!(multi_logger.context(), "");
+        assert_eq!(multi_logger.max_level(), LevelFilter::Off);
+        assert!(!multi_logger.enabled(&Metadata::new(Level::Fatal, "ctx")));
+        record_with("ctx", |record| multi_logger.log(record));
+        multi_logger.flush();
+    }
+}
@@ -0,0 +1,76 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! Common testing utilities.
+
+use crate::{Level, LevelFilter, Log, Metadata, Record};
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Number of calls to the [`StubLogger`] methods.
+#[derive(Default)]
+pub(crate) struct Counters {
+    pub log: AtomicUsize,
+    pub flush: AtomicUsize,
+}
+
+/// Logger enabled up to provided level, counting logged records and flushes.
+pub(crate) struct StubLogger {
+    context: &'static str,
+    level: LevelFilter,
+    counters: Arc<Counters>,
+}
+
+impl StubLogger {
+    pub fn new(context: &'static str, level: LevelFilter) -> (Self, Arc<Counters>) {
+        let counters = Arc::new(Counters::default());
+        let logger = Self {
+            context,
+            level,
+            counters: counters.clone(),
+        };
+        (logger, counters)
+    }
+}
+
+impl Log for StubLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level
+    }
+
+    fn context(&self) -> &str {
+        self.context
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        self.level
+    }
+
+    fn log(&self, _: &Record) {
+        self.counters.log.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn flush(&self) {
+        self.counters.flush.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Call provided function with an `Info` record with provided context.
+pub(crate) fn record_with<R>(context: &str, f: impl FnOnce(&Record) -> R) -> R {
+    // Fix for self-reference in `score_log_format_args`.
+    use crate as score_log;
+
+    let args = crate::format_args!("test_string_{}", 123);
+    let record = Record::new(args, Metadata::new(Level::Info, context), "module_path", "file", 123);
+    f(&record)
+}
@@ -0,0 +1,81 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+#![allow(missing_docs)]
+
+use score_log::{Level, LevelFilter, Log, Metadata, Record};
+use std::sync::Mutex;
+
+struct ContextLogger {
+    context: &'static str,
+    last_context: Mutex<String>,
+}
+
+impl Log for ContextLogger {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+    fn context(&self) -> &str {
+        self.context
+    }
+    fn log(&self, record: &Record) {
+        *self.last_context.lock().unwrap() = record.metadata().context().to_string();
+    }
+    fn flush(&self) {}
+}
+
+// Default context is global, so it's tested in a separate binary.
+#[test]
+fn default_context() {
+    score_log::set_max_level(LevelFilter::Trace);
+    let empty = ContextLogger {
+        context: "",
+        last_context: Mutex::new(String::new()),
+    };
+    let named = ContextLogger {
+        context: "LOGGER",
+        last_context: Mutex::new(String::new()),
+    };
+    let last_context = |logger: &ContextLogger| logger.last_context.lock().unwrap().clone();
+
+    // Empty context is kept if no default is set.
+    assert_eq!(score_log::default_context(), "");
+    score_log::info!(logger: empty, "hello");
+    assert_eq!(last_context(&empty), "");
+
+    score_log::set_default_context("DEFAULT");
+    assert_eq!(score_log::default_context(), "DEFAULT");
+    score_log::log!(logger: empty, Level::Info, "hello");
+    assert_eq!(last_context(&empty), "DEFAULT");
+
+    // Non-empty logger context, pushed context and explicit context take precedence.
+    score_log::info!(logger: named, "hello");
+    assert_eq!(last_context(&named), "LOGGER");
+    {
+        let _guard = score_log::push_context("NET");
+        score_log::info!(logger: empty, "hello");
+        assert_eq!(last_context(&empty), "NET");
+    }
+    score_log::info!(logger: empty, context: "explicit", "hello");
+    assert_eq!(last_context(&empty), "explicit");
+
+    // Default applies to other threads as well.
+    std::thread::scope(|s| {
+        s.spawn(|| score_log::info!(logger: empty, "hello"));
+    });
+    assert_eq!(last_context(&empty), "DEFAULT");
+
+    score_log::set_default_context("");
+    score_log::info!(logger: empty, "hello");
+    assert_eq!(last_context(&empty), "");
+}
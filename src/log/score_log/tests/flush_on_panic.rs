@@ -0,0 +1,60 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+#![allow(missing_docs)]
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+use score_log::{Log, Metadata, Record};
+
+static FLUSH_COUNT: AtomicUsize = AtomicUsize::new(0);
+static PREVIOUS_HOOK_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+struct FlushCountingLogger;
+
+impl Log for FlushCountingLogger {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn context(&self) -> &str {
+        "TEST"
+    }
+
+    fn log(&self, _: &Record) {}
+
+    fn flush(&self) {
+        FLUSH_COUNT.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn test_install_flush_on_panic() {
+    // Panic hook is process-wide, all checks are done in a single test.
+    assert!(score_log::set_global_logger(Box::new(FlushCountingLogger)).is_ok());
+    std::panic::set_hook(Box::new(|_| {
+        PREVIOUS_HOOK_COUNT.fetch_add(1, Ordering::SeqCst);
+    }));
+    score_log::install_flush_on_panic();
+
+    let result = std::thread::spawn(|| panic!("test panic")).join();
+    assert!(result.is_err());
+    assert_eq!(FLUSH_COUNT.load(Ordering::SeqCst), 1);
+    assert_eq!(PREVIOUS_HOOK_COUNT.load(Ordering::SeqCst), 1);
+
+    let result = std::panic::catch_unwind(|| panic!("test panic"));
+    assert!(result.is_err());
+    assert_eq!(FLUSH_COUNT.load(Ordering::SeqCst), 2);
+    assert_eq!(PREVIOUS_HOOK_COUNT.load(Ordering::SeqCst), 2);
+
+    let _ = std::panic::take_hook();
+}
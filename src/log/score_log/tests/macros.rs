@@ -13,7 +13,10 @@
 
 #![allow(missing_docs)]
 
-use score_log::{log, log_enabled, Level, Log, Metadata, Record};
+use core::cell::Cell;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use score_log::{log, log_assert, log_enabled, log_lazy, Level, LevelFilter, Log, Metadata, Record};
+use std::sync::Mutex;
 
 macro_rules! all_log_macros {
     ($($arg:tt)*) => ({
@@ -39,23 +42,29 @@ impl Log for Logger {
     fn flush(&self) {}
 }
 
-fn level_iter() -> impl Iterator<Item = Level> {
-    [
-        Level::Fatal,
-        Level::Error,
-        Level::Warn,
-        Level::Info,
-        Level::Debug,
-        Level::Trace,
-    ]
-    .into_iter()
+// Enabled up to `Info`, counts logged records.
+struct InfoLogger {
+    logged: AtomicUsize,
+}
+
+impl Log for InfoLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= Level::Info
+    }
+    fn context(&self) -> &str {
+        "TEST"
+    }
+    fn log(&self, _: &Record) {
+        self.logged.fetch_add(1, Ordering::Relaxed);
+    }
+    fn flush(&self) {}
 }
 
 #[test]
 fn no_args() {
     let logger = Logger;
 
-    for lvl in level_iter() {
+    for lvl in Level::iter() {
         log!(lvl, "hello");
         log!(lvl, "hello",);
 
@@ -84,7 +93,7 @@ fn no_args() {
 
 #[test]
 fn anonymous_args() {
-    for lvl in level_iter() {
+    for lvl in Level::iter() {
         log!(lvl, "hello {}", "world");
         log!(lvl, "hello {}", "world",);
 
@@ -112,7 +121,7 @@ fn anonymous_args() {
 
 #[test]
 fn named_args() {
-    for lvl in level_iter() {
+    for lvl in Level::iter() {
         log!(lvl, "hello {world}", world = "world");
         log!(lvl, "hello {world}", world = "world",);
 
@@ -142,7 +151,7 @@ fn named_args() {
 fn enabled() {
     let logger = Logger;
 
-    for lvl in level_iter() {
+    for lvl in Level::iter() {
         let _enabled = log_enabled!(lvl);
         let _enabled = log_enabled!(context: "my_context", lvl);
         let _enabled = log_enabled!(logger: logger, context: "my_context", lvl);
@@ -154,7 +163,7 @@ fn enabled() {
 fn expr() {
     let logger = Logger;
 
-    for lvl in level_iter() {
+    for lvl in Level::iter() {
         log!(lvl, "hello");
 
         log!(logger: logger, lvl, "hello");
@@ -171,3 +180,257 @@ fn logger_short_lived() {
 fn logger_expr() {
     all_log_macros!(logger: Logger, "hello");
 }
+
+#[test]
+fn args_not_evaluated_when_disabled() {
+    score_log::set_max_level(LevelFilter::Trace);
+    let logger = InfoLogger {
+        logged: AtomicUsize::new(0),
+    };
+    let evaluated = Cell::new(0);
+    let expensive = || {
+        evaluated.set(evaluated.get() + 1);
+        42
+    };
+
+    log!(logger: logger, Level::Debug, "value: {}", expensive());
+    score_log::trace!(logger: logger, context: "ctx", "value: {}", expensive());
+    assert_eq!(evaluated.get(), 0);
+    assert_eq!(logger.logged.load(Ordering::Relaxed), 0);
+
+    log!(logger: logger, Level::Info, "value: {}", expensive());
+    assert_eq!(evaluated.get(), 1);
+    assert_eq!(logger.logged.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn pushed_context() {
+    struct ContextLogger {
+        last_context: Mutex<String>,
+    }
+
+    impl Log for ContextLogger {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+        fn context(&self) -> &str {
+            "LOGGER"
+        }
+        fn log(&self, record: &Record) {
+            *self.last_context.lock().unwrap() = record.metadata().context().to_string();
+        }
+        fn flush(&self) {}
+    }
+
+    score_log::set_max_level(LevelFilter::Trace);
+    let logger = ContextLogger {
+        last_context: Mutex::new(String::new()),
+    };
+    let last_context = || logger.last_context.lock().unwrap().clone();
+
+    score_log::info!(logger: logger, "hello");
+    assert_eq!(last_context(), "LOGGER");
+    {
+        let _outer = score_log::push_context("NET");
+        score_log::info!(logger: logger, "hello");
+        assert_eq!(last_context(), "NET");
+        {
+            let _inner = score_log::push_context("TCP");
+            score_log::info!(logger: logger, "hello");
+            assert_eq!(last_context(), "TCP");
+            score_log::info!(logger: logger, context: "explicit", "hello");
+            assert_eq!(last_context(), "explicit");
+        }
+        score_log::info!(logger: logger, "hello");
+        assert_eq!(last_context(), "NET");
+    }
+    score_log::info!(logger: logger, "hello");
+    assert_eq!(last_context(), "LOGGER");
+}
+
+#[test]
+fn lazy_closure_not_called_when_disabled() {
+    score_log::set_max_level(LevelFilter::Trace);
+    let logger = InfoLogger {
+        logged: AtomicUsize::new(0),
+    };
+    let called = Cell::new(0);
+    let message = || {
+        called.set(called.get() + 1);
+        "message"
+    };
+
+    log_lazy!(logger: logger, Level::Debug, message);
+    log_lazy!(logger: logger, context: "ctx", Level::Trace, || message().len());
+    log_lazy!(logger: Logger, Level::Fatal, message);
+    assert_eq!(called.get(), 0);
+    assert_eq!(logger.logged.load(Ordering::Relaxed), 0);
+
+    log_lazy!(logger: logger, Level::Info, message);
+    log_lazy!(logger: logger, context: "ctx", Level::Error, message,);
+    assert_eq!(called.get(), 2);
+    assert_eq!(logger.logged.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn target() {
+    struct TargetLogger {
+        last_target: Mutex<Option<String>>,
+    }
+
+    impl Log for TargetLogger {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+        fn context(&self) -> &str {
+            "TEST"
+        }
+        fn log(&self, record: &Record) {
+            *self.last_target.lock().unwrap() = record.target().map(str::to_string);
+        }
+        fn flush(&self) {}
+    }
+
+    score_log::set_max_level(LevelFilter::Trace);
+    let logger = TargetLogger {
+        last_target: Mutex::new(None),
+    };
+    let last_target = || logger.last_target.lock().unwrap().take();
+
+    log!(logger: logger, Level::Info, "hello");
+    assert_eq!(last_target(), None);
+    log!(logger: logger, target: "net", Level::Info, "hello {}", 1);
+    assert_eq!(last_target().as_deref(), Some("net"));
+    log!(logger: logger, context: "ctx", target: "db", Level::Info, "hello");
+    assert_eq!(last_target().as_deref(), Some("db"));
+    log!(logger: logger, context: "ctx", Level::Info, "hello");
+    assert_eq!(last_target(), None);
+
+    // Global logger forms compile.
+    for lvl in Level::iter() {
+        log!(target: "net", lvl, "hello");
+        log!(context: "my_context", target: "net", lvl, "hello {}", 1);
+    }
+}
+
+#[test]
+fn assert_logs_fatal_and_panics() {
+    struct RecordingLogger {
+        records: Mutex<Vec<(Level, String, String)>>,
+    }
+
+    impl Log for RecordingLogger {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+        fn context(&self) -> &str {
+            "TEST"
+        }
+        fn log(&self, record: &Record) {
+            let message = score_log::fmt::format(*record.args());
+            let context = record.context().to_string();
+            self.records.lock().unwrap().push((record.level(), context, message));
+        }
+        fn flush(&self) {}
+    }
+
+    /// Runs `f` and returns the panic message, if any.
+    fn panic_message(f: impl FnOnce()) -> Option<String> {
+        let payload = std::panic::catch_unwind(core::panic::AssertUnwindSafe(f)).err()?;
+        Some(*payload.downcast::<String>().unwrap())
+    }
+
+    score_log::set_max_level(LevelFilter::Trace);
+    let logger = RecordingLogger {
+        records: Mutex::new(Vec::new()),
+    };
+    let take_records = || core::mem::take(&mut *logger.records.lock().unwrap());
+
+    // Nothing is logged or evaluated if the condition holds.
+    let evaluated = Cell::new(0);
+    let expensive = || {
+        evaluated.set(evaluated.get() + 1);
+        42
+    };
+    assert_eq!(
+        panic_message(|| log_assert!(logger: logger, 1 + 1 == 2, "value: {}", expensive())),
+        None
+    );
+    assert_eq!(evaluated.get(), 0);
+    assert!(take_records().is_empty());
+
+    let value = 5;
+    let message = panic_message(|| log_assert!(logger: logger, value < 3, "value too large: {}", value));
+    assert_eq!(message.as_deref(), Some("value too large: 5"));
+    assert_eq!(
+        take_records(),
+        [(Level::Fatal, "TEST".to_string(), "value too large: 5".to_string())]
+    );
+
+    let message = panic_message(|| log_assert!(logger: logger, context: "ctx", value == 0, "value: {}", value,));
+    assert_eq!(message.as_deref(), Some("value: 5"));
+    assert_eq!(
+        take_records(),
+        [(Level::Fatal, "ctx".to_string(), "value: 5".to_string())]
+    );
+
+    // Default message.
+    let message = panic_message(|| log_assert!(logger: logger, value == 0));
+    assert_eq!(message.as_deref(), Some("assertion failed: value == 0"));
+    assert_eq!(
+        take_records(),
+        [(
+            Level::Fatal,
+            "TEST".to_string(),
+            "assertion failed: value == 0".to_string()
+        )]
+    );
+
+    // Global logger forms compile.
+    log_assert!(true);
+    log_assert!(true,);
+    log_assert!(true, "hello {}", "world");
+    log_assert!(context: "my_context", true, "hello");
+    log_assert!(logger: Logger, context: "my_context", true);
+}
+
+#[test]
+fn raw_message() {
+    struct RecordingLogger {
+        messages: Mutex<Vec<String>>,
+    }
+
+    impl Log for RecordingLogger {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+        fn context(&self) -> &str {
+            "TEST"
+        }
+        fn log(&self, record: &Record) {
+            assert!(matches!(record.args().0, [score_log::fmt::Fragment::Raw(_)]));
+            let message = score_log::fmt::format(*record.args());
+            self.messages.lock().unwrap().push(message);
+        }
+        fn flush(&self) {}
+    }
+
+    score_log::set_max_level(LevelFilter::Trace);
+    let logger = RecordingLogger {
+        messages: Mutex::new(Vec::new()),
+    };
+
+    // Already formatted user data with placeholders is logged as is.
+    let user_data = String::from("user {} {name} {:?}");
+    log!(logger: logger, Level::Info, raw: user_data);
+    score_log::error!(logger: logger, context: "ctx", raw: &user_data);
+    score_log::warn!(logger: logger, raw: "{}",);
+    assert_eq!(
+        *logger.messages.lock().unwrap(),
+        ["user {} {name} {:?}", "user {} {name} {:?}", "{}"]
+    );
+
+    // Global logger forms compile.
+    log!(Level::Info, raw: user_data);
+    score_log::info!(raw: user_data);
+}
@@ -0,0 +1,97 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+#![allow(missing_docs)]
+
+use score_log::fmt::{FormatSpec, ScoreDebug, Writer};
+use score_log::{LevelFilter, Log, Metadata, Record};
+use std::sync::Mutex;
+
+/// Messages logged by all `RecordingLogger` instances, prefixed with the logger context.
+static RECORDS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+struct RecordingLogger {
+    context: &'static str,
+}
+
+impl Log for RecordingLogger {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+    fn context(&self) -> &str {
+        self.context
+    }
+    fn log(&self, record: &Record) {
+        // Rendering might log, so it's done before the records are locked.
+        let message = score_log::fmt::format(*record.args());
+        RECORDS.lock().unwrap().push(format!("{}:{}", self.context, message));
+
+        match message.as_str() {
+            "replace" => {
+                // Replacement from another thread must not wait for this log call to finish.
+                std::thread::spawn(|| score_log::replace_global_logger(Box::new(RecordingLogger { context: "B" })))
+                    .join()
+                    .unwrap();
+                // Nested log calls go to the new logger.
+                score_log::info!("nested");
+            },
+            "replace from same thread" => {
+                score_log::replace_global_logger(Box::new(RecordingLogger { context: "C" }));
+            },
+            _ => {},
+        }
+    }
+    fn flush(&self) {}
+}
+
+/// Argument logging while it is rendered.
+struct LoggingArg;
+
+impl ScoreDebug for LoggingArg {
+    fn fmt(&self, f: Writer, spec: &FormatSpec) -> score_log::fmt::Result {
+        score_log::info!("from argument");
+        f.write_str("arg", spec)
+    }
+}
+
+// Global logger is replaced, so it's tested in a separate binary.
+#[test]
+fn nested_logging() {
+    score_log::set_max_level(LevelFilter::Trace);
+    assert!(score_log::set_global_logger(Box::new(RecordingLogger { context: "A" })).is_ok());
+
+    // Reference kept across log calls, e.g., as done in the macro examples.
+    let logger = score_log::global_logger();
+    score_log::info!("value: {}", LoggingArg);
+    score_log::info!("replace");
+    score_log::info!("after");
+    score_log::info!("replace from same thread");
+    score_log::info!("after same thread");
+    score_log::info!(logger: logger, "old reference");
+
+    let previous = score_log::replace_global_logger(Box::new(RecordingLogger { context: "D" }));
+    assert_eq!(previous.context(), "C");
+    assert_eq!(
+        *RECORDS.lock().unwrap(),
+        [
+            "A:from argument",
+            "A:value: arg",
+            "A:replace",
+            "B:nested",
+            "B:after",
+            "B:replace from same thread",
+            "C:after same thread",
+            "A:old reference",
+        ]
+    );
+}
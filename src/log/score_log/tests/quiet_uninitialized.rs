@@ -0,0 +1,58 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+#![allow(missing_docs)]
+
+use std::process::Command;
+
+/// Set for the child process running the uninitialized logger.
+const CHILD_ENV: &str = "SCORE_LOG_QUIET_UNINITIALIZED_CHILD";
+
+/// Runs `test_name` from this binary in a child process and returns its stderr.
+///
+/// Warning is printed once per process and directly to stderr, so it can only be observed from outside.
+fn child_stderr(test_name: &str) -> String {
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", test_name, "--nocapture", "--test-threads=1"])
+        .env(CHILD_ENV, "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stderr).unwrap()
+}
+
+fn use_uninitialized_logger() {
+    score_log::global_logger().flush();
+    score_log::info!("hello");
+}
+
+#[test]
+fn warns_when_uninitialized() {
+    if std::env::var_os(CHILD_ENV).is_some() {
+        use_uninitialized_logger();
+        return;
+    }
+    let stderr = child_stderr("warns_when_uninitialized");
+    assert_eq!(stderr.matches("warn: logger not initialized").count(), 1);
+}
+
+#[test]
+fn quiet_when_uninitialized() {
+    if std::env::var_os(CHILD_ENV).is_some() {
+        score_log::set_quiet_uninitialized(true);
+        use_uninitialized_logger();
+        return;
+    }
+    let stderr = child_stderr("quiet_when_uninitialized");
+    assert!(!stderr.contains("logger not initialized"));
+}
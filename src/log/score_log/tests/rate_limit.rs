@@ -0,0 +1,92 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+#![allow(missing_docs)]
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+use score_log::{debug_every_n, info_once, log_every_n, log_once, Level, LevelFilter, Log, Metadata, Record};
+
+// NOTE: all tests in this file expect `LevelFilter::Trace` as the maximum log level.
+
+struct CountingLogger {
+    count: AtomicUsize,
+}
+
+impl CountingLogger {
+    fn new() -> Self {
+        score_log::set_max_level(LevelFilter::Trace);
+        Self {
+            count: AtomicUsize::new(0),
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.count.load(Ordering::Relaxed)
+    }
+}
+
+impl Log for CountingLogger {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn context(&self) -> &str {
+        "TEST"
+    }
+
+    fn log(&self, _: &Record) {
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn flush(&self) {}
+}
+
+#[test]
+fn log_once_single_call_site() {
+    let logger = CountingLogger::new();
+    for i in 0..10 {
+        log_once!(logger: logger, Level::Info, "hello {}", i);
+    }
+    assert_eq!(logger.count(), 1);
+}
+
+#[test]
+fn log_once_multiple_call_sites() {
+    let logger = CountingLogger::new();
+    for i in 0..10 {
+        log_once!(logger: logger, context: "my_context", Level::Info, "hello {}", i);
+        info_once!(logger: logger, "hello {}", i);
+        info_once!(logger: logger, context: "my_context", "hello {}", i);
+    }
+    assert_eq!(logger.count(), 3);
+}
+
+#[test]
+fn log_every_n_single_call_site() {
+    let logger = CountingLogger::new();
+    for i in 0..10 {
+        log_every_n!(3, logger: logger, Level::Info, "hello {}", i);
+    }
+    assert_eq!(logger.count(), 4);
+}
+
+#[test]
+fn log_every_n_multiple_call_sites() {
+    let logger = CountingLogger::new();
+    for i in 0..10 {
+        log_every_n!(3, logger: logger, context: "my_context", Level::Info, "hello {}", i);
+        debug_every_n!(5, logger: logger, "hello {}", i);
+        debug_every_n!(0, logger: logger, context: "my_context", "hello {}", i);
+    }
+    assert_eq!(logger.count(), 4 + 2 + 10);
+}
@@ -0,0 +1,78 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+#![allow(missing_docs)]
+
+use score_log::fmt::FmtWriteAdapter;
+use score_log::{LevelFilter, Log, Metadata, Record};
+use std::io::{BufWriter, Write};
+use std::sync::{Arc, Mutex};
+
+/// Sink shared between the logger and the test.
+#[derive(Clone, Default)]
+struct SharedSink(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedSink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Logger writing records through a buffer, like file-based loggers do.
+struct BufferedLogger {
+    writer: Mutex<BufWriter<SharedSink>>,
+}
+
+impl Log for BufferedLogger {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn context(&self) -> &str {
+        "TEST"
+    }
+
+    fn log(&self, record: &Record) {
+        let mut line = FmtWriteAdapter::new(String::new());
+        let _ = score_log::fmt::write(&mut line, *record.args());
+        let _ = writeln!(self.writer.lock().unwrap(), "{}", line.get_ref());
+    }
+
+    fn flush(&self) {
+        let _ = self.writer.lock().unwrap().flush();
+    }
+}
+
+#[test]
+fn replaced_logger_is_flushed() {
+    let sink = SharedSink::default();
+    let logger = BufferedLogger {
+        writer: Mutex::new(BufWriter::new(sink.clone())),
+    };
+    assert!(score_log::set_global_logger(Box::new(logger)).is_ok());
+    score_log::set_max_level(LevelFilter::Trace);
+
+    score_log::info!("first");
+    score_log::info!("second");
+    assert!(sink.0.lock().unwrap().is_empty());
+
+    let _previous = score_log::replace_global_logger(Box::new(BufferedLogger {
+        writer: Mutex::new(BufWriter::new(SharedSink::default())),
+    }));
+    assert_eq!(sink.0.lock().unwrap().as_slice(), b"first\nsecond\n");
+}
@@ -13,11 +13,89 @@
 
 //! Implementations of [`ScoreDebug`] implementation helper builders.
 
-use crate::{FormatSpec, Result, ScoreDebug, Writer};
+use crate::{FormatSpec, Result, ScoreDebug, ScoreWrite, Writer};
+
+/// Writer indenting every line written through it by one level.
+///
+/// Used for the alternate (`{:#?}`) output of the builders.
+/// Nested builders wrap the adapter of their parent, so the indentation grows with the nesting depth.
+struct PadAdapter<'a> {
+    writer: Writer<'a>,
+    on_newline: bool,
+}
+
+impl<'a> PadAdapter<'a> {
+    fn new(writer: Writer<'a>) -> Self {
+        PadAdapter {
+            writer,
+            on_newline: true,
+        }
+    }
+
+    fn pad(&mut self) -> Result {
+        if self.on_newline {
+            self.on_newline = false;
+            self.writer.write_str("    ", &FormatSpec::new())?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_pad_write {
+    ($($method:ident: $t:ty),* $(,)?) => {
+        $(
+            fn $method(&mut self, v: &$t, spec: &FormatSpec) -> Result {
+                self.pad()?;
+                self.writer.$method(v, spec)
+            }
+        )*
+    };
+}
+
+impl ScoreWrite for PadAdapter<'_> {
+    impl_pad_write!(
+        write_bool: bool,
+        write_f32: f32,
+        write_f64: f64,
+        write_i8: i8,
+        write_i16: i16,
+        write_i32: i32,
+        write_i64: i64,
+        write_u8: u8,
+        write_u16: u16,
+        write_u32: u32,
+        write_u64: u64,
+        write_bytes: [u8],
+    );
+
+    fn write_str(&mut self, v: &str, spec: &FormatSpec) -> Result {
+        if !v.contains('\n') {
+            self.pad()?;
+            return self.writer.write_str(v, spec);
+        }
+        for line in v.split_inclusive('\n') {
+            self.pad()?;
+            self.writer.write_str(line, spec)?;
+            self.on_newline = line.ends_with('\n');
+        }
+        Ok(())
+    }
+
+    fn write_int_radix(&mut self, value: u128, negative: bool, spec: &FormatSpec) -> Result {
+        self.pad()?;
+        self.writer.write_int_radix(value, negative, spec)
+    }
+
+    fn write_ptr(&mut self, v: *const (), spec: &FormatSpec) -> Result {
+        self.pad()?;
+        self.writer.write_ptr(v, spec)
+    }
+}
 
 /// Output a formatted struct.
 ///
 /// Useful as a part of [`ScoreDebug::fmt`] implementation.
+/// If the alternate flag is set (`{:#?}`), each field is written on its own indented line, same as in std.
 #[must_use = "must eventually call `finish()` on ScoreDebug builders"]
 pub struct DebugStruct<'a> {
     writer: Writer<'a>,
@@ -51,12 +129,23 @@ impl<'a> DebugStruct<'a> {
         F: FnOnce(Writer) -> Result,
     {
         self.result = self.result.and_then(|_| {
-            let prefix = if self.has_fields { ", " } else { " { " };
             let empty_spec = FormatSpec::new();
-            self.writer.write_str(prefix, &empty_spec)?;
-            self.writer.write_str(name, &empty_spec)?;
-            self.writer.write_str(": ", &empty_spec)?;
-            value_fmt(self.writer)
+            if self.spec.get_alternate() {
+                if !self.has_fields {
+                    self.writer.write_str(" {\n", &empty_spec)?;
+                }
+                let mut writer = PadAdapter::new(self.writer);
+                writer.write_str(name, &empty_spec)?;
+                writer.write_str(": ", &empty_spec)?;
+                value_fmt(&mut writer)?;
+                writer.write_str(",\n", &empty_spec)
+            } else {
+                let prefix = if self.has_fields { ", " } else { " { " };
+                self.writer.write_str(prefix, &empty_spec)?;
+                self.writer.write_str(name, &empty_spec)?;
+                self.writer.write_str(": ", &empty_spec)?;
+                value_fmt(self.writer)
+            }
         });
 
         self.has_fields = true;
@@ -67,10 +156,13 @@ impl<'a> DebugStruct<'a> {
     pub fn finish_non_exhaustive(&mut self) -> Result {
         self.result = self.result.and_then(|_| {
             let empty_spec = FormatSpec::new();
-            if self.has_fields {
-                self.writer.write_str(", .. }", &empty_spec)
-            } else {
+            if !self.has_fields {
                 self.writer.write_str(" { .. }", &empty_spec)
+            } else if self.spec.get_alternate() {
+                PadAdapter::new(self.writer).write_str("..\n", &empty_spec)?;
+                self.writer.write_str("}", &empty_spec)
+            } else {
+                self.writer.write_str(", .. }", &empty_spec)
             }
         });
         self.result
@@ -80,7 +172,8 @@ impl<'a> DebugStruct<'a> {
     pub fn finish(&mut self) -> Result {
         if self.has_fields {
             let empty_spec = FormatSpec::new();
-            self.result = self.result.and_then(|_| self.writer.write_str(" }", &empty_spec));
+            let suffix = if self.spec.get_alternate() { "}" } else { " }" };
+            self.result = self.result.and_then(|_| self.writer.write_str(suffix, &empty_spec));
         }
         self.result
     }
@@ -89,6 +182,7 @@ impl<'a> DebugStruct<'a> {
 /// Output a formatted tuple.
 ///
 /// Useful as a part of [`ScoreDebug::fmt`] implementation.
+/// If the alternate flag is set (`{:#?}`), each field is written on its own indented line, same as in std.
 #[must_use = "must eventually call `finish()` on ScoreDebug builders"]
 pub struct DebugTuple<'a> {
     writer: Writer<'a>,
@@ -124,10 +218,19 @@ impl<'a> DebugTuple<'a> {
         F: FnOnce(Writer) -> Result,
     {
         self.result = self.result.and_then(|_| {
-            let prefix = if self.fields == 0 { "(" } else { ", " };
             let empty_spec = FormatSpec::new();
-            self.writer.write_str(prefix, &empty_spec)?;
-            value_fmt(self.writer)
+            if self.spec.get_alternate() {
+                if self.fields == 0 {
+                    self.writer.write_str("(\n", &empty_spec)?;
+                }
+                let mut writer = PadAdapter::new(self.writer);
+                value_fmt(&mut writer)?;
+                writer.write_str(",\n", &empty_spec)
+            } else {
+                let prefix = if self.fields == 0 { "(" } else { ", " };
+                self.writer.write_str(prefix, &empty_spec)?;
+                value_fmt(self.writer)
+            }
         });
 
         self.fields += 1;
@@ -138,10 +241,13 @@ impl<'a> DebugTuple<'a> {
     pub fn finish_non_exhaustive(&mut self) -> Result {
         self.result = self.result.and_then(|_| {
             let empty_spec = FormatSpec::new();
-            if self.fields > 0 {
-                self.writer.write_str(", ..)", &empty_spec)
-            } else {
+            if self.fields == 0 {
                 self.writer.write_str("(..)", &empty_spec)
+            } else if self.spec.get_alternate() {
+                PadAdapter::new(self.writer).write_str("..\n", &empty_spec)?;
+                self.writer.write_str(")", &empty_spec)
+            } else {
+                self.writer.write_str(", ..)", &empty_spec)
             }
         });
         self.result
@@ -152,7 +258,7 @@ impl<'a> DebugTuple<'a> {
         if self.fields > 0 {
             self.result = self.result.and_then(|_| {
                 let empty_spec = FormatSpec::new();
-                if self.fields == 1 && self.empty_name {
+                if self.fields == 1 && self.empty_name && !self.spec.get_alternate() {
                     self.writer.write_str(",", &empty_spec)?;
                 }
                 self.writer.write_str(")", &empty_spec)
@@ -177,19 +283,44 @@ impl<'a> DebugInner<'a> {
     {
         self.result = self.result.and_then(|_| {
             let empty_spec = FormatSpec::new();
-            if self.has_fields {
-                self.writer.write_str(", ", &empty_spec)?
+            if self.spec.get_alternate() {
+                if !self.has_fields {
+                    self.writer.write_str("\n", &empty_spec)?;
+                }
+                let mut writer = PadAdapter::new(self.writer);
+                entry_writer(&mut writer)?;
+                writer.write_str(",\n", &empty_spec)
+            } else {
+                if self.has_fields {
+                    self.writer.write_str(", ", &empty_spec)?
+                }
+                entry_writer(self.writer)
             }
-            entry_writer(self.writer)
         });
 
         self.has_fields = true;
     }
+
+    /// Writes the non-exhaustive marker followed by the closing bracket `close`.
+    fn finish_non_exhaustive(&mut self, close: &str) -> Result {
+        self.result.and_then(|_| {
+            let empty_spec = FormatSpec::new();
+            if !self.has_fields {
+                self.writer.write_str("..", &empty_spec)?;
+            } else if self.spec.get_alternate() {
+                PadAdapter::new(self.writer).write_str("..\n", &empty_spec)?;
+            } else {
+                self.writer.write_str(", ..", &empty_spec)?;
+            }
+            self.writer.write_str(close, &empty_spec)
+        })
+    }
 }
 
 /// Output a formatted set of items.
 ///
 /// Useful as a part of [`ScoreDebug::fmt`] implementation.
+/// If the alternate flag is set (`{:#?}`), each entry is written on its own indented line, same as in std.
 #[must_use = "must eventually call `finish()` on ScoreDebug builders"]
 pub struct DebugSet<'a> {
     inner: DebugInner<'a>,
@@ -240,14 +371,7 @@ impl<'a> DebugSet<'a> {
 
     /// Marks the set as non-exhaustive, indicating to the reader that there are some other elements that are not shown in the debug representation.
     pub fn finish_non_exhaustive(&mut self) -> Result {
-        self.inner.result = self.inner.result.and_then(|_| {
-            let empty_spec = FormatSpec::new();
-            if self.inner.has_fields {
-                self.inner.writer.write_str(", ..}", &empty_spec)
-            } else {
-                self.inner.writer.write_str("..}", &empty_spec)
-            }
-        });
+        self.inner.result = self.inner.finish_non_exhaustive("}");
         self.inner.result
     }
 
@@ -264,6 +388,7 @@ impl<'a> DebugSet<'a> {
 /// Output a formatted list of items.
 ///
 /// Useful as a part of [`ScoreDebug::fmt`] implementation.
+/// If the alternate flag is set (`{:#?}`), each entry is written on its own indented line, same as in std.
 #[must_use = "must eventually call `finish()` on ScoreDebug builders"]
 pub struct DebugList<'a> {
     inner: DebugInner<'a>,
@@ -314,14 +439,7 @@ impl<'a> DebugList<'a> {
 
     /// Marks the list as non-exhaustive, indicating to the reader that there are some other elements that are not shown in the debug representation.
     pub fn finish_non_exhaustive(&mut self) -> Result {
-        self.inner.result.and_then(|_| {
-            let empty_spec = FormatSpec::new();
-            if self.inner.has_fields {
-                self.inner.writer.write_str(", ..]", &empty_spec)
-            } else {
-                self.inner.writer.write_str("..]", &empty_spec)
-            }
-        })
+        self.inner.finish_non_exhaustive("]")
     }
 
     /// Finishes output and returns any error encountered.
@@ -337,6 +455,7 @@ impl<'a> DebugList<'a> {
 /// Output a formatted map of items.
 ///
 /// Useful as a part of [`ScoreDebug::fmt`] implementation.
+/// If the alternate flag is set (`{:#?}`), each entry is written on its own indented line, same as in std.
 #[must_use = "must eventually call `finish()` on ScoreDebug builders"]
 pub struct DebugMap<'a> {
     writer: Writer<'a>,
@@ -392,11 +511,20 @@ impl<'a> DebugMap<'a> {
             );
 
             let empty_spec = FormatSpec::new();
-            if self.has_fields {
-                self.writer.write_str(", ", &empty_spec)?
+            if self.spec.get_alternate() {
+                if !self.has_fields {
+                    self.writer.write_str("\n", &empty_spec)?;
+                }
+                let mut writer = PadAdapter::new(self.writer);
+                key_fmt(&mut writer)?;
+                writer.write_str(": ", &empty_spec)?;
+            } else {
+                if self.has_fields {
+                    self.writer.write_str(", ", &empty_spec)?
+                }
+                key_fmt(self.writer)?;
+                self.writer.write_str(": ", &empty_spec)?;
             }
-            key_fmt(self.writer)?;
-            self.writer.write_str(": ", &empty_spec)?;
 
             self.has_key = true;
             Ok(())
@@ -427,7 +555,17 @@ impl<'a> DebugMap<'a> {
     {
         self.result = self.result.and_then(|_| {
             assert!(self.has_key, "attempted to format a map value before its key");
-            value_fmt(self.writer)?;
+            if self.spec.get_alternate() {
+                // The key has already been written on the current line, so the value isn't indented again.
+                let mut writer = PadAdapter {
+                    writer: self.writer,
+                    on_newline: false,
+                };
+                value_fmt(&mut writer)?;
+                writer.write_str(",\n", &FormatSpec::new())?;
+            } else {
+                value_fmt(self.writer)?;
+            }
             self.has_key = false;
             Ok(())
         });
@@ -455,10 +593,13 @@ impl<'a> DebugMap<'a> {
             assert!(!self.has_key, "attempted to finish a map with a partial entry");
 
             let empty_spec = FormatSpec::new();
-            if self.has_fields {
-                self.writer.write_str(", ..}", &empty_spec)
-            } else {
+            if !self.has_fields {
                 self.writer.write_str("..}", &empty_spec)
+            } else if self.spec.get_alternate() {
+                PadAdapter::new(self.writer).write_str("..\n", &empty_spec)?;
+                self.writer.write_str("}", &empty_spec)
+            } else {
+                self.writer.write_str(", ..}", &empty_spec)
             }
         });
         self.result
@@ -484,7 +625,8 @@ impl<'a> DebugMap<'a> {
 mod tests {
     use crate::builders::{DebugList, DebugMap, DebugSet, DebugStruct, DebugTuple};
     use crate::test_utils::StringWriter;
-    use crate::{DisplayHint, FormatSpec};
+    use crate::{DisplayHint, FormatSpec, Result, ScoreDebug, Writer};
+    use std::collections::{BTreeMap, BTreeSet, HashMap};
 
     #[test]
     fn test_struct_finish_non_exhaustive() {
@@ -783,4 +925,162 @@ mod tests {
 
         assert_eq!(writer.get(), format!("{:?}", v));
     }
+
+    fn pretty_spec() -> FormatSpec {
+        let mut spec = FormatSpec::new();
+        spec.display_hint(DisplayHint::Debug).alternate(true);
+        spec
+    }
+
+    #[derive(Debug)]
+    struct Inner {
+        x: i32,
+        values: Vec<u32>,
+        empty: Vec<u32>,
+    }
+
+    impl ScoreDebug for Inner {
+        fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+            DebugStruct::new(f, spec, "Inner")
+                .field("x", &self.x)
+                .field("values", &self.values)
+                .field("empty", &self.empty)
+                .finish()
+        }
+    }
+
+    #[derive(Debug)]
+    struct Unit;
+
+    impl ScoreDebug for Unit {
+        fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+            DebugStruct::new(f, spec, "Unit").finish()
+        }
+    }
+
+    #[derive(Debug)]
+    struct Outer {
+        id: u8,
+        inner: Inner,
+        pair: (i64, Option<u16>),
+        map: HashMap<u8, Inner>,
+        unit: Unit,
+    }
+
+    impl ScoreDebug for Outer {
+        fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+            DebugStruct::new(f, spec, "Outer")
+                .field("id", &self.id)
+                .field("inner", &self.inner)
+                .field("pair", &self.pair)
+                .field("map", &self.map)
+                .field("unit", &self.unit)
+                .finish()
+        }
+    }
+
+    #[test]
+    fn test_pretty_nested_struct() {
+        let v = Outer {
+            id: 7,
+            inner: Inner {
+                x: -12,
+                values: vec![1, 2, 3],
+                empty: vec![],
+            },
+            pair: (i64::MIN, Some(5)),
+            map: HashMap::from([(
+                1,
+                Inner {
+                    x: 0,
+                    values: vec![42],
+                    empty: vec![],
+                },
+            )]),
+            unit: Unit,
+        };
+
+        let mut writer = StringWriter::new();
+        let _ = ScoreDebug::fmt(&v, &mut writer, &pretty_spec()).map_err(|_| panic!("failed to format"));
+        assert_eq!(writer.get(), format!("{:#?}", v));
+
+        // The same value without the alternate flag stays on a single line.
+        let mut writer = StringWriter::new();
+        let mut spec = FormatSpec::new();
+        spec.display_hint(DisplayHint::Debug);
+        let _ = ScoreDebug::fmt(&v, &mut writer, &spec).map_err(|_| panic!("failed to format"));
+        assert_eq!(writer.get(), format!("{:?}", v));
+    }
+
+    #[test]
+    fn test_pretty_collections() {
+        let tuple = (1, (2, 3), [4, 5]);
+        let mut writer = StringWriter::new();
+        let _ = ScoreDebug::fmt(&tuple, &mut writer, &pretty_spec()).map_err(|_| panic!("failed to format"));
+        assert_eq!(writer.get(), format!("{:#?}", tuple));
+
+        let single = (531,);
+        let mut writer = StringWriter::new();
+        let spec = pretty_spec();
+        let _ = DebugTuple::new(&mut writer, &spec, "")
+            .field(&single.0)
+            .finish()
+            .map_err(|_| panic!("failed to finish"));
+        assert_eq!(writer.get(), format!("{:#?}", single));
+
+        let set = BTreeSet::from([123, 456]);
+        let mut writer = StringWriter::new();
+        let _ = DebugSet::new(&mut writer, &spec)
+            .entries(set.clone())
+            .finish()
+            .map_err(|_| panic!("failed to finish"));
+        assert_eq!(writer.get(), format!("{:#?}", set));
+
+        let map = BTreeMap::from([("first", vec![1, 2]), ("second", vec![])]);
+        let mut writer = StringWriter::new();
+        let _ = DebugMap::new(&mut writer, &spec)
+            .entries(map.clone())
+            .finish()
+            .map_err(|_| panic!("failed to finish"));
+        assert_eq!(writer.get(), format!("{:#?}", map));
+    }
+
+    #[test]
+    fn test_pretty_finish_non_exhaustive() {
+        let spec = pretty_spec();
+
+        let mut writer = StringWriter::new();
+        let _ = DebugStruct::new(&mut writer, &spec, "Point")
+            .field("x", &123)
+            .finish_non_exhaustive()
+            .map_err(|_| panic!("failed to finish"));
+        assert_eq!(writer.get(), "Point {\n    x: 123,\n    ..\n}");
+
+        let mut writer = StringWriter::new();
+        let _ = DebugTuple::new(&mut writer, &spec, "Point")
+            .field(&123)
+            .finish_non_exhaustive()
+            .map_err(|_| panic!("failed to finish"));
+        assert_eq!(writer.get(), "Point(\n    123,\n    ..\n)");
+
+        let mut writer = StringWriter::new();
+        let _ = DebugList::new(&mut writer, &spec)
+            .entry(&123)
+            .finish_non_exhaustive()
+            .map_err(|_| panic!("failed to finish"));
+        assert_eq!(writer.get(), "[\n    123,\n    ..\n]");
+
+        let mut writer = StringWriter::new();
+        let _ = DebugMap::new(&mut writer, &spec)
+            .entry(&1, &2)
+            .finish_non_exhaustive()
+            .map_err(|_| panic!("failed to finish"));
+        assert_eq!(writer.get(), "{\n    1: 2,\n    ..\n}");
+
+        let mut writer = StringWriter::new();
+        let _ = DebugList::new(&mut writer, &spec)
+            .finish_non_exhaustive()
+            .map_err(|_| panic!("failed to finish"));
+        assert_eq!(writer.get(), "[..]");
+    }
 }
@@ -0,0 +1,203 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! [`ScoreWrite`] implementation counting the written bytes.
+
+use crate::{FmtWriteAdapter, FormatSpec, Result, ScoreWrite};
+use core::fmt::{self, Write};
+
+/// Writer discarding the output and counting the number of bytes written.
+///
+/// Output is counted as rendered by [`FmtWriteAdapter`] and [`StackWriter`](crate::StackWriter),
+/// honoring the spec the same way, e.g., to compute the capacity of a buffer before rendering a message into it.
+#[derive(Default)]
+pub struct CountingWriter {
+    len: usize,
+}
+
+impl CountingWriter {
+    /// Create `CountingWriter` instance.
+    pub const fn new() -> Self {
+        Self { len: 0 }
+    }
+
+    /// Get number of bytes written since the last [`clear`](Self::clear).
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if no bytes were written since the last [`clear`](Self::clear).
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Reset the byte count.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Get adapter rendering the values into this writer.
+    fn adapter(&mut self) -> FmtWriteAdapter<&mut Self> {
+        FmtWriteAdapter::new(self)
+    }
+}
+
+impl Write for CountingWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+impl ScoreWrite for CountingWriter {
+    fn write_bool(&mut self, v: &bool, spec: &FormatSpec) -> Result {
+        self.adapter().write_bool(v, spec)
+    }
+
+    fn write_f32(&mut self, v: &f32, spec: &FormatSpec) -> Result {
+        self.adapter().write_f32(v, spec)
+    }
+
+    fn write_f64(&mut self, v: &f64, spec: &FormatSpec) -> Result {
+        self.adapter().write_f64(v, spec)
+    }
+
+    fn write_str(&mut self, v: &str, spec: &FormatSpec) -> Result {
+        self.adapter().write_str(v, spec)
+    }
+
+    fn write_ptr(&mut self, v: *const (), spec: &FormatSpec) -> Result {
+        self.adapter().write_ptr(v, spec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CountingWriter;
+    use crate::{
+        format, write, Alignment, Arguments, DebugAsHex, DisplayHint, FmtWriteAdapter, FormatSpec, Fragment,
+        Placeholder, ScoreWrite, Sign, StackWriter,
+    };
+
+    #[test]
+    fn test_mixed_message() {
+        let mut hex_spec = FormatSpec::hex();
+        hex_spec.alternate(true);
+        let mut float_spec = FormatSpec::new();
+        float_spec.precision(Some(3));
+        let mut str_spec = FormatSpec::with_width(12);
+        str_spec.align(Some(Alignment::Center)).fill('·');
+        let mut debug_hex_spec = FormatSpec::debug();
+        debug_hex_spec.debug_as_hex(Some(DebugAsHex::Upper));
+        let values = [1u16, 255, 4096];
+        let fragments = [
+            Fragment::Literal("mixed_"),
+            Fragment::Placeholder(Placeholder::new(&true, FormatSpec::new())),
+            Fragment::Literal("_"),
+            Fragment::Placeholder(Placeholder::new(&-123456i64, FormatSpec::new())),
+            Fragment::Literal("_"),
+            Fragment::Placeholder(Placeholder::new(&0xbeefu32, hex_spec)),
+            Fragment::Literal("_"),
+            Fragment::Placeholder(Placeholder::new(&core::f64::consts::PI, float_spec)),
+            Fragment::Literal("_"),
+            Fragment::Placeholder(Placeholder::new(&"日本語", str_spec)),
+            Fragment::Raw("_ß_"),
+            Fragment::Placeholder(Placeholder::new(&values, debug_hex_spec)),
+            Fragment::Placeholder(Placeholder::new(&Some('ü'), FormatSpec::debug())),
+        ];
+        let args = Arguments(&fragments);
+
+        let mut w = CountingWriter::new();
+        assert!(w.is_empty());
+        assert!(write(&mut w, args) == Ok(()));
+        let rendered = format(args);
+        assert_eq!(w.len(), rendered.len());
+
+        // Buffer of the counted size fits the message exactly.
+        let mut buf = FmtWriteAdapter::new(String::with_capacity(w.len()));
+        let capacity = buf.get_ref().capacity();
+        assert!(write(&mut buf, args) == Ok(()));
+        assert_eq!(buf.get_ref(), &rendered);
+        assert_eq!(buf.get_ref().capacity(), capacity);
+
+        w.clear();
+        assert!(w.is_empty());
+    }
+
+    #[test]
+    fn test_matches_stack_writer() {
+        let mut right_spec = FormatSpec::with_width(6);
+        right_spec.align(Some(Alignment::Right));
+        let mut zero_pad_spec = FormatSpec::with_width(6);
+        zero_pad_spec.zero_pad(true);
+        let mut sign_spec = FormatSpec::new();
+        sign_spec.sign(Some(Sign::Plus));
+        let mut bool_spec = FormatSpec::with_width(5);
+        bool_spec.align(Some(Alignment::Right));
+        let mut float_spec = FormatSpec::with_width(8);
+        float_spec.precision(Some(2));
+        let mut signed_float_spec = FormatSpec::with_width(10);
+        signed_float_spec.align(Some(Alignment::Center)).sign(Some(Sign::Plus));
+        let fragments = [
+            Fragment::Literal("["),
+            Fragment::Placeholder(Placeholder::new(&42u32, right_spec)),
+            Fragment::Literal("]["),
+            Fragment::Placeholder(Placeholder::new(&-7i32, zero_pad_spec)),
+            Fragment::Literal("]["),
+            Fragment::Placeholder(Placeholder::new(&5i32, sign_spec)),
+            Fragment::Literal("]["),
+            Fragment::Placeholder(Placeholder::new(&true, bool_spec)),
+            Fragment::Literal("]["),
+            Fragment::Placeholder(Placeholder::new(&12.34567f64, float_spec)),
+            Fragment::Literal("]["),
+            Fragment::Placeholder(Placeholder::new(&-0.5f32, signed_float_spec)),
+            Fragment::Literal("]"),
+        ];
+        let args = Arguments(&fragments);
+
+        let mut w = CountingWriter::new();
+        assert!(write(&mut w, args) == Ok(()));
+        let mut stack_writer = StackWriter::<256>::new();
+        assert!(write(&mut stack_writer, args) == Ok(()));
+        assert!(!stack_writer.overflowed());
+        assert_eq!(w.len(), stack_writer.get().len());
+        assert_eq!(
+            stack_writer.get(),
+            std::format!(
+                "[{:>6}][{:06}][{:+}][{:>5}][{:8.2}][{:^+10}]",
+                42u32,
+                -7i32,
+                5i32,
+                true,
+                12.34567f64,
+                -0.5f32
+            )
+        );
+    }
+
+    #[test]
+    fn test_display_hints() {
+        let cases = [
+            (DisplayHint::NoHint, std::format!("{}", u64::MAX)),
+            (DisplayHint::Binary, std::format!("{:b}", u64::MAX)),
+            (DisplayHint::Octal, std::format!("{:o}", u64::MAX)),
+        ];
+        for (display_hint, expected) in cases {
+            let mut w = CountingWriter::new();
+            let mut spec = FormatSpec::new();
+            spec.display_hint(display_hint);
+            assert!(w.write_u64(&u64::MAX, &spec) == Ok(()));
+            assert_eq!(w.len(), expected.len());
+        }
+    }
+}
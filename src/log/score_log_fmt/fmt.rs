@@ -11,7 +11,7 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
-use crate::FormatSpec;
+use crate::{Alignment, DebugList, DisplayHint, FmtWriteAdapter, FormatSpec, Sign};
 use core::marker::PhantomData;
 use core::ptr::NonNull;
 
@@ -29,10 +29,177 @@ pub type Writer<'a> = &'a mut dyn ScoreWrite;
 #[derive(Copy, Clone, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Error;
 
+/// Write a signed integer with [`ScoreWrite::write_int_radix`].
+///
+/// Same as [`core::fmt`], non-decimal radixes write the two's complement of negative values.
+macro_rules! write_signed_int {
+    ($writer:expr, $v:expr, $unsigned:ty, $spec:expr) => {{
+        let v = $v;
+        match $spec.get_display_hint() {
+            DisplayHint::Octal | DisplayHint::LowerHex | DisplayHint::UpperHex | DisplayHint::Binary => {
+                $writer.write_int_radix(u128::from(v as $unsigned), false, $spec)
+            },
+            _ => $writer.write_int_radix(u128::from(v.unsigned_abs()), v < 0, $spec),
+        }
+    }};
+}
+
+/// Write digits of `value` in `RADIX` at the end of `buf`, returning the position of the first digit.
+///
+/// Radix is a constant, so that division is optimized into multiplication or shifts.
+fn write_digits<const RADIX: u64>(buf: &mut [u8; 128], value: u128, digits: &[u8; 16]) -> usize {
+    let mut pos = buf.len();
+    // 64-bit division is much cheaper than 128-bit division, and is enough for all types except `u128`.
+    if let Ok(mut rest) = u64::try_from(value) {
+        // Decimal digits are written in groups of four and pairs, reducing the number of 64-bit divisions.
+        if RADIX == 10 {
+            const PAIRS: &[u8; 200] = b"0001020304050607080910111213141516171819\
+                2021222324252627282930313233343536373839\
+                4041424344454647484950515253545556575859\
+                6061626364656667686970717273747576777879\
+                8081828384858687888990919293949596979899";
+            let mut write_pair = |pos: usize, pair: u32| {
+                let pair = pair as usize * 2;
+                buf[pos..pos + 2].copy_from_slice(&PAIRS[pair..pair + 2]);
+            };
+            while rest >= 10_000 {
+                // Remainder fits in 32 bits, which is cheaper to split further.
+                let group = (rest % 10_000) as u32;
+                rest /= 10_000;
+                pos -= 4;
+                write_pair(pos, group / 100);
+                write_pair(pos + 2, group % 100);
+            }
+            let mut rest = rest as u32;
+            if rest >= 100 {
+                pos -= 2;
+                write_pair(pos, rest % 100);
+                rest /= 100;
+            }
+            if rest >= 10 {
+                pos -= 2;
+                write_pair(pos, rest);
+            } else {
+                pos -= 1;
+                buf[pos] = digits[rest as usize];
+            }
+            return pos;
+        }
+        loop {
+            pos -= 1;
+            buf[pos] = digits[(rest % RADIX) as usize];
+            rest /= RADIX;
+            if rest == 0 {
+                break;
+            }
+        }
+    } else {
+        let mut rest = value;
+        loop {
+            pos -= 1;
+            buf[pos] = digits[(rest % u128::from(RADIX)) as usize];
+            rest /= u128::from(RADIX);
+            if rest == 0 {
+                break;
+            }
+        }
+    }
+    pos
+}
+
+/// [`core::fmt::Write`] sink forwarding into a [`ScoreWrite`] writer with [`ScoreWrite::write_str`].
+struct ScoreWriteSink<'a, W: ScoreWrite + ?Sized>(&'a mut W);
+
+impl<W: ScoreWrite + ?Sized> core::fmt::Write for ScoreWriteSink<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0.write_str(s, &FormatSpec::new()).map_err(|_| core::fmt::Error)
+    }
+}
+
+/// [`core::fmt::Write`] sink counting the written bytes.
+struct LenSink(usize);
+
+impl core::fmt::Write for LenSink {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// Render a float with [`core::fmt`], honoring the exponent display hints, precision and sign,
+/// and padding with zeros after the sign up to `zero_pad_width`.
+fn render_float<T, W>(out: &mut W, v: &T, spec: &FormatSpec, zero_pad_width: usize) -> core::fmt::Result
+where
+    T: core::fmt::Display + core::fmt::LowerExp + core::fmt::UpperExp,
+    W: core::fmt::Write,
+{
+    let w = zero_pad_width;
+    let plus = spec.get_sign() == Some(Sign::Plus);
+    match (spec.get_display_hint(), spec.get_precision().map(usize::from), plus) {
+        (DisplayHint::LowerExp, None, false) => write!(out, "{v:0w$e}"),
+        (DisplayHint::LowerExp, None, true) => write!(out, "{v:+0w$e}"),
+        (DisplayHint::LowerExp, Some(p), false) => write!(out, "{v:0w$.p$e}"),
+        (DisplayHint::LowerExp, Some(p), true) => write!(out, "{v:+0w$.p$e}"),
+        (DisplayHint::UpperExp, None, false) => write!(out, "{v:0w$E}"),
+        (DisplayHint::UpperExp, None, true) => write!(out, "{v:+0w$E}"),
+        (DisplayHint::UpperExp, Some(p), false) => write!(out, "{v:0w$.p$E}"),
+        (DisplayHint::UpperExp, Some(p), true) => write!(out, "{v:+0w$.p$E}"),
+        (_, None, false) => write!(out, "{v:0w$}"),
+        (_, None, true) => write!(out, "{v:+0w$}"),
+        (_, Some(p), false) => write!(out, "{v:0w$.p$}"),
+        (_, Some(p), true) => write!(out, "{v:+0w$.p$}"),
+    }
+}
+
+/// Write a float into `writer` with [`ScoreWrite::write_str`].
+///
+/// Handles the spec the same way as [`core::fmt`] does for floats,
+/// including width, fill, alignment (right by default), sign and zero pad.
+/// Output is measured before it is written, so no intermediate buffer is required.
+pub(crate) fn write_float<T, W>(writer: &mut W, v: &T, spec: &FormatSpec) -> Result
+where
+    T: core::fmt::Display + core::fmt::LowerExp + core::fmt::UpperExp,
+    W: ScoreWrite + ?Sized,
+{
+    let width = match spec.get_width() {
+        Some(width) if !spec.get_zero_pad() => usize::from(width),
+        // Zero padding is placed after the sign, leave it to `core::fmt`.
+        width => {
+            let zero_pad_width = width.map_or(0, usize::from);
+            return render_float(&mut ScoreWriteSink(writer), v, spec, zero_pad_width).map_err(|_| Error);
+        },
+    };
+
+    // Rendered floats are always ASCII, so length in bytes equals length in characters.
+    let mut len = LenSink(0);
+    render_float(&mut len, v, spec, 0).map_err(|_| Error)?;
+    let padding = width.saturating_sub(len.0);
+    let (pre, post) = match spec.get_align() {
+        None | Some(Alignment::Right) => (padding, 0),
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+    };
+
+    let piece_spec = FormatSpec::new();
+    let mut fill = [0; 4];
+    let fill = spec.get_fill().encode_utf8(&mut fill);
+    for _ in 0..pre {
+        writer.write_str(fill, &piece_spec)?;
+    }
+    render_float(&mut ScoreWriteSink(&mut *writer), v, spec, 0).map_err(|_| Error)?;
+    for _ in 0..post {
+        writer.write_str(fill, &piece_spec)?;
+    }
+    Ok(())
+}
+
 /// A trait for writing into message frames.
 ///
 /// This trait accepts multiple data types.
 /// Implementation is responsible for output formatting based on provided spec.
+///
+/// Integer methods are provided and delegate to [`write_int_radix`](ScoreWrite::write_int_radix),
+/// so text based writers only need to implement them if a different output is required.
 pub trait ScoreWrite {
     /// Write a `bool` into this writer.
     fn write_bool(&mut self, v: &bool, spec: &FormatSpec) -> Result;
@@ -41,23 +208,199 @@ pub trait ScoreWrite {
     /// Write a `f64` into this writer.
     fn write_f64(&mut self, v: &f64, spec: &FormatSpec) -> Result;
     /// Write a `i8` into this writer.
-    fn write_i8(&mut self, v: &i8, spec: &FormatSpec) -> Result;
+    fn write_i8(&mut self, v: &i8, spec: &FormatSpec) -> Result {
+        write_signed_int!(self, *v, u8, spec)
+    }
     /// Write a `i16` into this writer.
-    fn write_i16(&mut self, v: &i16, spec: &FormatSpec) -> Result;
+    fn write_i16(&mut self, v: &i16, spec: &FormatSpec) -> Result {
+        write_signed_int!(self, *v, u16, spec)
+    }
     /// Write a `i32` into this writer.
-    fn write_i32(&mut self, v: &i32, spec: &FormatSpec) -> Result;
+    fn write_i32(&mut self, v: &i32, spec: &FormatSpec) -> Result {
+        write_signed_int!(self, *v, u32, spec)
+    }
     /// Write a `i64` into this writer.
-    fn write_i64(&mut self, v: &i64, spec: &FormatSpec) -> Result;
+    fn write_i64(&mut self, v: &i64, spec: &FormatSpec) -> Result {
+        write_signed_int!(self, *v, u64, spec)
+    }
     /// Write a `u8` into this writer.
-    fn write_u8(&mut self, v: &u8, spec: &FormatSpec) -> Result;
+    fn write_u8(&mut self, v: &u8, spec: &FormatSpec) -> Result {
+        self.write_int_radix(u128::from(*v), false, spec)
+    }
     /// Write a `u16` into this writer.
-    fn write_u16(&mut self, v: &u16, spec: &FormatSpec) -> Result;
+    fn write_u16(&mut self, v: &u16, spec: &FormatSpec) -> Result {
+        self.write_int_radix(u128::from(*v), false, spec)
+    }
     /// Write a `u32` into this writer.
-    fn write_u32(&mut self, v: &u32, spec: &FormatSpec) -> Result;
+    fn write_u32(&mut self, v: &u32, spec: &FormatSpec) -> Result {
+        self.write_int_radix(u128::from(*v), false, spec)
+    }
     /// Write a `u64` into this writer.
-    fn write_u64(&mut self, v: &u64, spec: &FormatSpec) -> Result;
+    fn write_u64(&mut self, v: &u64, spec: &FormatSpec) -> Result {
+        self.write_int_radix(u128::from(*v), false, spec)
+    }
     /// Write a `&str` into this writer.
     fn write_str(&mut self, v: &str, spec: &FormatSpec) -> Result;
+    /// Write an integer given as magnitude and sign into this writer, using [`write_str`](ScoreWrite::write_str).
+    ///
+    /// Handles the spec the same way as [`core::fmt`] does for integers:
+    /// - radix is selected by the octal, hex and binary display hints, decimal otherwise,
+    /// - `+` sign is written for non-negative values if requested,
+    /// - radix prefix (`0o`, `0x`, `0b`) is written in alternate mode,
+    /// - output is padded to width with zeros after the sign and prefix in zero pad mode,
+    ///   or with the fill character according to alignment otherwise (right by default).
+    fn write_int_radix(&mut self, value: u128, negative: bool, spec: &FormatSpec) -> Result {
+        const LOWER: &[u8; 16] = b"0123456789abcdef";
+        const UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+        // Binary representation of `u128::MAX` is the longest.
+        let mut buf = [0u8; 128];
+        let (pos, prefix) = match spec.get_display_hint() {
+            DisplayHint::Octal => (write_digits::<8>(&mut buf, value, LOWER), "0o"),
+            DisplayHint::LowerHex => (write_digits::<16>(&mut buf, value, LOWER), "0x"),
+            DisplayHint::UpperHex => (write_digits::<16>(&mut buf, value, UPPER), "0x"),
+            DisplayHint::Binary => (write_digits::<2>(&mut buf, value, LOWER), "0b"),
+            _ => (write_digits::<10>(&mut buf, value, LOWER), ""),
+        };
+        // SAFETY: digits are always ASCII.
+        let digits = unsafe { core::str::from_utf8_unchecked(&buf[pos..]) };
+
+        // Fast path for the common case of digits only, without sign, prefix or padding.
+        if !negative && spec.get_width().is_none() && spec.get_sign().is_none() && !spec.get_alternate() {
+            return self.write_str(digits, &FormatSpec::new());
+        }
+
+        let sign = match (negative, spec.get_sign()) {
+            (true, _) => "-",
+            (false, Some(Sign::Plus)) => "+",
+            (false, _) => "",
+        };
+        let prefix = if spec.get_alternate() { prefix } else { "" };
+        let len = sign.len() + prefix.len() + digits.len();
+        let padding = spec
+            .get_width()
+            .map_or(0, |width| usize::from(width).saturating_sub(len));
+
+        let piece_spec = FormatSpec::new();
+        let mut write_piece = |piece: &str| {
+            if piece.is_empty() {
+                Ok(())
+            } else {
+                self.write_str(piece, &piece_spec)
+            }
+        };
+        if spec.get_zero_pad() {
+            write_piece(sign)?;
+            write_piece(prefix)?;
+            for _ in 0..padding {
+                write_piece("0")?;
+            }
+            return write_piece(digits);
+        }
+
+        let (pre, post) = match spec.get_align() {
+            None | Some(Alignment::Right) => (padding, 0),
+            Some(Alignment::Left) => (0, padding),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        };
+        let mut fill = [0; 4];
+        let fill = spec.get_fill().encode_utf8(&mut fill);
+        for _ in 0..pre {
+            write_piece(fill)?;
+        }
+        write_piece(sign)?;
+        write_piece(prefix)?;
+        write_piece(digits)?;
+        for _ in 0..post {
+            write_piece(fill)?;
+        }
+        Ok(())
+    }
+    /// Write a `&str` truncated and padded according to the spec into this writer, using [`write_str`](ScoreWrite::write_str).
+    ///
+    /// Handles the spec the same way as [`core::fmt`] does for strings:
+    /// - output is truncated to precision, counted in characters, not bytes,
+    /// - output is padded to width with the fill character according to alignment (left by default).
+    ///
+    /// If neither precision nor width is set, the string and spec are passed to [`write_str`](ScoreWrite::write_str) as is.
+    fn write_str_padded(&mut self, v: &str, spec: &FormatSpec) -> Result {
+        if spec.get_precision().is_none() && spec.get_width().is_none() {
+            return self.write_str(v, spec);
+        }
+
+        let v = match spec.get_precision() {
+            Some(precision) => v
+                .char_indices()
+                .nth(usize::from(precision))
+                .map_or(v, |(end, _)| &v[..end]),
+            None => v,
+        };
+        let padding = spec
+            .get_width()
+            .map_or(0, |width| usize::from(width).saturating_sub(v.chars().count()));
+        let (pre, post) = match spec.get_align() {
+            None | Some(Alignment::Left) => (0, padding),
+            Some(Alignment::Right) => (padding, 0),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        };
+
+        let piece_spec = FormatSpec::new();
+        let mut fill = [0; 4];
+        let fill = spec.get_fill().encode_utf8(&mut fill);
+        for _ in 0..pre {
+            self.write_str(fill, &piece_spec)?;
+        }
+        if !v.is_empty() {
+            self.write_str(v, &piece_spec)?;
+        }
+        for _ in 0..post {
+            self.write_str(fill, &piece_spec)?;
+        }
+        Ok(())
+    }
+    /// Write raw bytes into this writer.
+    ///
+    /// Intended for message frames that are not exclusively text based.
+    /// Default implementation hex-encodes the bytes using [`write_str`](ScoreWrite::write_str),
+    /// with uppercase digits if [`DisplayHint::UpperHex`] is requested.
+    fn write_bytes(&mut self, v: &[u8], spec: &FormatSpec) -> Result {
+        let digits = if spec.get_display_hint() == DisplayHint::UpperHex {
+            b"0123456789ABCDEF"
+        } else {
+            b"0123456789abcdef"
+        };
+        let piece_spec = FormatSpec::new();
+        for byte in v {
+            let hex = [digits[(byte >> 4) as usize], digits[(byte & 0xf) as usize]];
+            // Hex digits are always valid UTF-8.
+            let hex = core::str::from_utf8(&hex).map_err(|_| Error)?;
+            self.write_str(hex, &piece_spec)?;
+        }
+        Ok(())
+    }
+    /// Write a pointer address into this writer.
+    ///
+    /// Default implementation writes the address as `0x`-prefixed lowercase hex using [`write_str`](ScoreWrite::write_str),
+    /// same as [`core::fmt::Pointer`].
+    fn write_ptr(&mut self, v: *const (), _spec: &FormatSpec) -> Result {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+        let mut buf = [0u8; 2 + 2 * core::mem::size_of::<usize>()];
+        let mut pos = buf.len();
+        let mut addr = v.addr();
+        loop {
+            pos -= 1;
+            buf[pos] = DIGITS[addr & 0xf];
+            addr >>= 4;
+            if addr == 0 {
+                break;
+            }
+        }
+        buf[pos - 2] = b'0';
+        buf[pos - 1] = b'x';
+        // Hex digits and prefix are always valid UTF-8.
+        let hex = core::str::from_utf8(&buf[pos - 2..]).map_err(|_| Error)?;
+        self.write_str(hex, &FormatSpec::new())
+    }
 }
 
 /// Data placeholder in message.
@@ -97,10 +440,16 @@ impl<'a> Placeholder<'a> {
 }
 
 /// Message fragment.
-/// A string literal or data placeholder.
+/// A string literal, raw string or data placeholder.
 pub enum Fragment<'a> {
     /// Fragment is a string literal, with no additional formatting.
     Literal(&'a str),
+    /// Fragment is an already formatted string, e.g., provided at runtime, written verbatim.
+    ///
+    /// Written the same as [`Literal`](Self::Literal), but not a part of the format string,
+    /// so its content is never interpreted, e.g., `{}` in user data is written as is.
+    /// Created by the `raw: expr` form of the logging macros.
+    Raw(&'a str),
     /// Fragment is a placeholder for provided data.
     Placeholder(Placeholder<'a>),
 }
@@ -116,11 +465,36 @@ impl ScoreDebug for Arguments<'_> {
     }
 }
 
+/// Renders the message through [`FmtWriteAdapter`], e.g., for use with [`std::println!`].
+impl core::fmt::Display for Arguments<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write(&mut FmtWriteAdapter::new(f), *self).map_err(|_| core::fmt::Error)
+    }
+}
+
+/// Same as [`Display`](core::fmt::Display), like [`core::fmt::Arguments`].
+impl core::fmt::Debug for Arguments<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(self, f)
+    }
+}
+
 /// `ScoreDebug` provides the output in a programmer-facing, debugging context.
 /// Replacement for [`core::fmt::Debug`].
 pub trait ScoreDebug {
     /// Write debug representation of `self` to the provided writer.
     fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result;
+
+    /// Write debug representation of a slice of `Self` to the provided writer.
+    ///
+    /// Used by [`ScoreDebug`] implementation for `[T]`.
+    /// Can be overridden to provide specialized slice representation, e.g., hex dump of bytes.
+    fn fmt_slice(slice: &[Self], f: Writer, spec: &FormatSpec) -> Result
+    where
+        Self: Sized,
+    {
+        DebugList::new(f, spec).entries(slice.iter()).finish()
+    }
 }
 
 /// Write [`Arguments`] into provided `output` writer.
@@ -129,7 +503,7 @@ pub trait ScoreDebug {
 pub fn write(output: Writer, args: Arguments<'_>) -> Result {
     for fragment in args.0 {
         match fragment {
-            Fragment::Literal(s) => output.write_str(s, &FormatSpec::new()),
+            Fragment::Literal(s) | Fragment::Raw(s) => output.write_str(s, &FormatSpec::new()),
             Fragment::Placeholder(ph) => ph.fmt(output, &ph.spec),
         }?;
     }
@@ -137,10 +511,336 @@ pub fn write(output: Writer, args: Arguments<'_>) -> Result {
     Ok(())
 }
 
+/// Write [`Arguments`] into the writer, applying the outer spec to the whole message.
+///
+/// If `outer` has a width set, the message is first rendered into a [`String`] using [`format`],
+/// then padded with the fill character according to the alignment (left by default) and written using [`ScoreWrite::write_str`].
+/// Otherwise this is the same as [`write`], and the writer receives the individual fragments.
+pub fn write_spec(output: Writer, args: Arguments<'_>, outer: &FormatSpec) -> Result {
+    let Some(width) = outer.get_width() else {
+        return write(output, args);
+    };
+
+    let message = format(args);
+    let padding = usize::from(width).saturating_sub(message.chars().count());
+    let (pre, post) = match outer.get_align() {
+        None | Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Right) => (padding, 0),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+    };
+
+    let mut fill = [0; 4];
+    let fill = outer.get_fill().encode_utf8(&mut fill);
+    let piece_spec = FormatSpec::new();
+    for _ in 0..pre {
+        output.write_str(fill, &piece_spec)?;
+    }
+    output.write_str(&message, &piece_spec)?;
+    for _ in 0..post {
+        output.write_str(fill, &piece_spec)?;
+    }
+    Ok(())
+}
+
+/// Write [`core::fmt::Arguments`] into provided `output` writer.
+///
+/// Allows feeding messages created with [`core::format_args!`] into a [`ScoreWrite`] writer.
+/// The message is rendered into a [`String`] first and written using a single [`ScoreWrite::write_str`] call;
+/// messages without arguments are written directly.
+pub fn write_core(output: Writer, args: core::fmt::Arguments<'_>) -> Result {
+    let empty_spec = FormatSpec::new();
+    match args.as_str() {
+        Some(message) => output.write_str(message, &empty_spec),
+        None => output.write_str(&std::fmt::format(args), &empty_spec),
+    }
+}
+
+/// Format [`Arguments`] into a [`String`].
+///
+/// Replacement for [`std::fmt::format`].
+pub fn format(args: Arguments<'_>) -> String {
+    let mut output = FmtWriteAdapter::new(String::new());
+    // Writing to `String` never fails.
+    let _ = write(&mut output, args);
+    output.into_inner()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::test_utils::StringWriter;
-    use crate::{write, Arguments, FormatSpec, Fragment, Placeholder, ScoreDebug};
+    use crate::{
+        format, write, write_core, write_spec, Alignment, Arguments, DisplayHint, FormatSpec, Fragment, Placeholder,
+        Result, ScoreDebug, ScoreWrite, Sign,
+    };
+
+    /// Writer recording values as raw bytes.
+    struct BinaryWriter {
+        buf: Vec<u8>,
+    }
+
+    impl ScoreWrite for BinaryWriter {
+        fn write_bool(&mut self, v: &bool, _spec: &FormatSpec) -> Result {
+            self.buf.push(*v as u8);
+            Ok(())
+        }
+
+        fn write_f32(&mut self, v: &f32, _spec: &FormatSpec) -> Result {
+            self.buf.extend_from_slice(&v.to_le_bytes());
+            Ok(())
+        }
+
+        fn write_f64(&mut self, v: &f64, _spec: &FormatSpec) -> Result {
+            self.buf.extend_from_slice(&v.to_le_bytes());
+            Ok(())
+        }
+
+        fn write_i8(&mut self, v: &i8, _spec: &FormatSpec) -> Result {
+            self.buf.extend_from_slice(&v.to_le_bytes());
+            Ok(())
+        }
+
+        fn write_i16(&mut self, v: &i16, _spec: &FormatSpec) -> Result {
+            self.buf.extend_from_slice(&v.to_le_bytes());
+            Ok(())
+        }
+
+        fn write_i32(&mut self, v: &i32, _spec: &FormatSpec) -> Result {
+            self.buf.extend_from_slice(&v.to_le_bytes());
+            Ok(())
+        }
+
+        fn write_i64(&mut self, v: &i64, _spec: &FormatSpec) -> Result {
+            self.buf.extend_from_slice(&v.to_le_bytes());
+            Ok(())
+        }
+
+        fn write_u8(&mut self, v: &u8, _spec: &FormatSpec) -> Result {
+            self.buf.push(*v);
+            Ok(())
+        }
+
+        fn write_u16(&mut self, v: &u16, _spec: &FormatSpec) -> Result {
+            self.buf.extend_from_slice(&v.to_le_bytes());
+            Ok(())
+        }
+
+        fn write_u32(&mut self, v: &u32, _spec: &FormatSpec) -> Result {
+            self.buf.extend_from_slice(&v.to_le_bytes());
+            Ok(())
+        }
+
+        fn write_u64(&mut self, v: &u64, _spec: &FormatSpec) -> Result {
+            self.buf.extend_from_slice(&v.to_le_bytes());
+            Ok(())
+        }
+
+        fn write_str(&mut self, v: &str, _spec: &FormatSpec) -> Result {
+            self.buf.extend_from_slice(v.as_bytes());
+            Ok(())
+        }
+
+        fn write_bytes(&mut self, v: &[u8], _spec: &FormatSpec) -> Result {
+            self.buf.extend_from_slice(v);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_bytes_verbatim() {
+        let mut w = BinaryWriter { buf: Vec::new() };
+        let bytes = [0x00, 0xff, 0x7b, 0x0a, 0x80];
+        assert!(w.write_str("ab", &FormatSpec::new()) == Ok(()));
+        assert!(w.write_bytes(&bytes, &FormatSpec::new()) == Ok(()));
+        assert!(w.write_u16(&0x1234, &FormatSpec::new()) == Ok(()));
+        assert_eq!(w.buf, [b'a', b'b', 0x00, 0xff, 0x7b, 0x0a, 0x80, 0x34, 0x12]);
+    }
+
+    #[test]
+    fn test_write_bytes_default_hex() {
+        let bytes = [0x00, 0xff, 0x7b, 0x0a, 0x80];
+
+        let mut w = StringWriter::new();
+        assert!(w.write_bytes(&bytes, &FormatSpec::new()) == Ok(()));
+        assert_eq!(w.get(), "00ff7b0a80");
+
+        let mut w = StringWriter::new();
+        let mut spec = FormatSpec::new();
+        spec.display_hint(DisplayHint::UpperHex);
+        assert!(w.write_bytes(&bytes, &spec) == Ok(()));
+        assert_eq!(w.get(), "00FF7B0A80");
+
+        let mut w = StringWriter::new();
+        assert!(w.write_bytes(&[], &FormatSpec::new()) == Ok(()));
+        assert_eq!(w.get(), "");
+    }
+
+    fn assert_pointer_shape(s: &str) {
+        let digits = s.strip_prefix("0x").expect("missing 0x prefix");
+        assert!(!digits.is_empty());
+        assert!(digits.chars().all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+    }
+
+    #[test]
+    fn test_write_ptr_default() {
+        let value = 123u32;
+        let ptr = (&value as *const u32).cast::<()>();
+        let mut w = StringWriter::new();
+        assert!(w.write_ptr(ptr, &FormatSpec::new()) == Ok(()));
+        assert_pointer_shape(w.get());
+        assert_eq!(w.get(), format!("{ptr:p}"));
+
+        let mut w = StringWriter::new();
+        assert!(w.write_ptr(core::ptr::null(), &FormatSpec::new()) == Ok(()));
+        assert_eq!(w.get(), "0x0");
+    }
+
+    fn int_spec(display_hint: DisplayHint, align: Option<Alignment>, width: Option<u16>) -> FormatSpec {
+        let mut spec = FormatSpec::new();
+        spec.display_hint(display_hint).align(align).width(width);
+        spec
+    }
+
+    fn write_int<F: FnOnce(&mut StringWriter, &FormatSpec) -> Result>(spec: &FormatSpec, f: F) -> String {
+        let mut w = StringWriter::new();
+        assert!(f(&mut w, spec) == Ok(()));
+        w.get().to_string()
+    }
+
+    fn str_spec(align: Option<Alignment>, fill: char, width: Option<u16>, precision: Option<u16>) -> FormatSpec {
+        let mut spec = FormatSpec::new();
+        spec.align(align).fill(fill).width(width).precision(precision);
+        spec
+    }
+
+    #[test]
+    fn test_write_str_padded() {
+        let write_str = |v: &str, spec: &FormatSpec| write_int(spec, |w, s| w.write_str_padded(v, s));
+
+        for v in ["", "abcdef", "héllo", "日本語テキスト", "a😀b"] {
+            let spec = str_spec(None, ' ', None, Some(3));
+            assert_eq!(write_str(v, &spec), std::format!("{v:.3}"));
+            let spec = str_spec(None, ' ', None, Some(0));
+            assert_eq!(write_str(v, &spec), std::format!("{v:.0}"));
+            let spec = str_spec(None, ' ', None, Some(100));
+            assert_eq!(write_str(v, &spec), std::format!("{v:.100}"));
+            let spec = str_spec(None, ' ', Some(6), None);
+            assert_eq!(write_str(v, &spec), std::format!("{v:6}"));
+            let spec = str_spec(Some(Alignment::Right), ' ', Some(6), Some(3));
+            assert_eq!(write_str(v, &spec), std::format!("{v:>6.3}"));
+            let spec = str_spec(Some(Alignment::Center), '*', Some(7), Some(2));
+            assert_eq!(write_str(v, &spec), std::format!("{v:*^7.2}"));
+            let spec = str_spec(Some(Alignment::Left), 'ß', Some(5), Some(4));
+            assert_eq!(write_str(v, &spec), std::format!("{v:ß<5.4}"));
+        }
+
+        // Without width and precision, the string is written as is.
+        assert_eq!(write_str("abc", &FormatSpec::new()), "abc");
+    }
+
+    #[test]
+    fn test_write_int_radix() {
+        let spec = FormatSpec::new();
+        assert_eq!(write_int(&spec, |w, s| w.write_int_radix(0, false, s)), "0");
+        assert_eq!(
+            write_int(&spec, |w, s| w.write_int_radix(u128::MAX, false, s)),
+            u128::MAX.to_string()
+        );
+        assert_eq!(write_int(&spec, |w, s| w.write_int_radix(123, true, s)), "-123");
+
+        let spec = int_spec(DisplayHint::Binary, None, None);
+        assert_eq!(
+            write_int(&spec, |w, s| w.write_int_radix(u128::MAX, false, s)),
+            format!("{:b}", u128::MAX)
+        );
+    }
+
+    #[test]
+    fn test_write_int_radix_specs() {
+        let v = 255u32;
+        let neg = -42i32;
+
+        let spec = int_spec(DisplayHint::LowerHex, None, None);
+        assert_eq!(write_int(&spec, |w, s| w.write_u32(&v, s)), format!("{v:x}"));
+        assert_eq!(write_int(&spec, |w, s| w.write_i32(&neg, s)), format!("{neg:x}"));
+        let spec = int_spec(DisplayHint::UpperHex, None, None);
+        assert_eq!(write_int(&spec, |w, s| w.write_i8(&-1, s)), format!("{:X}", -1i8));
+        let spec = int_spec(DisplayHint::Octal, None, None);
+        assert_eq!(
+            write_int(&spec, |w, s| w.write_u64(&u64::MAX, s)),
+            format!("{:o}", u64::MAX)
+        );
+
+        let mut spec = int_spec(DisplayHint::LowerHex, None, Some(10));
+        spec.alternate(true).zero_pad(true);
+        assert_eq!(write_int(&spec, |w, s| w.write_u32(&v, s)), format!("{v:#010x}"));
+
+        let mut spec = int_spec(DisplayHint::NoHint, None, Some(6));
+        spec.zero_pad(true);
+        assert_eq!(write_int(&spec, |w, s| w.write_i32(&neg, s)), format!("{neg:06}"));
+
+        let mut spec = int_spec(DisplayHint::NoHint, None, None);
+        spec.sign(Some(Sign::Plus));
+        assert_eq!(write_int(&spec, |w, s| w.write_u16(&7, s)), format!("{:+}", 7u16));
+        assert_eq!(write_int(&spec, |w, s| w.write_i16(&-7, s)), format!("{:+}", -7i16));
+
+        let spec = int_spec(DisplayHint::NoHint, None, Some(6));
+        assert_eq!(write_int(&spec, |w, s| w.write_i64(&-42, s)), format!("{:6}", -42i64));
+        let spec = int_spec(DisplayHint::NoHint, Some(Alignment::Left), Some(6));
+        assert_eq!(write_int(&spec, |w, s| w.write_u8(&42, s)), format!("{:<6}", 42u8));
+        let mut spec = int_spec(DisplayHint::Binary, Some(Alignment::Center), Some(9));
+        spec.fill('*').alternate(true);
+        assert_eq!(write_int(&spec, |w, s| w.write_u8(&5, s)), format!("{:*^#9b}", 5u8));
+
+        // Width smaller than the output.
+        let spec = int_spec(DisplayHint::NoHint, None, Some(2));
+        assert_eq!(write_int(&spec, |w, s| w.write_u32(&12345, s)), "12345");
+    }
+
+    #[test]
+    fn test_write_spec() {
+        let value = 42;
+        let fragments = [
+            Fragment::Literal("a"),
+            Fragment::Placeholder(Placeholder::new(&value, FormatSpec::new())),
+            Fragment::Literal("ß"),
+        ];
+        let args = Arguments(&fragments);
+
+        let cases = [
+            (None, None, '.', "a42ß"),
+            (Some(8), None, '.', "a42ß...."),
+            (Some(8), Some(Alignment::Right), ' ', "    a42ß"),
+            (Some(9), Some(Alignment::Center), '*', "**a42ß***"),
+            (Some(2), Some(Alignment::Right), ' ', "a42ß"),
+        ];
+        for (width, align, fill, expected) in cases {
+            let mut w = StringWriter::new();
+            let mut outer = FormatSpec::new();
+            outer.width(width).align(align).fill(fill);
+            assert!(write_spec(&mut w, args, &outer) == Ok(()));
+            assert_eq!(w.get(), expected);
+        }
+
+        let mut w = StringWriter::new();
+        let mut outer = FormatSpec::new();
+        outer.width(Some(12)).align(Some(Alignment::Right));
+        assert!(write_spec(&mut w, args, &outer) == Ok(()));
+        assert_eq!(w.get(), std::format!("{:>12}", "a42ß"));
+    }
+
+    #[test]
+    fn test_arguments_core_fmt() {
+        let fragments = [
+            Fragment::Literal("value: "),
+            Fragment::Placeholder(Placeholder::new(&-123i32, FormatSpec::new())),
+            Fragment::Literal(", name: "),
+            Fragment::Placeholder(Placeholder::new(&"abc", FormatSpec::new())),
+        ];
+        let args = Arguments(&fragments);
+        assert_eq!(std::format!("{}", args), "value: -123, name: abc");
+        assert_eq!(std::format!("{:?}", args), "value: -123, name: abc");
+        assert_eq!(std::format!("[{}]", Arguments(&[])), "[]");
+    }
 
     #[test]
     fn test_arguments_debug() {
@@ -184,6 +884,22 @@ mod tests {
         assert!(w.get() == "test_string");
     }
 
+    #[test]
+    fn test_write_raw() {
+        let user_data = String::from("{} {name} {:?}");
+        let fragments = [
+            Fragment::Literal("received: "),
+            Fragment::Raw(&user_data),
+            Fragment::Placeholder(Placeholder::new(&1u8, FormatSpec::new())),
+        ];
+        let args = Arguments(&fragments);
+
+        let mut w = StringWriter::new();
+        assert!(write(&mut w, args) == Ok(()));
+        assert_eq!(w.get(), "received: {} {name} {:?}1");
+        assert_eq!(format(args), "received: {} {name} {:?}1");
+    }
+
     #[test]
     fn test_write_placeholders_only() {
         let mut w = StringWriter::new();
@@ -220,4 +936,34 @@ mod tests {
         assert!(write(&mut w, args) == Ok(()));
         assert!(w.get() == "test_123_string");
     }
+
+    #[test]
+    fn test_write_core() {
+        let mut w = StringWriter::new();
+        assert!(write_core(&mut w, format_args!("{}-{}", 1, 2)) == Ok(()));
+        assert_eq!(w.get(), "1-2");
+
+        let mut w = StringWriter::new();
+        assert!(write_core(&mut w, format_args!("literal only")) == Ok(()));
+        assert_eq!(w.get(), "literal only");
+    }
+
+    #[test]
+    fn test_format() {
+        let fragments = [
+            Fragment::Literal("test_"),
+            Fragment::Placeholder(Placeholder::new(&123u32, FormatSpec::new())),
+            Fragment::Literal("_"),
+            Fragment::Placeholder(Placeholder::new(&"string", FormatSpec::new())),
+            Fragment::Literal("_"),
+            Fragment::Placeholder(Placeholder::new(&-1.5f32, FormatSpec::new())),
+        ];
+        let output = format(Arguments(&fragments));
+        assert_eq!(output, std::format!("test_{}_{}_{}", 123u32, "string", -1.5f32));
+    }
+
+    #[test]
+    fn test_format_empty() {
+        assert_eq!(format(Arguments(&[])), "");
+    }
 }
@@ -15,7 +15,7 @@
 
 use crate::builders::{DebugList, DebugStruct, DebugTuple};
 use crate::fmt::{Error, Result, ScoreDebug, Writer};
-use crate::fmt_spec::{DisplayHint, FormatSpec};
+use crate::fmt_spec::{DebugAsHex, DisplayHint, FormatSpec};
 use crate::DebugMap;
 
 macro_rules! impl_debug_for_t {
@@ -28,17 +28,85 @@ macro_rules! impl_debug_for_t {
     };
 }
 
+/// Translate `{:x?}` and `{:X?}` into the matching hex display hint for integer values.
+///
+/// Returns `None` if `spec` doesn't request debug output as hex.
+fn debug_as_hex_spec(spec: &FormatSpec) -> Option<FormatSpec> {
+    if spec.get_display_hint() != DisplayHint::Debug {
+        return None;
+    }
+    let display_hint = match spec.get_debug_as_hex()? {
+        DebugAsHex::Lower => DisplayHint::LowerHex,
+        DebugAsHex::Upper => DisplayHint::UpperHex,
+    };
+    let mut hex_spec = spec.clone();
+    hex_spec.display_hint(display_hint).debug_as_hex(None);
+    Some(hex_spec)
+}
+
+macro_rules! impl_debug_for_int {
+    ($t:ty, $fn:ident) => {
+        impl ScoreDebug for $t {
+            fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+                match debug_as_hex_spec(spec) {
+                    Some(hex_spec) => f.$fn(self, &hex_spec),
+                    None => f.$fn(self, spec),
+                }
+            }
+        }
+    };
+}
+
 impl_debug_for_t!(bool, write_bool);
 impl_debug_for_t!(f32, write_f32);
 impl_debug_for_t!(f64, write_f64);
-impl_debug_for_t!(i8, write_i8);
-impl_debug_for_t!(i16, write_i16);
-impl_debug_for_t!(i32, write_i32);
-impl_debug_for_t!(i64, write_i64);
-impl_debug_for_t!(u8, write_u8);
-impl_debug_for_t!(u16, write_u16);
-impl_debug_for_t!(u32, write_u32);
-impl_debug_for_t!(u64, write_u64);
+impl_debug_for_int!(i8, write_i8);
+impl_debug_for_int!(i16, write_i16);
+impl_debug_for_int!(i32, write_i32);
+impl_debug_for_int!(i64, write_i64);
+
+impl ScoreDebug for u8 {
+    fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+        match debug_as_hex_spec(spec) {
+            Some(hex_spec) => f.write_u8(self, &hex_spec),
+            None => f.write_u8(self, spec),
+        }
+    }
+
+    /// Byte slices are represented as a hex dump if [`DisplayHint::LowerHex`] or [`DisplayHint::UpperHex`] is requested.
+    /// Each byte is prefixed with `0x` if alternate flag is set.
+    fn fmt_slice(slice: &[Self], f: Writer, spec: &FormatSpec) -> Result {
+        let digits = match spec.get_display_hint() {
+            DisplayHint::LowerHex => b"0123456789abcdef",
+            DisplayHint::UpperHex => b"0123456789ABCDEF",
+            _ => return DebugList::new(f, spec).entries(slice.iter()).finish(),
+        };
+
+        // The hex dump stays on a single line, the alternate flag only selects the `0x` prefix.
+        let mut list_spec = spec.clone();
+        list_spec.alternate(false);
+        let mut debug_list = DebugList::new(f, &list_spec);
+        for byte in slice {
+            debug_list.entry_with(|f| {
+                let buf = [
+                    b'0',
+                    b'x',
+                    digits[usize::from(byte >> 4)],
+                    digits[usize::from(byte & 0xf)],
+                ];
+                let start = if spec.get_alternate() { 0 } else { 2 };
+                // SAFETY: `buf` consists of ASCII characters only.
+                let hex = unsafe { core::str::from_utf8_unchecked(&buf[start..]) };
+                f.write_str(hex, &FormatSpec::new())
+            });
+        }
+        debug_list.finish()
+    }
+}
+
+impl_debug_for_int!(u16, write_u16);
+impl_debug_for_int!(u32, write_u32);
+impl_debug_for_int!(u64, write_u64);
 
 impl ScoreDebug for () {
     fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
@@ -55,7 +123,29 @@ impl ScoreDebug for str {
                 f.write_str(self, spec)?;
                 f.write_str("\"", &queue_spec)
             },
-            _ => f.write_str(self, spec),
+            _ => f.write_str_padded(self, spec),
+        }
+    }
+}
+
+impl ScoreDebug for char {
+    fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+        let mut buf = [0; 4];
+        match spec.get_display_hint() {
+            DisplayHint::Debug => {
+                let quote_spec = FormatSpec::new();
+                f.write_str("'", &quote_spec)?;
+                // Double quote is not escaped, same as in `core::fmt::Debug` implementation.
+                if *self == '"' {
+                    f.write_str("\"", &quote_spec)?;
+                } else {
+                    for c in self.escape_debug() {
+                        f.write_str(c.encode_utf8(&mut buf), &quote_spec)?;
+                    }
+                }
+                f.write_str("'", &quote_spec)
+            },
+            _ => f.write_str(self.encode_utf8(&mut buf), spec),
         }
     }
 }
@@ -91,7 +181,10 @@ macro_rules! impl_debug_for_t_casted {
         impl ScoreDebug for $ti {
             fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
                 let casted = <$to>::try_from(*self).map_err(|_| Error)?;
-                f.$fn(&casted, spec)
+                match debug_as_hex_spec(spec) {
+                    Some(hex_spec) => f.$fn(&casted, &hex_spec),
+                    None => f.$fn(&casted, spec),
+                }
             }
         }
     };
@@ -106,22 +199,98 @@ impl_debug_for_t_casted!(usize, u32, write_u32);
 #[cfg(target_pointer_width = "64")]
 impl_debug_for_t_casted!(usize, u64, write_u64);
 
+macro_rules! impl_debug_for_non_zero {
+    ($($t:ty),*) => {
+        $(
+            impl ScoreDebug for $t {
+                fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+                    ScoreDebug::fmt(&self.get(), f, spec)
+                }
+            }
+        )*
+    };
+}
+
+impl_debug_for_non_zero!(
+    core::num::NonZeroI8,
+    core::num::NonZeroI16,
+    core::num::NonZeroI32,
+    core::num::NonZeroI64,
+    core::num::NonZeroIsize,
+    core::num::NonZeroU8,
+    core::num::NonZeroU16,
+    core::num::NonZeroU32,
+    core::num::NonZeroU64,
+    core::num::NonZeroUsize
+);
+
+/// References write the address of the referenced value if [`DisplayHint::Pointer`] is requested.
+/// For wide pointers, only the data address is written.
 impl<T: ScoreDebug + ?Sized> ScoreDebug for &T {
     fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
-        ScoreDebug::fmt(&**self, f, spec)
+        match spec.get_display_hint() {
+            DisplayHint::Pointer => f.write_ptr((*self as *const T).cast(), spec),
+            _ => ScoreDebug::fmt(&**self, f, spec),
+        }
     }
 }
 
+/// References write the address of the referenced value if [`DisplayHint::Pointer`] is requested.
 impl<T: ScoreDebug + ?Sized> ScoreDebug for &mut T {
     fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
-        ScoreDebug::fmt(&**self, f, spec)
+        match spec.get_display_hint() {
+            DisplayHint::Pointer => f.write_ptr((&**self as *const T).cast(), spec),
+            _ => ScoreDebug::fmt(&**self, f, spec),
+        }
+    }
+}
+
+impl<T: ?Sized> ScoreDebug for *const T {
+    fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+        f.write_ptr(self.cast(), spec)
+    }
+}
+
+impl<T: ?Sized> ScoreDebug for *mut T {
+    fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+        f.write_ptr(self.cast_const().cast(), spec)
+    }
+}
+
+/// Function pointers write their address, same as [`core::fmt::Debug`].
+macro_rules! impl_debug_for_fn_ptr {
+    ($($arg:ident),*) => {
+        impl_debug_for_fn_ptr!(@impl fn($($arg),*) -> R; $($arg),*);
+        impl_debug_for_fn_ptr!(@impl unsafe fn($($arg),*) -> R; $($arg),*);
+        impl_debug_for_fn_ptr!(@impl extern "C" fn($($arg),*) -> R; $($arg),*);
+        impl_debug_for_fn_ptr!(@impl unsafe extern "C" fn($($arg),*) -> R; $($arg),*);
+    };
+    (@impl $t:ty; $($arg:ident),*) => {
+        impl<R, $($arg),*> ScoreDebug for $t {
+            fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+                f.write_ptr(*self as *const (), spec)
+            }
+        }
+    };
+}
+
+impl_debug_for_fn_ptr!();
+impl_debug_for_fn_ptr!(A);
+impl_debug_for_fn_ptr!(A, B);
+impl_debug_for_fn_ptr!(A, B, C);
+impl_debug_for_fn_ptr!(A, B, C, D);
+impl_debug_for_fn_ptr!(A, B, C, D, E);
+impl_debug_for_fn_ptr!(A, B, C, D, E, F);
+
+impl ScoreDebug for core::convert::Infallible {
+    fn fmt(&self, _f: Writer, _spec: &FormatSpec) -> Result {
+        match *self {}
     }
 }
 
 impl<T: ScoreDebug> ScoreDebug for [T] {
     fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
-        let mut debug_list = DebugList::new(f, spec);
-        debug_list.entries(self.iter()).finish()
+        T::fmt_slice(self, f, spec)
     }
 }
 
@@ -159,12 +328,7 @@ impl<T: ScoreDebug> ScoreDebug for std::sync::Arc<T> {
 impl<T: ScoreDebug> ScoreDebug for Option<T> {
     fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
         match self {
-            Some(v) => {
-                let outer_spec = FormatSpec::new();
-                f.write_str("Some(", &outer_spec)?;
-                ScoreDebug::fmt(v, f, spec)?;
-                f.write_str(")", &outer_spec)
-            },
+            Some(v) => DebugTuple::new(f, spec, "Some").field(v).finish(),
             None => f.write_str("None", spec),
         }
     }
@@ -176,6 +340,170 @@ impl<T: ScoreDebug + ?Sized> ScoreDebug for Box<T> {
     }
 }
 
+impl<T: ScoreDebug + ToOwned + ?Sized> ScoreDebug for std::borrow::Cow<'_, T> {
+    fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+        ScoreDebug::fmt(&**self, f, spec)
+    }
+}
+
+impl<T: ?Sized> ScoreDebug for core::marker::PhantomData<T> {
+    fn fmt(&self, f: Writer, _spec: &FormatSpec) -> Result {
+        let empty_spec = FormatSpec::new();
+        f.write_str("PhantomData<", &empty_spec)?;
+        f.write_str(core::any::type_name::<T>(), &empty_spec)?;
+        f.write_str(">", &empty_spec)
+    }
+}
+
+impl<T: ScoreDebug> ScoreDebug for core::ops::Range<T> {
+    fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+        ScoreDebug::fmt(&self.start, f, spec)?;
+        f.write_str("..", &FormatSpec::new())?;
+        ScoreDebug::fmt(&self.end, f, spec)
+    }
+}
+
+/// `PartialOrd` is required to detect an exhausted range, which is marked with ` (exhausted)`,
+/// same as in [`core::fmt::Debug`].
+impl<T: ScoreDebug + PartialOrd> ScoreDebug for core::ops::RangeInclusive<T> {
+    fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+        let empty_spec = FormatSpec::new();
+        ScoreDebug::fmt(self.start(), f, spec)?;
+        f.write_str("..=", &empty_spec)?;
+        ScoreDebug::fmt(self.end(), f, spec)?;
+        // Range with `start <= end` is empty only if exhausted.
+        if self.is_empty() && self.start() <= self.end() {
+            f.write_str(" (exhausted)", &empty_spec)?;
+        }
+        Ok(())
+    }
+}
+
+impl ScoreDebug for core::ops::RangeFull {
+    fn fmt(&self, f: Writer, _spec: &FormatSpec) -> Result {
+        f.write_str("..", &FormatSpec::new())
+    }
+}
+
+impl<T: ScoreDebug> ScoreDebug for core::ops::RangeFrom<T> {
+    fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+        ScoreDebug::fmt(&self.start, f, spec)?;
+        f.write_str("..", &FormatSpec::new())
+    }
+}
+
+impl<T: ScoreDebug> ScoreDebug for core::ops::RangeTo<T> {
+    fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+        f.write_str("..", &FormatSpec::new())?;
+        ScoreDebug::fmt(&self.end, f, spec)
+    }
+}
+
+impl<T: ScoreDebug> ScoreDebug for core::ops::RangeToInclusive<T> {
+    fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+        f.write_str("..=", &FormatSpec::new())?;
+        ScoreDebug::fmt(&self.end, f, spec)
+    }
+}
+
+/// Fixed size buffer for [`core::fmt::Display`] output of bounded length.
+struct DisplayBuf<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> DisplayBuf<N> {
+    /// Write [`core::fmt::Display`] output of provided value into the buffer.
+    fn new(v: &dyn core::fmt::Display) -> core::result::Result<Self, Error> {
+        use core::fmt::Write;
+        let mut display_buf = Self { buf: [0; N], len: 0 };
+        write!(display_buf, "{v}").map_err(|_| Error)?;
+        Ok(display_buf)
+    }
+
+    /// Write [`core::fmt::Debug`] output of provided value into the buffer.
+    fn debug(v: &dyn core::fmt::Debug) -> core::result::Result<Self, Error> {
+        use core::fmt::Write;
+        let mut display_buf = Self { buf: [0; N], len: 0 };
+        write!(display_buf, "{v:?}").map_err(|_| Error)?;
+        Ok(display_buf)
+    }
+
+    fn as_str(&self) -> &str {
+        // SAFETY: only complete `&str` are written into `self.buf[..self.len]`.
+        unsafe { core::str::from_utf8_unchecked(&self.buf[..self.len]) }
+    }
+}
+
+impl<const N: usize> core::fmt::Write for DisplayBuf<N> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        if end > N {
+            return Err(core::fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+macro_rules! impl_debug_for_net {
+    ($($t:ty),*) => {
+        $(
+            impl ScoreDebug for $t {
+                fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+                    // Longest representation is IPv6 socket address with scope ID, e.g.:
+                    // `[ffff:ffff:ffff:ffff:ffff:ffff:255.255.255.255%4294967295]:65535`.
+                    let display_buf = DisplayBuf::<64>::new(self)?;
+                    f.write_str(display_buf.as_str(), spec)
+                }
+            }
+        )*
+    };
+}
+
+// `std::net` re-exports these types, so the implementations cover both paths.
+// Output is formatted without allocation, so it doesn't depend on `std`.
+impl_debug_for_net!(
+    core::net::IpAddr,
+    core::net::Ipv4Addr,
+    core::net::Ipv6Addr,
+    core::net::SocketAddr,
+    core::net::SocketAddrV4,
+    core::net::SocketAddrV6
+);
+
+/// Human-readable output with a unit suffix, e.g., `1.234s` or `15ms`, same as [`core::fmt::Debug`].
+///
+/// This also covers [`std::time::Instant::elapsed`], which can be logged directly.
+impl ScoreDebug for core::time::Duration {
+    fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+        // Longest representation is `18446744073709551615.999999999s`.
+        let display_buf = DisplayBuf::<32>::debug(self)?;
+        f.write_str(display_buf.as_str(), spec)
+    }
+}
+
+impl ScoreDebug for std::io::ErrorKind {
+    fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+        // Longest representation is a variant name, e.g. `StaleNetworkFileHandle`.
+        let display_buf = DisplayBuf::<64>::debug(self)?;
+        f.write_str(display_buf.as_str(), spec)
+    }
+}
+
+/// Representation of [`std::io::Error`] is opaque, so it is rendered as `Error { kind: .., message: .. }`,
+/// where `message` is the [`Display`](core::fmt::Display) output of the error.
+impl ScoreDebug for std::io::Error {
+    fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+        let mut debug_struct = DebugStruct::new(f, spec, "Error");
+        debug_struct
+            .field("kind", &self.kind())
+            .field("message", &self.to_string())
+            .finish()
+    }
+}
+
 impl<K, V, S> ScoreDebug for std::collections::HashMap<K, V, S>
 where
     K: ScoreDebug,
@@ -187,6 +515,18 @@ where
     }
 }
 
+/// Entries are rendered in key order, use [`Sorted`](crate::Sorted) for the same ordering of [`HashMap`](std::collections::HashMap).
+impl<K, V> ScoreDebug for std::collections::BTreeMap<K, V>
+where
+    K: ScoreDebug,
+    V: ScoreDebug,
+{
+    fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+        let mut debug_map = DebugMap::new(f, spec);
+        debug_map.entries(self.iter()).finish()
+    }
+}
+
 impl<T> ScoreDebug for std::sync::PoisonError<T> {
     fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
         let mut debug_struct = DebugStruct::new(f, spec, "PoisonError");
@@ -235,7 +575,8 @@ impl<A: ScoreDebug, B: ScoreDebug, C: ScoreDebug, D: ScoreDebug, E: ScoreDebug>
 
 #[cfg(test)]
 mod tests {
-    use crate::test_utils::common_test_debug;
+    use crate::test_utils::{common_test_debug, StringWriter};
+    use crate::{Alignment, DebugAsHex, DisplayHint, FmtWriteAdapter, FormatSpec, ScoreDebug};
 
     #[test]
     fn test_bool_debug() {
@@ -292,6 +633,23 @@ mod tests {
         common_test_debug(1200000000000000000u64);
     }
 
+    #[test]
+    fn test_char_debug() {
+        for c in [
+            'a', 'ß', '😀', '\'', '"', '\\', '\n', '\r', '\t', '\0', '\u{7f}', '\u{301}', '\u{feff}',
+        ] {
+            common_test_debug(c);
+        }
+    }
+
+    #[test]
+    fn test_char_display() {
+        let mut w = StringWriter::new();
+        let _ = ScoreDebug::fmt(&'ß', &mut w, &FormatSpec::new());
+        let _ = ScoreDebug::fmt(&'\'', &mut w, &FormatSpec::new());
+        assert_eq!(w.get(), "ß'");
+    }
+
     #[test]
     fn test_unit_debug() {
         common_test_debug(());
@@ -302,6 +660,30 @@ mod tests {
         common_test_debug("test");
     }
 
+    #[test]
+    fn test_str_display_precision() {
+        let display = |v: &str, spec: &FormatSpec| {
+            let mut w = StringWriter::new();
+            let _ = ScoreDebug::fmt(v, &mut w, spec);
+            w.get().to_string()
+        };
+
+        let mut spec = FormatSpec::new();
+        spec.precision(Some(3));
+        assert_eq!(display("abcdef", &spec), std::format!("{:.3}", "abcdef"));
+        assert_eq!(
+            display("日本語テキスト", &spec),
+            std::format!("{:.3}", "日本語テキスト")
+        );
+        spec.width(Some(5)).align(Some(Alignment::Right));
+        assert_eq!(display("héllo", &spec), std::format!("{:>5.3}", "héllo"));
+        assert_eq!(display("ab", &spec), std::format!("{:>5.3}", "ab"));
+
+        // Debug output is not truncated, same as in `core::fmt::Debug` implementation.
+        spec.display_hint(DisplayHint::Debug);
+        assert_eq!(display("abcdef", &spec), std::format!("{:>5.3?}", "abcdef"));
+    }
+
     #[test]
     fn test_string_debug() {
         common_test_debug(String::from("test"));
@@ -331,16 +713,216 @@ mod tests {
         common_test_debug(1200000000000000000usize);
     }
 
+    #[test]
+    fn test_non_zero_debug() {
+        common_test_debug(core::num::NonZeroU8::new(123).unwrap());
+        common_test_debug(core::num::NonZeroU8::MAX);
+        common_test_debug(core::num::NonZeroI64::new(-1200000000000000000).unwrap());
+        common_test_debug(core::num::NonZeroI64::MIN);
+        common_test_debug(core::num::NonZeroU32::new(123456).unwrap());
+        common_test_debug(core::num::NonZeroIsize::new(-1).unwrap());
+        common_test_debug(core::num::NonZeroUsize::MAX);
+    }
+
     #[test]
     fn test_slice_debug() {
         common_test_debug([123, 456, 789].as_slice());
     }
 
+    #[test]
+    fn test_u8_slice_debug() {
+        common_test_debug([0xde_u8, 0xad, 0xbe, 0xef].as_slice());
+        common_test_debug(vec![0u8, 1, 255]);
+        common_test_debug(Vec::<u8>::new());
+    }
+
+    fn hex_dump(v: &dyn ScoreDebug, display_hint: DisplayHint, alternate: bool) -> String {
+        let mut w = StringWriter::new();
+        let mut spec = FormatSpec::new();
+        spec.display_hint(display_hint).alternate(alternate);
+        let _ = v.fmt(&mut w, &spec);
+        w.get().to_string()
+    }
+
+    #[test]
+    fn test_u8_slice_hex() {
+        let bytes = [0xde_u8, 0xad, 0xbe, 0xef, 0x00, 0x0a];
+        assert_eq!(
+            hex_dump(&bytes.as_slice(), DisplayHint::LowerHex, false),
+            "[de, ad, be, ef, 00, 0a]"
+        );
+        assert_eq!(
+            hex_dump(&bytes.as_slice(), DisplayHint::UpperHex, false),
+            "[DE, AD, BE, EF, 00, 0A]"
+        );
+        assert_eq!(
+            hex_dump(&bytes.to_vec(), DisplayHint::LowerHex, true),
+            "[0xde, 0xad, 0xbe, 0xef, 0x00, 0x0a]"
+        );
+        assert_eq!(
+            hex_dump(&bytes, DisplayHint::UpperHex, true),
+            "[0xDE, 0xAD, 0xBE, 0xEF, 0x00, 0x0A]"
+        );
+        assert_eq!(hex_dump(&Vec::<u8>::new(), DisplayHint::LowerHex, true), "[]");
+    }
+
+    fn debug_as_hex(v: &dyn ScoreDebug, debug_as_hex: DebugAsHex) -> String {
+        let mut w = FmtWriteAdapter::new(String::new());
+        let mut spec = FormatSpec::new();
+        spec.display_hint(DisplayHint::Debug).debug_as_hex(Some(debug_as_hex));
+        let _ = v.fmt(&mut w, &spec);
+        w.into_inner()
+    }
+
+    #[test]
+    fn test_debug_as_hex() {
+        let values = vec![0u32, 10, 255, 0xdead_beef];
+        assert_eq!(debug_as_hex(&values, DebugAsHex::Lower), format!("{values:x?}"));
+        assert_eq!(debug_as_hex(&values, DebugAsHex::Upper), format!("{values:X?}"));
+
+        let bytes = vec![0u8, 0x7f, 0xff];
+        assert_eq!(debug_as_hex(&bytes, DebugAsHex::Lower), format!("{bytes:x?}"));
+
+        let nested = (Some(-1i16), [usize::MAX, 16]);
+        assert_eq!(debug_as_hex(&nested, DebugAsHex::Upper), format!("{nested:X?}"));
+    }
+
     #[test]
     fn test_array_debug() {
         common_test_debug([123, 456, 789]);
     }
 
+    fn pointer(v: &dyn ScoreDebug) -> String {
+        let mut w = StringWriter::new();
+        let mut spec = FormatSpec::new();
+        spec.display_hint(DisplayHint::Pointer);
+        let _ = v.fmt(&mut w, &spec);
+        w.get().to_string()
+    }
+
+    #[test]
+    fn test_pointer() {
+        let value = 123i32;
+        let reference = &value;
+        let output = pointer(&reference);
+        assert!(output.starts_with("0x"));
+        assert!(output[2..].chars().all(|c| c.is_ascii_hexdigit()));
+        assert_eq!(output, format!("{reference:p}"));
+
+        let slice = [1u8, 2, 3].as_slice();
+        // Only the data address is written for wide pointers.
+        assert_eq!(pointer(&slice), format!("{:p}", slice.as_ptr()));
+
+        let mut value = 456u64;
+        let raw = &mut value as *mut u64;
+        assert_eq!(pointer(&raw), format!("{raw:p}"));
+        common_test_debug(raw.cast_const());
+        common_test_debug(raw);
+    }
+
+    #[test]
+    fn test_fn_pointer_debug() {
+        fn add_one(v: i32) -> i32 {
+            v + 1
+        }
+        fn never() -> ! {
+            panic!("never called")
+        }
+        unsafe fn unsafe_noop(_a: u8, _b: &str) {}
+        extern "C" fn c_noop() {}
+
+        fn debug(v: &dyn ScoreDebug) -> String {
+            let mut w = StringWriter::new();
+            let mut spec = FormatSpec::new();
+            spec.display_hint(DisplayHint::Debug);
+            let _ = v.fmt(&mut w, &spec);
+            w.get().to_string()
+        }
+
+        let fn_ptr: fn(i32) -> i32 = add_one;
+        let never_ptr: fn() -> ! = never;
+        let unsafe_ptr: unsafe fn(u8, &'static str) = unsafe_noop;
+        let c_ptr: extern "C" fn() = c_noop;
+        for (output, expected) in [
+            (debug(&fn_ptr), format!("{fn_ptr:?}")),
+            (debug(&never_ptr), format!("{never_ptr:?}")),
+            (debug(&unsafe_ptr), format!("{unsafe_ptr:?}")),
+            (debug(&c_ptr), format!("{c_ptr:?}")),
+        ] {
+            assert!(output.starts_with("0x"));
+            assert_eq!(output, expected);
+        }
+        // The pointer is still callable, nothing is consumed by formatting.
+        assert_eq!(fn_ptr(1), 2);
+    }
+
+    #[test]
+    fn test_duration_debug() {
+        use core::time::Duration;
+        for duration in [
+            Duration::ZERO,
+            Duration::from_nanos(1),
+            Duration::from_nanos(1_500),
+            Duration::from_micros(15_000),
+            Duration::from_millis(1_234),
+            Duration::from_secs(3_600),
+            Duration::MAX,
+        ] {
+            common_test_debug(duration);
+        }
+
+        let mut w = StringWriter::new();
+        let _ = ScoreDebug::fmt(&Duration::from_millis(1_234), &mut w, &FormatSpec::new());
+        assert_eq!(w.get(), "1.234s");
+    }
+
+    #[test]
+    fn test_io_error_kind_debug() {
+        use std::io::ErrorKind;
+        for kind in [ErrorKind::NotFound, ErrorKind::PermissionDenied, ErrorKind::Other] {
+            common_test_debug(kind);
+        }
+    }
+
+    #[test]
+    fn test_io_error_debug() {
+        use std::io::{Error, ErrorKind};
+
+        let mut w = StringWriter::new();
+        let error = Error::new(ErrorKind::NotFound, "config.json missing");
+        let _ = ScoreDebug::fmt(&error, &mut w, &FormatSpec::new());
+        assert_eq!(w.get(), "Error { kind: NotFound, message: config.json missing }");
+
+        let mut w = StringWriter::new();
+        let error = Error::from(ErrorKind::NotFound);
+        let _ = ScoreDebug::fmt(&error, &mut w, &FormatSpec::new());
+        assert_eq!(w.get(), format!("Error {{ kind: NotFound, message: {error} }}"));
+    }
+
+    #[test]
+    fn test_empty_array_debug() {
+        common_test_debug([0u8; 0]);
+        common_test_debug([0i32; 0]);
+        assert_eq!(hex_dump(&[0u8; 0], DisplayHint::LowerHex, true), "[]");
+    }
+
+    #[test]
+    fn test_nested_array_debug() {
+        common_test_debug([[1, 2], [3, 4]]);
+        common_test_debug([[0u8; 0]; 2]);
+    }
+
+    #[test]
+    fn test_mut_slice_debug() {
+        let mut values = [123, 456, 789];
+        common_test_debug(values.as_mut_slice());
+        let mut bytes = [0xde_u8, 0xad];
+        assert_eq!(
+            hex_dump(&bytes.as_mut_slice(), DisplayHint::UpperHex, false),
+            "[DE, AD]"
+        );
+    }
+
     #[test]
     fn test_try_from_slice_error_debug() {
         let a1 = vec![123, 456];
@@ -376,11 +958,109 @@ mod tests {
         common_test_debug(Box::new(432.1));
     }
 
+    #[test]
+    fn test_cow_debug() {
+        common_test_debug(std::borrow::Cow::Borrowed("borrowed"));
+        common_test_debug(std::borrow::Cow::<str>::Owned(String::from("owned")));
+        common_test_debug(std::borrow::Cow::<[i32]>::Owned(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_phantom_data_debug() {
+        common_test_debug(core::marker::PhantomData::<u32>);
+        common_test_debug(core::marker::PhantomData::<&str>);
+        common_test_debug(core::marker::PhantomData::<dyn ScoreDebug>);
+    }
+
+    #[test]
+    fn test_range_debug() {
+        common_test_debug(0..10);
+        common_test_debug(-10i64..-5);
+        common_test_debug(10u8..);
+        common_test_debug(..0.5f32);
+        common_test_debug(..);
+
+        let range = 10u32..255;
+        assert_eq!(debug_as_hex(&range, DebugAsHex::Lower), format!("{range:x?}"));
+    }
+
+    #[test]
+    fn test_range_inclusive_debug() {
+        use core::ops::RangeInclusive;
+
+        common_test_debug(1..=5);
+        common_test_debug(RangeInclusive::new(5, 1));
+        common_test_debug(..=-3i16);
+
+        let mut range = 1u8..=2;
+        for _ in range.by_ref() {}
+        assert!(range.is_empty());
+        common_test_debug(range);
+
+        let range = 'a'..='z';
+        assert_eq!(debug_as_hex(&range, DebugAsHex::Upper), format!("{range:X?}"));
+        let range = 10..=255;
+        assert_eq!(debug_as_hex(&range, DebugAsHex::Upper), format!("{range:X?}"));
+    }
+
+    #[test]
+    fn test_ip_addr_debug() {
+        use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+        common_test_debug(Ipv4Addr::new(192, 168, 0, 1));
+        common_test_debug(Ipv4Addr::BROADCAST);
+        common_test_debug(Ipv6Addr::LOCALHOST);
+        common_test_debug(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1));
+        common_test_debug(Ipv4Addr::new(255, 255, 255, 255).to_ipv6_mapped());
+        common_test_debug(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+        common_test_debug(IpAddr::V6(Ipv6Addr::from(u128::MAX)));
+    }
+
+    #[test]
+    #[allow(clippy::std_instead_of_core)]
+    fn test_std_net_debug() {
+        // Same types as in `core::net`.
+        let ip = std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST);
+        common_test_debug(ip);
+        common_test_debug(std::net::SocketAddr::new(ip, 80));
+        let core_ip: core::net::IpAddr = ip;
+        common_test_debug(core_ip);
+    }
+
+    #[test]
+    fn test_socket_addr_debug() {
+        use core::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+        let v4 = SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 8080);
+        common_test_debug(v4);
+        common_test_debug(SocketAddr::V4(v4));
+
+        let v6 = SocketAddrV6::new(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1), 443, 0, 0);
+        common_test_debug(v6);
+        common_test_debug(SocketAddr::V6(v6));
+
+        // Longest representation.
+        let v6_scoped = SocketAddrV6::new(
+            Ipv4Addr::new(255, 255, 255, 255).to_ipv6_mapped(),
+            u16::MAX,
+            0,
+            u32::MAX,
+        );
+        common_test_debug(v6_scoped);
+        common_test_debug(SocketAddr::V6(v6_scoped));
+    }
+
     #[test]
     fn test_hashmap_debug() {
         common_test_debug(std::collections::HashMap::from([("x", 123), ("y", 321), ("z", 444)]));
     }
 
+    #[test]
+    fn test_btreemap_debug() {
+        common_test_debug(std::collections::BTreeMap::from([("z", 444), ("x", 123), ("y", 321)]));
+        common_test_debug(std::collections::BTreeMap::<i32, i32>::new());
+    }
+
     #[test]
     fn test_poison_error_debug() {
         let pe = std::sync::PoisonError::new(123.0);
@@ -12,7 +12,7 @@
 // *******************************************************************************
 
 /// Alignment of written data.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Alignment {
     /// Align to left (`<`).
     Left,
@@ -23,7 +23,7 @@ pub enum Alignment {
 }
 
 /// Add sign character for numeric values.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Sign {
     /// Always show sign (`+`).
     Plus,
@@ -32,7 +32,7 @@ pub enum Sign {
 }
 
 /// Format integer values as hexadecimal for `ScoreDebug` implementations.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum DebugAsHex {
     /// Format integer values to lower hex.
     Lower,
@@ -41,7 +41,7 @@ pub enum DebugAsHex {
 }
 
 /// Display data in a provided format.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum DisplayHint {
     /// `{}` or `{:}`.
     NoHint,
@@ -73,7 +73,7 @@ pub enum DisplayHint {
 /// precision := count | '*'
 /// type := '?' | 'x?' | 'X?' | 'o' | 'x' | 'X' | 'p' | 'b' | 'e' | 'E'
 /// parameter := argument '$'
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct FormatSpec {
     display_hint: DisplayHint,
     fill: char,
@@ -98,7 +98,7 @@ impl FormatSpec {
     /// - `debug_as_hex`: `None`
     /// - `width`: `None`
     /// - `precision`: `None`
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self {
             display_hint: DisplayHint::NoHint,
             fill: ' ',
@@ -114,7 +114,7 @@ impl FormatSpec {
 
     /// Create format spec with provided parameters.
     #[allow(clippy::too_many_arguments)]
-    pub fn from_params(
+    pub const fn from_params(
         display_hint: DisplayHint,
         fill: char,
         align: Option<Alignment>,
@@ -138,6 +138,33 @@ impl FormatSpec {
         }
     }
 
+    /// Create format spec for debug output (`{:?}`).
+    ///
+    /// Other parameters are the same as in [`new`](Self::new).
+    pub const fn debug() -> Self {
+        let mut spec = Self::new();
+        spec.display_hint = DisplayHint::Debug;
+        spec
+    }
+
+    /// Create format spec for lower hex output (`{:x}`).
+    ///
+    /// Other parameters are the same as in [`new`](Self::new).
+    pub const fn hex() -> Self {
+        let mut spec = Self::new();
+        spec.display_hint = DisplayHint::LowerHex;
+        spec
+    }
+
+    /// Create format spec with provided minimum width (`{:width}`).
+    ///
+    /// Other parameters are the same as in [`new`](Self::new).
+    pub const fn with_width(width: u16) -> Self {
+        let mut spec = Self::new();
+        spec.width = Some(width);
+        spec
+    }
+
     /// Set display hint.
     pub fn display_hint(&mut self, display_hint: DisplayHint) -> &mut Self {
         self.display_hint = display_hint;
@@ -265,18 +292,39 @@ mod tests {
 
     #[test]
     fn test_default() {
-        let spec_default = FormatSpec::default();
-        let spec_new = FormatSpec::new();
+        assert_eq!(FormatSpec::default(), FormatSpec::new());
+    }
+
+    #[test]
+    fn test_eq() {
+        let mut a = FormatSpec::new();
+        a.width(Some(8)).align(Some(Alignment::Center)).fill('*');
+        let b = FormatSpec::from_params(
+            DisplayHint::NoHint,
+            '*',
+            Some(Alignment::Center),
+            None,
+            false,
+            false,
+            None,
+            Some(8),
+            None,
+        );
+        assert_eq!(a, b);
+        assert_eq!(a.clone(), b);
+
+        let mut c = a.clone();
+        c.precision(Some(2));
+        assert_ne!(a, c);
+        assert_ne!(FormatSpec::debug(), FormatSpec::hex());
+        assert_ne!(FormatSpec::new(), FormatSpec::with_width(0));
+    }
 
-        assert!(spec_default.get_display_hint() == spec_new.get_display_hint());
-        assert!(spec_default.get_fill() == spec_new.get_fill());
-        assert!(spec_default.get_align() == spec_new.get_align());
-        assert!(spec_default.get_sign() == spec_new.get_sign());
-        assert!(spec_default.get_alternate() == spec_new.get_alternate());
-        assert!(spec_default.get_zero_pad() == spec_new.get_zero_pad());
-        assert!(spec_default.get_debug_as_hex() == spec_new.get_debug_as_hex());
-        assert!(spec_default.get_width() == spec_new.get_width());
-        assert!(spec_default.get_precision() == spec_new.get_precision());
+    #[test]
+    fn test_debug() {
+        let spec = FormatSpec::hex();
+        let output = format!("{spec:?}");
+        assert!(output.starts_with("FormatSpec { display_hint: LowerHex, fill: ' ', align: None,"));
     }
 
     #[test]
@@ -314,6 +362,36 @@ mod tests {
         assert!(format_spec.get_precision() == precision);
     }
 
+    /// Check that all parameters except display hint and width are the same as in `FormatSpec::new()`.
+    fn assert_defaults_except_hint_and_width(format_spec: &FormatSpec) {
+        assert_eq!(format_spec.get_fill(), ' ');
+        assert!(format_spec.get_align().is_none());
+        assert!(format_spec.get_sign().is_none());
+        assert!(!format_spec.get_alternate());
+        assert!(!format_spec.get_zero_pad());
+        assert!(format_spec.get_debug_as_hex().is_none());
+        assert!(format_spec.get_precision().is_none());
+    }
+
+    #[test]
+    fn test_presets() {
+        const DEBUG: FormatSpec = FormatSpec::debug();
+        const HEX: FormatSpec = FormatSpec::hex();
+        const WIDTH: FormatSpec = FormatSpec::with_width(8);
+
+        assert!(DEBUG.get_display_hint() == DisplayHint::Debug);
+        assert!(DEBUG.get_width().is_none());
+        assert_defaults_except_hint_and_width(&DEBUG);
+
+        assert!(HEX.get_display_hint() == DisplayHint::LowerHex);
+        assert!(HEX.get_width().is_none());
+        assert_defaults_except_hint_and_width(&HEX);
+
+        assert!(WIDTH.get_display_hint() == DisplayHint::NoHint);
+        assert!(WIDTH.get_width() == Some(8));
+        assert_defaults_except_hint_and_width(&WIDTH);
+    }
+
     #[test]
     fn test_display_hint() {
         let mut format_spec = FormatSpec::new();
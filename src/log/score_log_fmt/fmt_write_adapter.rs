@@ -0,0 +1,305 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! [`ScoreWrite`] implementation for [`core::fmt::Write`] sinks.
+
+use crate::fmt::write_float;
+use crate::{Error, FormatSpec, Result, ScoreWrite};
+use core::fmt::Write;
+
+/// Adapter implementing [`ScoreWrite`] over a [`core::fmt::Write`] sink.
+///
+/// Values are rendered the same way as with [`core::fmt`], honoring width, fill, alignment, sign and zero pad.
+/// Integer values are written with [`ScoreWrite::write_int_radix`], honoring the octal, hex and binary display hints.
+/// Floating point values honor the exponent display hints and precision.
+pub struct FmtWriteAdapter<W: Write> {
+    inner: W,
+}
+
+impl<W: Write> FmtWriteAdapter<W> {
+    /// Create adapter over provided sink.
+    pub const fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Get reference to the inner sink.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Get mutable reference to the inner sink.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Consume adapter and return the inner sink.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write + Default> Default for FmtWriteAdapter<W> {
+    fn default() -> Self {
+        Self::new(W::default())
+    }
+}
+
+impl<W: Write> ScoreWrite for FmtWriteAdapter<W> {
+    fn write_bool(&mut self, v: &bool, spec: &FormatSpec) -> Result {
+        self.write_str_padded(if *v { "true" } else { "false" }, spec)
+    }
+
+    fn write_f32(&mut self, v: &f32, spec: &FormatSpec) -> Result {
+        write_float(self, v, spec)
+    }
+
+    fn write_f64(&mut self, v: &f64, spec: &FormatSpec) -> Result {
+        write_float(self, v, spec)
+    }
+
+    fn write_str(&mut self, v: &str, _spec: &FormatSpec) -> Result {
+        self.inner.write_str(v).map_err(|_| Error)
+    }
+
+    fn write_ptr(&mut self, v: *const (), _spec: &FormatSpec) -> Result {
+        write!(self.inner, "{:p}", v).map_err(|_| Error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FmtWriteAdapter;
+    use crate::{write, Alignment, Arguments, DisplayHint, Error, FormatSpec, Fragment, Placeholder, ScoreWrite, Sign};
+
+    #[test]
+    fn test_string() {
+        let mut w = FmtWriteAdapter::new(String::new());
+        let fragments = [
+            Fragment::Literal("test_"),
+            Fragment::Placeholder(Placeholder::new(&true, FormatSpec::new())),
+            Fragment::Literal("_"),
+            Fragment::Placeholder(Placeholder::new(&-123i32, FormatSpec::new())),
+            Fragment::Literal("_"),
+            Fragment::Placeholder(Placeholder::new(&432.2f64, FormatSpec::new())),
+            Fragment::Literal("_"),
+            Fragment::Placeholder(Placeholder::new(&"str", FormatSpec::new())),
+        ];
+        assert!(write(&mut w, Arguments(&fragments)) == Ok(()));
+        assert_eq!(w.get_ref(), "test_true_-123_432.2_str");
+
+        w.get_mut().clear();
+        assert!(w.write_u64(&u64::MAX, &FormatSpec::new()) == Ok(()));
+        assert_eq!(w.into_inner(), u64::MAX.to_string());
+    }
+
+    #[test]
+    fn test_int_display_hints() {
+        let cases = [
+            (DisplayHint::NoHint, false, "255"),
+            (DisplayHint::Octal, false, "377"),
+            (DisplayHint::LowerHex, true, "0xff"),
+            (DisplayHint::UpperHex, false, "FF"),
+            (DisplayHint::Binary, true, "0b11111111"),
+        ];
+        for (display_hint, alternate, expected) in cases {
+            let mut w = FmtWriteAdapter::new(String::new());
+            let mut spec = FormatSpec::new();
+            spec.display_hint(display_hint).alternate(alternate);
+            assert!(w.write_u32(&255, &spec) == Ok(()));
+            assert_eq!(w.get_ref(), expected);
+        }
+    }
+
+    fn write_float(v: f64, display_hint: DisplayHint, precision: Option<u16>) -> String {
+        let mut w = FmtWriteAdapter::new(String::new());
+        let mut spec = FormatSpec::new();
+        spec.display_hint(display_hint).precision(precision);
+        assert!(w.write_f64(&v, &spec) == Ok(()));
+        w.into_inner()
+    }
+
+    #[test]
+    fn test_float_exp() {
+        for v in [0.0, -0.0, 1.0, -1234.5678, 1e300, f64::MIN_POSITIVE / 4.0] {
+            assert_eq!(write_float(v, DisplayHint::LowerExp, None), format!("{v:e}"));
+            assert_eq!(write_float(v, DisplayHint::UpperExp, None), format!("{v:E}"));
+            assert_eq!(write_float(v, DisplayHint::UpperExp, Some(3)), format!("{v:.3E}"));
+            assert_eq!(write_float(v, DisplayHint::NoHint, Some(2)), format!("{v:.2}"));
+        }
+
+        let mut w = FmtWriteAdapter::new(String::new());
+        let mut spec = FormatSpec::new();
+        spec.display_hint(DisplayHint::LowerExp).precision(Some(1));
+        assert!(w.write_f32(&-0.00125f32, &spec) == Ok(()));
+        assert_eq!(w.get_ref(), &format!("{:.1e}", -0.00125f32));
+    }
+
+    fn spec(
+        align: Option<Alignment>,
+        fill: char,
+        sign: Option<Sign>,
+        zero_pad: bool,
+        width: Option<u16>,
+    ) -> FormatSpec {
+        let mut spec = FormatSpec::new();
+        spec.align(align).fill(fill).sign(sign).zero_pad(zero_pad).width(width);
+        spec
+    }
+
+    #[test]
+    fn test_int_padding() {
+        let cases = [
+            (
+                spec(Some(Alignment::Right), ' ', None, false, Some(6)),
+                format!("{:>6}", -42i32),
+            ),
+            (spec(None, ' ', None, true, Some(6)), format!("{:06}", -42i32)),
+            (spec(None, ' ', Some(Sign::Plus), false, None), format!("{:+}", -42i32)),
+            (
+                spec(Some(Alignment::Left), '*', Some(Sign::Plus), false, Some(6)),
+                format!("{:*<+6}", -42i32),
+            ),
+            (
+                spec(Some(Alignment::Center), '-', None, false, Some(7)),
+                format!("{:-^7}", -42i32),
+            ),
+        ];
+        for (spec, expected) in cases {
+            let mut w = FmtWriteAdapter::new(String::new());
+            assert!(w.write_i32(&-42, &spec) == Ok(()));
+            assert_eq!(w.get_ref(), &expected);
+        }
+
+        let mut w = FmtWriteAdapter::new(String::new());
+        assert!(w.write_u8(&7, &spec(None, ' ', Some(Sign::Plus), true, Some(4))) == Ok(()));
+        assert_eq!(w.get_ref(), &format!("{:+04}", 7u8));
+    }
+
+    #[test]
+    fn test_bool_padding() {
+        let cases = [
+            (
+                spec(Some(Alignment::Right), ' ', None, false, Some(6)),
+                format!("{:>6}", true),
+            ),
+            (spec(None, ' ', None, false, Some(6)), format!("{:6}", true)),
+            (
+                spec(Some(Alignment::Center), '.', None, false, Some(9)),
+                format!("{:.^9}", true),
+            ),
+        ];
+        for (spec, expected) in cases {
+            let mut w = FmtWriteAdapter::new(String::new());
+            assert!(w.write_bool(&true, &spec) == Ok(()));
+            assert_eq!(w.get_ref(), &expected);
+        }
+    }
+
+    #[test]
+    fn test_float_padding() {
+        for v in [12.34567, -12.34567, 0.0, f64::INFINITY, f64::NAN] {
+            let cases = [
+                (spec(None, ' ', None, false, Some(8)), Some(2), format!("{v:8.2}")),
+                (
+                    spec(Some(Alignment::Left), '_', None, false, Some(8)),
+                    Some(2),
+                    format!("{v:_<8.2}"),
+                ),
+                (
+                    spec(Some(Alignment::Center), ' ', Some(Sign::Plus), false, Some(10)),
+                    None,
+                    format!("{v:^+10}"),
+                ),
+                (spec(None, ' ', None, true, Some(9)), Some(3), format!("{v:09.3}")),
+                (
+                    spec(None, ' ', Some(Sign::Plus), true, Some(9)),
+                    None,
+                    format!("{v:+09}"),
+                ),
+                (
+                    spec(None, ' ', Some(Sign::Plus), false, None),
+                    Some(1),
+                    format!("{v:+.1}"),
+                ),
+                (spec(None, ' ', None, false, Some(2)), None, format!("{v:2}")),
+            ];
+            for (mut spec, precision, expected) in cases {
+                let mut w = FmtWriteAdapter::new(String::new());
+                spec.precision(precision);
+                assert!(w.write_f64(&v, &spec) == Ok(()));
+                assert_eq!(w.get_ref(), &expected);
+            }
+        }
+
+        let mut w = FmtWriteAdapter::new(String::new());
+        let mut exp_spec = spec(Some(Alignment::Right), ' ', Some(Sign::Plus), false, Some(12));
+        exp_spec.display_hint(DisplayHint::UpperExp).precision(Some(2));
+        assert!(w.write_f32(&1234.5f32, &exp_spec) == Ok(()));
+        assert_eq!(w.get_ref(), &format!("{:>+12.2E}", 1234.5f32));
+    }
+
+    #[test]
+    fn test_arguments_with_specs() {
+        let mut w = FmtWriteAdapter::new(String::new());
+        let mut float_spec = FormatSpec::with_width(8);
+        float_spec.precision(Some(2));
+        let fragments = [
+            Fragment::Literal("["),
+            Fragment::Placeholder(Placeholder::new(
+                &42u32,
+                spec(Some(Alignment::Right), ' ', None, false, Some(6)),
+            )),
+            Fragment::Literal("]["),
+            Fragment::Placeholder(Placeholder::new(&-7i32, spec(None, ' ', None, true, Some(6)))),
+            Fragment::Literal("]["),
+            Fragment::Placeholder(Placeholder::new(&5i32, spec(None, ' ', Some(Sign::Plus), false, None))),
+            Fragment::Literal("]["),
+            Fragment::Placeholder(Placeholder::new(
+                &true,
+                spec(Some(Alignment::Right), ' ', None, false, Some(5)),
+            )),
+            Fragment::Literal("]["),
+            Fragment::Placeholder(Placeholder::new(&12.34567f64, float_spec)),
+            Fragment::Literal("]"),
+        ];
+        assert!(write(&mut w, Arguments(&fragments)) == Ok(()));
+        assert_eq!(
+            w.get_ref(),
+            &format!(
+                "[{:>6}][{:06}][{:+}][{:>5}][{:8.2}]",
+                42u32, -7i32, 5i32, true, 12.34567f64
+            )
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        let w = FmtWriteAdapter::<String>::default();
+        assert!(w.get_ref().is_empty());
+    }
+
+    struct FailingSink;
+
+    impl core::fmt::Write for FailingSink {
+        fn write_str(&mut self, _s: &str) -> core::fmt::Result {
+            Err(core::fmt::Error)
+        }
+    }
+
+    #[test]
+    fn test_error_propagated() {
+        let mut w = FmtWriteAdapter::new(FailingSink);
+        assert!(w.write_str("test", &FormatSpec::new()) == Err(Error));
+        assert!(w.write_i32(&123, &FormatSpec::new()) == Err(Error));
+    }
+}
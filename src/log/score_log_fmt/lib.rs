@@ -17,16 +17,24 @@
 //! Replacement for [`core::fmt`].
 
 mod builders;
+mod counting_writer;
 mod fmt;
 mod fmt_impl;
 #[cfg(feature = "qm")]
 mod fmt_impl_qm;
 mod fmt_spec;
+mod fmt_write_adapter;
 mod macros;
+mod sorted;
+mod stack_writer;
 
 pub use builders::{DebugList, DebugMap, DebugSet, DebugStruct, DebugTuple};
+pub use counting_writer::CountingWriter;
 pub use fmt::*;
 pub use fmt_spec::*;
+pub use fmt_write_adapter::FmtWriteAdapter;
+pub use sorted::Sorted;
+pub use stack_writer::StackWriter;
 
 #[cfg(test)]
 mod test_utils;
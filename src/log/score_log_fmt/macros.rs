@@ -21,3 +21,14 @@ macro_rules! score_write {
         $crate::write($dst, score_log::format_args!($($arg)*))
     };
 }
+
+/// Creates a [`String`] using interpolation of runtime expressions.
+///
+/// This macro accepts a format string and a list of arguments.
+/// Replacement for [`std::format!`].
+#[macro_export]
+macro_rules! score_format {
+    ($($arg:tt)*) => {
+        $crate::format(score_log::format_args!($($arg)*))
+    };
+}
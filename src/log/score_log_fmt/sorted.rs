@@ -0,0 +1,80 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! Wrapper rendering collections in a deterministic order.
+
+use crate::{DebugMap, FormatSpec, Result, ScoreDebug, Writer};
+use std::collections::HashMap;
+
+/// Wrapper rendering the wrapped collection with entries sorted by key.
+///
+/// Iteration order of [`HashMap`] depends on its random state, so the output differs between runs.
+/// Sorting makes it stable, e.g., for diffing logs, at the cost of collecting and sorting entry references.
+///
+/// Wraps a reference to the collection, e.g., `Sorted(&map)`, so the collection is not consumed.
+#[derive(Clone, Copy)]
+pub struct Sorted<T>(pub T);
+
+impl<K, V, S> ScoreDebug for Sorted<&HashMap<K, V, S>>
+where
+    K: Ord + ScoreDebug,
+    V: ScoreDebug,
+{
+    fn fmt(&self, f: Writer, spec: &FormatSpec) -> Result {
+        // Keys are unique, so unstable sort is deterministic.
+        let mut entries: Vec<(&K, &V)> = self.0.iter().collect();
+        entries.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut debug_map = DebugMap::new(f, spec);
+        debug_map.entries(entries).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sorted;
+    use crate::test_utils::StringWriter;
+    use crate::{DisplayHint, FormatSpec, ScoreDebug};
+    use std::collections::{BTreeMap, HashMap};
+
+    fn debug<T: ScoreDebug>(v: T) -> String {
+        let mut w = StringWriter::new();
+        let mut spec = FormatSpec::new();
+        spec.display_hint(DisplayHint::Debug);
+        assert!(ScoreDebug::fmt(&v, &mut w, &spec).is_ok());
+        w.get().to_string()
+    }
+
+    #[test]
+    fn test_sorted_hashmap() {
+        let entries: Vec<(String, u32)> = (0..32).map(|i| (format!("key_{i:02}"), i)).collect();
+        let expected = format!("{:?}", BTreeMap::from_iter(entries.clone()));
+
+        // Each map has its own random state, so unsorted iteration order differs between them.
+        for i in 0..16 {
+            let mut map = HashMap::with_capacity(i);
+            if i % 2 == 0 {
+                map.extend(entries.iter().cloned());
+            } else {
+                map.extend(entries.iter().rev().cloned());
+            }
+            assert_eq!(debug(Sorted(&map)), expected);
+        }
+    }
+
+    #[test]
+    fn test_sorted_hashmap_empty() {
+        let map = HashMap::<i32, i32>::new();
+        assert_eq!(debug(Sorted(&map)), "{}");
+    }
+}
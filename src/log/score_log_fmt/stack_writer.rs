@@ -0,0 +1,348 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//! [`ScoreWrite`] implementation over a fixed size buffer.
+
+use crate::fmt::write_float;
+use crate::{Error, FormatSpec, Result, ScoreWrite};
+use core::fmt::{self, Write};
+
+/// Writer implementation based on fixed size buffer.
+///
+/// The buffer is stored inline, so the writer doesn't allocate and can live on the stack.
+/// Data which doesn't fit into the buffer is dropped, cutting strings at a char boundary,
+/// and the writer is marked as [`overflowed`](Self::overflowed); writes never fail.
+///
+/// Integer and `bool` values are written without `core::fmt`, integers by the provided [`ScoreWrite`] methods.
+/// Floating point values are still written with `core::fmt`.
+/// All values honor width, fill, alignment, sign and zero pad,
+/// same as with [`FmtWriteAdapter`](crate::FmtWriteAdapter).
+pub struct StackWriter<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+    overflowed: bool,
+}
+
+impl<const N: usize> StackWriter<N> {
+    /// Create `StackWriter` instance.
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+            overflowed: false,
+        }
+    }
+
+    /// Get data from buffer.
+    pub fn get(&self) -> &str {
+        // SAFETY: All bytes in `self.buf[..self.len]` are guaranteed to form valid UTF-8.
+        unsafe { core::str::from_utf8_unchecked(&self.buf[..self.len]) }
+    }
+
+    /// Check if any data was truncated since the last [`clear`](Self::clear).
+    pub fn overflowed(&self) -> bool {
+        self.overflowed
+    }
+
+    /// Get number of remaining bytes in the buffer.
+    pub fn remaining(&self) -> usize {
+        N - self.len
+    }
+
+    /// Reset buffer state, including the overflow flag.
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.overflowed = false;
+    }
+}
+
+impl<const N: usize> Default for StackWriter<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Write for StackWriter<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // Get number of remaining bytes in the buffer.
+        // Return if buffer is full.
+        let remaining = self.remaining();
+        if remaining == 0 {
+            self.overflowed |= !s.is_empty();
+            return Ok(());
+        }
+
+        // Get provided string as bytes.
+        let bytes = s.as_bytes();
+
+        // Get number of bytes requested or remaining in the buffer.
+        // Mark buffer as overflowed if not all bytes fit.
+        let mut end = bytes.len().min(remaining);
+        self.overflowed |= end < bytes.len();
+
+        // Move back until char boundary.
+        // Overflow is already marked, as `end` can only decrease here.
+        // Return if buffer is full.
+        while end > 0 && !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        if end == 0 {
+            return Ok(());
+        }
+
+        // Write to underlying buffer.
+        self.buf[self.len..self.len + end].copy_from_slice(&bytes[..end]);
+        self.len += end;
+
+        Ok(())
+    }
+}
+
+impl<const N: usize> ScoreWrite for StackWriter<N> {
+    fn write_bool(&mut self, v: &bool, spec: &FormatSpec) -> Result {
+        self.write_str_padded(if *v { "true" } else { "false" }, spec)
+    }
+
+    fn write_f32(&mut self, v: &f32, spec: &FormatSpec) -> Result {
+        write_float(self, v, spec)
+    }
+
+    fn write_f64(&mut self, v: &f64, spec: &FormatSpec) -> Result {
+        write_float(self, v, spec)
+    }
+
+    fn write_str(&mut self, v: &str, _spec: &FormatSpec) -> Result {
+        Write::write_str(self, v).map_err(|_| Error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StackWriter;
+    use crate::{write, Arguments, DisplayHint, FormatSpec, Fragment, Placeholder, ScoreWrite};
+    use core::fmt::Write;
+
+    #[test]
+    fn test_arguments() {
+        let mut w = StackWriter::<64>::new();
+        let fragments = [
+            Fragment::Literal("test_"),
+            Fragment::Placeholder(Placeholder::new(&true, FormatSpec::new())),
+            Fragment::Literal("_"),
+            Fragment::Placeholder(Placeholder::new(&-123i32, FormatSpec::new())),
+            Fragment::Literal("_"),
+            Fragment::Placeholder(Placeholder::new(&432.2f64, FormatSpec::new())),
+            Fragment::Literal("_"),
+            Fragment::Placeholder(Placeholder::new(&"str", FormatSpec::new())),
+        ];
+        assert!(write(&mut w, Arguments(&fragments)) == Ok(()));
+        assert_eq!(w.get(), "test_true_-123_432.2_str");
+        assert!(!w.overflowed());
+
+        // Output is cut at the buffer size, without failing.
+        let mut w = StackWriter::<8>::new();
+        assert!(write(&mut w, Arguments(&fragments)) == Ok(()));
+        assert_eq!(w.get(), "test_tru");
+        assert!(w.overflowed());
+    }
+
+    #[test]
+    fn test_exact_fit() {
+        let mut w = StackWriter::<4>::new();
+        assert!(Write::write_str(&mut w, "ab").is_ok());
+        assert!(Write::write_str(&mut w, "cd").is_ok());
+        assert_eq!(w.get(), "abcd");
+        assert!(!w.overflowed());
+        assert!(Write::write_str(&mut w, "").is_ok());
+        assert!(!w.overflowed());
+    }
+
+    #[test]
+    fn test_under_fill() {
+        let mut w = StackWriter::<8>::new();
+        assert!(Write::write_str(&mut w, "abc").is_ok());
+        assert_eq!(w.get(), "abc");
+        assert_eq!(w.remaining(), 5);
+        assert!(!w.overflowed());
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut w = StackWriter::<4>::new();
+        assert!(Write::write_str(&mut w, "abcdef").is_ok());
+        assert_eq!(w.get(), "abcd");
+        assert!(w.overflowed());
+
+        w.clear();
+        assert!(!w.overflowed());
+        assert!(Write::write_str(&mut w, "abcd").is_ok());
+        assert!(Write::write_str(&mut w, "e").is_ok());
+        assert!(w.overflowed());
+
+        let mut w = StackWriter::<4>::new();
+        assert!(ScoreWrite::write_str(&mut w, "abcde", &FormatSpec::new()).is_ok());
+        assert!(w.overflowed());
+        w.clear();
+        assert!(!w.overflowed());
+    }
+
+    #[test]
+    fn test_multibyte_overflow() {
+        // Emoji straddles the capacity edge.
+        let mut w = StackWriter::<3>::new();
+        assert!(Write::write_str(&mut w, "a😀").is_ok());
+        assert_eq!(w.get(), "a");
+        assert!(w.overflowed());
+
+        // Nothing of the emoji fits, but the buffer isn't full.
+        let mut w = StackWriter::<6>::new();
+        assert!(Write::write_str(&mut w, "abcd").is_ok());
+        assert!(Write::write_str(&mut w, "😀").is_ok());
+        assert_eq!(w.get(), "abcd");
+        assert_eq!(w.remaining(), 2);
+        assert!(w.overflowed());
+
+        // Multibyte string fitting exactly.
+        let mut w = StackWriter::<5>::new();
+        assert!(Write::write_str(&mut w, "a😀").is_ok());
+        assert_eq!(w.get(), "a😀");
+        assert!(!w.overflowed());
+    }
+
+    /// Write integer with the spec and compare with `core::fmt` output of each display hint.
+    macro_rules! check_int {
+        ($write:ident, $v:expr) => {{
+            let v = $v;
+            let cases = [
+                (DisplayHint::NoHint, format!("{v}")),
+                (DisplayHint::Octal, format!("{v:o}")),
+                (DisplayHint::LowerHex, format!("{v:x}")),
+                (DisplayHint::UpperHex, format!("{v:X}")),
+                (DisplayHint::Binary, format!("{v:b}")),
+            ];
+            for (display_hint, expected) in cases {
+                let mut writer = StackWriter::<256>::new();
+                let mut spec = FormatSpec::new();
+                spec.display_hint(display_hint);
+                assert!(writer.$write(&v, &spec).is_ok());
+                assert_eq!(writer.get(), expected);
+            }
+        }};
+    }
+
+    #[test]
+    fn test_write_int_8_and_16_bit_full_range() {
+        for v in i8::MIN..=i8::MAX {
+            check_int!(write_i8, v);
+        }
+        for v in u8::MIN..=u8::MAX {
+            check_int!(write_u8, v);
+        }
+        for v in i16::MIN..=i16::MAX {
+            check_int!(write_i16, v);
+        }
+        for v in u16::MIN..=u16::MAX {
+            check_int!(write_u16, v);
+        }
+    }
+
+    #[test]
+    fn test_write_int_32_and_64_bit_range() {
+        // Boundaries, powers of radixes and a pseudo-random sample of the full range.
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        let mut samples = vec![0, 1, u64::MAX, u64::MAX - 1, 1 << 63, (1 << 63) - 1];
+        samples.extend((0..64).flat_map(|shift| [1u64 << shift, (1u64 << shift) - 1]));
+        samples.extend((0..20).map(|exp| 10u64.pow(exp)));
+        samples.extend((0..10_000).map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        }));
+        for v in samples {
+            check_int!(write_u64, v);
+            check_int!(write_i64, v as i64);
+            check_int!(write_u32, v as u32);
+            check_int!(write_i32, v as i32);
+        }
+    }
+
+    #[test]
+    fn test_write_int_width() {
+        let mut writer = StackWriter::<64>::new();
+        let mut spec = FormatSpec::new();
+        spec.width(Some(8)).zero_pad(true);
+        assert!(writer.write_i32(&-1234, &spec).is_ok());
+        assert_eq!(writer.get(), format!("{:08}", -1234));
+    }
+
+    /// Compare integer writes of the provided [`ScoreWrite`] methods with `write!`, which uses `core::fmt`.
+    ///
+    /// Timing dependent, so ignored by default. Run in release mode:
+    /// `cargo test --release -p score_log_fmt bench_write_int -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn bench_write_int() {
+        use core::hint::black_box;
+        use core::time::Duration;
+        use std::time::Instant;
+
+        const ITERATIONS: usize = 20_000_000;
+
+        fn measure<T: Copy>(values: &[T], mut write: impl FnMut(&mut StackWriter<64>, T)) -> Duration {
+            let mut writer = StackWriter::<64>::new();
+            let start = Instant::now();
+            for &v in values.iter().cycle().take(ITERATIONS) {
+                writer.clear();
+                write(&mut writer, black_box(v));
+                black_box(writer.get());
+            }
+            start.elapsed()
+        }
+
+        fn report<T: Copy + core::fmt::Display>(name: &str, values: &[T], score_write: fn(&mut StackWriter<64>, T)) {
+            let score = measure(values, score_write);
+            let core = measure(values, |w, v| {
+                let _ = write!(w, "{}", v);
+            });
+            println!("{name:<12} score_write: {score:>10.2?}, write!: {core:>10.2?}");
+        }
+
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        let random: Vec<u64> = (0..4096)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state
+            })
+            .collect();
+        let u64_full: Vec<u64> = random.clone();
+        let u64_small: Vec<u64> = random.iter().map(|v| v % 10_000).collect();
+        let i32_full: Vec<i32> = random.iter().map(|&v| v as i32).collect();
+        let u8_full: Vec<u8> = random.iter().map(|&v| v as u8).collect();
+
+        report("u64", &u64_full, |w, v| {
+            let _ = w.write_u64(&v, &FormatSpec::new());
+        });
+        report("u64 < 10^4", &u64_small, |w, v| {
+            let _ = w.write_u64(&v, &FormatSpec::new());
+        });
+        report("i32", &i32_full, |w, v| {
+            let _ = w.write_i32(&v, &FormatSpec::new());
+        });
+        report("u8", &u8_full, |w, v| {
+            let _ = w.write_u8(&v, &FormatSpec::new());
+        });
+    }
+}
@@ -11,17 +11,58 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
-use quote::{quote, ToTokens};
+use core::ops::Range;
+use proc_macro2::{Spacing, TokenTree};
+use quote::quote;
 use score_log_fmt::{Alignment, DebugAsHex, DisplayHint, FormatSpec, Sign};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
 use syn::punctuated::{IntoIter, Punctuated};
 use syn::token::Comma;
-use syn::{parse_macro_input, Error, Expr, ExprLit, Lit};
+use syn::{parse_macro_input, Error, Expr, ExprLit, ExprPath, Lit, LitStr, Token};
 
 /// Parse error containing reason.
 /// - Functions with access to tokens should return `syn::Error`
 /// - Other functions should return `ParseError` containing explanation.
 struct ParseError(pub String);
 
+/// Format string parse error, with byte range of the offending part of the format string.
+struct FormatStringError {
+    error: ParseError,
+    range: Range<usize>,
+}
+
+impl FormatStringError {
+    fn new(error: ParseError, range: Range<usize>) -> Self {
+        Self { error, range }
+    }
+
+    /// Convert to `syn::Error` pointing to the offending part of the format string literal.
+    ///
+    /// Spans of parts of a literal are not available on all compilers.
+    /// The whole literal is used in that case, and the offending part is named in the message.
+    fn into_syn_error(self, format_string_expr: &LitStr) -> Error {
+        let format_string = format_string_expr.value();
+        let span = subspan(format_string_expr, &self.range).unwrap_or_else(|| format_string_expr.span());
+        let part = format_string.get(self.range).unwrap_or_default();
+        Error::new(span, format!("{} in `{}`", self.error.0, part))
+    }
+}
+
+/// Get span of a byte range of the string literal value.
+///
+/// Ranges can only be mapped to the literal source if it contains no escapes and isn't a raw string.
+fn subspan(lit: &LitStr, range: &Range<usize>) -> Option<proc_macro2::Span> {
+    let token = lit.token();
+    let repr = token.to_string();
+    let contents = repr.strip_prefix('"')?.strip_suffix('"')?;
+    if contents != lit.value() {
+        return None;
+    }
+    // Skip opening quote.
+    token.subspan(range.start + 1..range.end + 1)
+}
+
 enum Argument {
     Position,
     Index(usize),
@@ -59,6 +100,15 @@ fn get_sign(c: &char) -> Result<Sign, ParseError> {
     }
 }
 
+/// Parse width or precision value.
+///
+/// Values are limited to `u16`, which also bounds the padding emitted at render time.
+/// Only digits are collected by the caller, so the only possible failure is overflow.
+fn parse_count(s: &str, name: &str) -> Result<u16, ParseError> {
+    s.parse()
+        .map_err(|_| ParseError(format!("{name} is too large: {s}, maximum is {}", u16::MAX)))
+}
+
 /// Parse right side of the placeholder `{arg:*spec*}`.
 fn parse_spec(s: &str) -> Result<FormatSpec, ParseError> {
     let mut chars = s.chars().peekable();
@@ -138,10 +188,7 @@ fn parse_spec(s: &str) -> Result<FormatSpec, ParseError> {
             }
         }
         if !width_str.is_empty() {
-            width = match width_str.parse() {
-                Ok(v) => Some(v),
-                Err(_) => return Err(ParseError("unable to parse width".to_string())),
-            };
+            width = Some(parse_count(&width_str, "width")?);
         }
     }
 
@@ -162,10 +209,7 @@ fn parse_spec(s: &str) -> Result<FormatSpec, ParseError> {
                     }
                 }
                 if !precision_str.is_empty() {
-                    precision = match precision_str.parse() {
-                        Ok(v) => Some(v),
-                        Err(_) => return Err(ParseError("unable to parse precision".to_string())),
-                    };
+                    precision = Some(parse_count(&precision_str, "precision")?);
                 }
             }
         }
@@ -346,7 +390,7 @@ fn process_escaped_braces(string_literal: &str) -> String {
     string_literal.replace("{{", "{").replace("}}", "}")
 }
 
-fn process_format_string(format_string: &str) -> Result<Vec<Spec>, ParseError> {
+fn process_format_string(format_string: &str) -> Result<Vec<Spec>, FormatStringError> {
     // Find braces locations.
     #[derive(PartialEq)]
     enum Brace {
@@ -356,7 +400,8 @@ fn process_format_string(format_string: &str) -> Result<Vec<Spec>, ParseError> {
         DoubleRight,
     }
 
-    let mut chars = format_string.chars().enumerate().peekable();
+    // Byte offsets are used, as they're required for slicing the format string.
+    let mut chars = format_string.char_indices().peekable();
     let mut braces = Vec::new();
     while let Some((i, c)) = chars.next() {
         let next = chars.peek().map(|&(_, ch)| ch);
@@ -393,10 +438,13 @@ fn process_format_string(format_string: &str) -> Result<Vec<Spec>, ParseError> {
             Brace::SingleLeft => {
                 let (pi, pb) = braces_it
                     .peek()
-                    .ok_or_else(|| ParseError("dangling left brace".to_string()))?;
+                    .ok_or_else(|| FormatStringError::new(ParseError("dangling left brace".to_string()), i..i + 1))?;
                 match pb {
                     Brace::SingleLeft => {
-                        return Err(ParseError("dangling left brace".to_string()));
+                        return Err(FormatStringError::new(
+                            ParseError("dangling left brace".to_string()),
+                            i..i + 1,
+                        ));
                     },
                     Brace::SingleRight => {
                         // Inclusive range cannot be used.
@@ -405,13 +453,19 @@ fn process_format_string(format_string: &str) -> Result<Vec<Spec>, ParseError> {
                         braces_it.next();
                     },
                     Brace::DoubleLeft | Brace::DoubleRight => {
-                        return Err(ParseError("escaped characters inside placeholder".to_string()));
+                        return Err(FormatStringError::new(
+                            ParseError("escaped characters inside placeholder".to_string()),
+                            i..*pi + 2,
+                        ));
                     },
                 }
             },
             // Dangling right brace.
             Brace::SingleRight => {
-                return Err(ParseError("dangling right brace".to_string()));
+                return Err(FormatStringError::new(
+                    ParseError("dangling right brace".to_string()),
+                    i..i + 1,
+                ));
             },
             // Escaped characters are ignored.
             Brace::DoubleLeft | Brace::DoubleRight => continue,
@@ -442,7 +496,9 @@ fn process_format_string(format_string: &str) -> Result<Vec<Spec>, ParseError> {
     let mut specs = Vec::new();
     for (is_placeholder, range) in types_and_ranges {
         let spec = if is_placeholder {
-            Spec::Placeholder(Placeholder::from(&format_string[range])?)
+            let placeholder =
+                Placeholder::from(&format_string[range.clone()]).map_err(|e| FormatStringError::new(e, range))?;
+            Spec::Placeholder(placeholder)
         } else {
             Spec::Literal(process_escaped_braces(&format_string[range]))
         };
@@ -498,6 +554,15 @@ fn validate_args(args: &[Expr]) -> Result<(), Error> {
     Ok(())
 }
 
+/// Get name of the argument expression, if it is a plain identifier.
+/// Raw identifiers are returned without the `r#` prefix.
+fn arg_name(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Path(ExprPath { qself: None, path, .. }) => path.get_ident().map(|ident| ident.unraw().to_string()),
+        _ => None,
+    }
+}
+
 /// Select argument with name.
 ///
 /// Following cases are supported:
@@ -506,10 +571,15 @@ fn validate_args(args: &[Expr]) -> Result<(), Error> {
 /// - Name provided by spec, but aliased by `args` - get assigned argument expression from `args`.
 ///   E.g., `score_log_format_args!("{arg}", arg=other_value)`.
 ///
+/// Names are compared as identifiers, raw identifiers match names without the `r#` prefix.
+/// E.g., `score_log_format_args!("{type}", r#type)`.
+///
 /// Not yet supported:
 /// - Name provided by spec, but not `args` - create argument expression.
 ///   E.g., `score_log_format_args!("{arg}")`.
 fn select_arg_with_name(args: &[Expr], name: &str) -> Result<Expr, Error> {
+    let name = name.strip_prefix("r#").unwrap_or(name);
+
     // Find all arguments that match. Either zero or one are allowed.
     let mut found: Vec<Expr> = Vec::new();
     for arg in args.iter() {
@@ -545,7 +615,7 @@ fn select_arg_with_name(args: &[Expr], name: &str) -> Result<Expr, Error> {
             _ => return Err(Error::new_spanned(arg, "invalid expression type")),
         };
 
-        if arg_expr.to_token_stream().to_string() == name {
+        if arg_name(&arg_expr).is_some_and(|arg_name| arg_name == name) {
             if let Some(alias_expr) = alias_expr {
                 found.push(alias_expr);
             } else {
@@ -585,8 +655,7 @@ fn parse_fragments(punctuated_it: &mut IntoIter<Expr>) -> Result<Vec<proc_macro2
 
     // Process format string and create list of specs.
     let format_string = format_string_expr.value();
-    let specs =
-        process_format_string(&format_string).map_err(|e| Error::new_spanned(format_string_expr.clone(), e.0))?;
+    let specs = process_format_string(&format_string).map_err(|e| e.into_syn_error(&format_string_expr))?;
 
     // Process specs and match them to provided args.
     let args: Vec<Expr> = punctuated_it.collect();
@@ -627,7 +696,41 @@ fn parse_fragments(punctuated_it: &mut IntoIter<Expr>) -> Result<Vec<proc_macro2
     Ok(fragments)
 }
 
+/// Input in `raw: expr` form, written verbatim as a single fragment.
+struct RawInput(Expr);
+
+impl RawInput {
+    /// Check if input starts with `raw:`.
+    fn matches(input: &proc_macro2::TokenStream) -> bool {
+        let mut tokens = input.clone().into_iter();
+        matches!(
+            (tokens.next(), tokens.next()),
+            (Some(TokenTree::Ident(ident)), Some(TokenTree::Punct(punct)))
+                if ident == "raw" && punct.as_char() == ':' && punct.spacing() == Spacing::Alone
+        )
+    }
+}
+
+impl Parse for RawInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<syn::Ident>()?;
+        input.parse::<Token![:]>()?;
+        let expr = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self(expr))
+    }
+}
+
 pub(crate) fn expand(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    // Raw string is not a format string, so it's never parsed.
+    if RawInput::matches(&input.clone().into()) {
+        let RawInput(expr) = parse_macro_input!(input as RawInput);
+        return quote! {
+            score_log::fmt::Arguments(&[score_log::fmt::Fragment::Raw(core::convert::AsRef::<str>::as_ref(&(#expr)))])
+        }
+        .into();
+    }
+
     // Collect expressions separated by comma.
     // NOTE: `parse_macro_input!` can't be build if function return type is not `TokenStream`.
     let punctuated = parse_macro_input!(input with Punctuated<Expr, Comma>::parse_terminated);
@@ -641,3 +744,82 @@ pub(crate) fn expand(input: proc_macro::TokenStream) -> proc_macro::TokenStream
 
     quote! { score_log::fmt::Arguments(&[#(#fragments),*]) }.into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_part(format_string: &str) -> Option<&str> {
+        process_format_string(format_string)
+            .err()
+            .and_then(|e| format_string.get(e.range))
+    }
+
+    fn spec_error(spec: &str) -> Option<String> {
+        parse_spec(spec).err().map(|e| e.0)
+    }
+
+    fn selected_arg(args: &[Expr], name: &str) -> Option<String> {
+        select_arg_with_name(args, name)
+            .ok()
+            .map(|expr| quote!(#expr).to_string())
+    }
+
+    #[test]
+    fn test_select_arg_with_name() {
+        let args: Vec<Expr> = vec![
+            syn::parse_quote!(value),
+            syn::parse_quote!(r#type),
+            syn::parse_quote!(r#match = 123),
+            syn::parse_quote!(self.value),
+        ];
+        assert_eq!(selected_arg(&args, "value"), Some("value".to_string()));
+        assert_eq!(selected_arg(&args, "type"), Some("r#type".to_string()));
+        assert_eq!(selected_arg(&args, "r#type"), Some("r#type".to_string()));
+        assert_eq!(selected_arg(&args, "match"), Some("123".to_string()));
+        assert_eq!(selected_arg(&args, "self.value"), None);
+        assert_eq!(selected_arg(&args, "other"), None);
+    }
+
+    #[test]
+    fn test_spec_width_overflow() {
+        assert_eq!(spec_error("65535"), None);
+        assert_eq!(
+            spec_error("70000"),
+            Some("width is too large: 70000, maximum is 65535".to_string())
+        );
+        assert_eq!(
+            spec_error(">99999999999999999999"),
+            Some("width is too large: 99999999999999999999, maximum is 65535".to_string())
+        );
+        assert_eq!(
+            spec_error(".70000"),
+            Some("precision is too large: 70000, maximum is 65535".to_string())
+        );
+    }
+
+    #[test]
+    fn test_spec_width_non_numeric() {
+        assert_eq!(spec_error("abc"), Some("unknown display hint: abc".to_string()));
+        assert_eq!(spec_error("5abc"), Some("unknown display hint: abc".to_string()));
+    }
+
+    #[test]
+    fn test_error_range_placeholder() {
+        assert_eq!(error_part("test_{}_{:q}_{}"), Some("{:q}"));
+    }
+
+    #[test]
+    fn test_error_range_multibyte() {
+        assert_eq!(error_part("zażółć_{}_{x:5.3z}"), Some("{x:5.3z}"));
+    }
+
+    #[test]
+    fn test_error_range_braces() {
+        assert_eq!(error_part("test_{_{}"), Some("{"));
+        assert_eq!(error_part("test_{}_{"), Some("{"));
+        assert_eq!(error_part("test_}_{}"), Some("}"));
+        assert_eq!(error_part("test_{a{{}"), Some("{a{{"));
+        assert_eq!(error_part("test_{}_{:x}"), None);
+    }
+}
@@ -27,13 +27,21 @@ mod score_debug;
 ///
 /// This macro takes a formatting string literal containing `{}` for each additional argument.
 /// [`score_log_format_args!`] prepares the additional parameters to ensure the output can be interpreted as a message.
+///
+/// Alternatively, `raw: expr` can be provided instead of the format string and arguments.
+/// The expression must implement `AsRef<str>`, e.g., an already formatted `String`.
+/// It is written verbatim as a single [`Fragment::Raw`](score_log_fmt::Fragment::Raw),
+/// so its content is never interpreted, e.g., `{}` in user data is written as is.
 #[proc_macro]
 pub fn score_log_format_args(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     format_args::expand(input)
 }
 
 /// Automatically generate [`ScoreDebug`] implementation.
-#[proc_macro_derive(ScoreDebug)]
+///
+/// Structs annotated with `#[score_debug(non_exhaustive)]` finish their output with `..`,
+/// using `finish_non_exhaustive()` of the builders.
+#[proc_macro_derive(ScoreDebug, attributes(score_debug))]
 pub fn score_debug(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     score_debug::expand(input)
 }
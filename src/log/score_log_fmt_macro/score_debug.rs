@@ -13,18 +13,50 @@
 
 use quote::{format_ident, quote};
 use syn::{
-    parse_macro_input, Data, DataEnum, DataStruct, DeriveInput, Error, Fields, Ident, ImplGenerics, Index, TypeGenerics,
+    parse_macro_input, Attribute, Data, DataEnum, DataStruct, DeriveInput, Error, Fields, Ident, ImplGenerics, Index,
+    TypeGenerics,
 };
 
+/// Container-level options set with `#[score_debug(...)]`.
+#[derive(Default)]
+struct ContainerOptions {
+    /// Finish output with `..` to indicate hidden fields.
+    non_exhaustive: bool,
+}
+
+impl ContainerOptions {
+    /// Parse options from `#[score_debug(...)]` attributes.
+    fn parse(attrs: &[Attribute]) -> Result<Self, Error> {
+        let mut options = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("score_debug")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("non_exhaustive") {
+                    options.non_exhaustive = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported `score_debug` option"))
+                }
+            })?;
+        }
+        Ok(options)
+    }
+}
+
 /// Generate `ScoreDebug` implementation for struct.
 fn generate_for_struct(
     ident: Ident,
     data_struct: DataStruct,
+    options: ContainerOptions,
     impl_generics: ImplGenerics,
     ty_generics: TypeGenerics,
 ) -> Result<proc_macro2::TokenStream, Error> {
     // Generate `.fmt` implementations for struct types.
     let struct_name = ident.to_string();
+    let finish = if options.non_exhaustive {
+        quote! { .finish_non_exhaustive() }
+    } else {
+        quote! { .finish() }
+    };
     let fmt_impl = match data_struct.fields {
         // Regular struct - contains named fields.
         Fields::Named(fields) => {
@@ -43,7 +75,7 @@ fn generate_for_struct(
             quote! {
                 score_log::fmt::DebugStruct::new(f, spec, #struct_name)
                     #(#field_methods)*
-                    .finish()
+                    #finish
             }
         },
 
@@ -60,14 +92,14 @@ fn generate_for_struct(
             quote! {
                 score_log::fmt::DebugTuple::new(f, spec, #struct_name)
                     #(#field_methods)*
-                    .finish()
+                    #finish
             }
         },
 
         // Unit struct - no fields.
         Fields::Unit => {
             quote! {
-                score_log::fmt::DebugStruct::new(f, spec, #struct_name).finish()
+                score_log::fmt::DebugStruct::new(f, spec, #struct_name)#finish
             }
         },
     };
@@ -91,12 +123,13 @@ fn generate_for_enum(
     ty_generics: TypeGenerics,
 ) -> Result<proc_macro2::TokenStream, Error> {
     // Handle technically legal empty enum definition.
+    // No value of such enum can exist, so the match is unreachable.
     if data_enum.variants.is_empty() {
         return Ok(quote! {
             #[automatically_derived]
             impl #impl_generics score_log::fmt::ScoreDebug for #ident #ty_generics {
-                fn fmt(&self, f: score_log::fmt::Writer, spec: &score_log::fmt::FormatSpec) -> score_log::fmt::Result {
-                    Ok(())
+                fn fmt(&self, _f: score_log::fmt::Writer, _spec: &score_log::fmt::FormatSpec) -> score_log::fmt::Result {
+                    match *self {}
                 }
             }
         });
@@ -177,18 +210,25 @@ fn generate_for_enum(
 /// Generate `ScoreDebug` implementation.
 fn generate_score_debug(derive_input: DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
     let DeriveInput {
-        attrs: _,
+        attrs,
         vis: _,
         ident,
         generics,
         data,
     } = derive_input;
 
+    // Parse container-level options.
+    let options = ContainerOptions::parse(&attrs)?;
+
     // Split generics.
     let (impl_generics, ty_generics, _) = generics.split_for_impl();
 
     match data {
-        Data::Struct(data_struct) => generate_for_struct(ident, data_struct, impl_generics, ty_generics),
+        Data::Struct(data_struct) => generate_for_struct(ident, data_struct, options, impl_generics, ty_generics),
+        Data::Enum(_) if options.non_exhaustive => Err(Error::new(
+            proc_macro2::Span::call_site(),
+            "`#[score_debug(non_exhaustive)]` is only supported on structs",
+        )),
         Data::Enum(data_enum) => generate_for_enum(ident, data_enum, impl_generics, ty_generics),
         Data::Union(_) => Err(Error::new(
             proc_macro2::Span::call_site(),
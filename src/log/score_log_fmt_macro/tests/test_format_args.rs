@@ -15,6 +15,7 @@
 //!
 //! Only positive paths can be checked with regular unit tests.
 //! This is due to error paths resulting in compilation errors (as expected with proc macros).
+//! Diagnostics of error paths are checked in `compile_fail.rs`.
 //!
 //! Results are compared with Rust built-in `format_args` macro.
 
@@ -44,6 +45,13 @@ fn common_format_args_test(
     assert_eq!(w.get(), expected);
 }
 
+#[test]
+fn test_multibyte_literal() {
+    let score_log_args = score_log_format_args!("zażółć_{}_gęślą_{}", 123, 456);
+    let core_fmt_args = format_args!("zażółć_{}_gęślą_{}", 123, 456);
+    common_format_args_test(score_log_args, core_fmt_args, 4, "zażółć_123_gęślą_456");
+}
+
 #[test]
 fn test_single_literal() {
     let score_log_args = score_log_format_args!("test_string");
@@ -58,6 +66,21 @@ fn test_escaped_braces() {
     common_format_args_test(score_log_args, core_fmt_args, 1, "{}}{");
 }
 
+#[test]
+fn test_raw() {
+    let user_data = String::from("{} {name} {{");
+    let score_log_args = score_log_format_args!(raw: user_data);
+    assert!(matches!(score_log_args.0, [Fragment::Raw("{} {name} {{")]));
+
+    let mut w = StringWriter::new();
+    assert!(write(&mut w, score_log_args).is_ok());
+    assert_eq!(w.get(), "{} {name} {{");
+
+    // Any `AsRef<str>` is accepted, trailing comma is allowed.
+    let score_log_args = score_log_format_args!(raw: "{:?}",);
+    assert!(matches!(score_log_args.0, [Fragment::Raw("{:?}")]));
+}
+
 #[test]
 fn test_single_placeholder() {
     let score_log_args = score_log_format_args!("{}", 123);
@@ -91,14 +114,34 @@ fn test_arg_name() {
     let x1 = 123;
     let x2 = 234;
     let x3 = 345;
+    // Arguments matching the placeholder names are accepted, where `format_args` would capture them implicitly.
     let score_log_args = score_log_format_args!("test_{x3}_{x2}_{x1}", x1, x2, x3);
-    // NOTE: known misalignment.
-    // It is not allowed to have redundant arguments in Rust (`("{x1}", x1)`).
-    // This is currently not possible to do using `score_log_format_args`.
     let core_fmt_args = format_args!("test_{x3}_{x2}_{x1}");
     common_format_args_test(score_log_args, core_fmt_args, 6, "test_345_234_123");
 }
 
+#[test]
+fn test_arg_name_redundant() {
+    let x1 = 123;
+    let x2 = 234;
+    let score_log_args = score_log_format_args!("test_{x2}_{x1}", x1 = x1, x2 = x2);
+    let core_fmt_args = format_args!("test_{x2}_{x1}", x1 = x1, x2 = x2);
+    common_format_args_test(score_log_args, core_fmt_args, 4, "test_234_123");
+}
+
+#[test]
+fn test_arg_name_raw() {
+    let r#type = 123;
+    let r#match = 234;
+    let score_log_args = score_log_format_args!("test_{type}_{match}_{r#type}", r#type, r#match);
+    let core_fmt_args = format_args!("test_{}_{}_{}", r#type, r#match, r#type);
+    common_format_args_test(score_log_args, core_fmt_args, 6, "test_123_234_123");
+
+    let score_log_args = score_log_format_args!("test_{type}", r#type = r#match);
+    let core_fmt_args = format_args!("test_{}", r#match);
+    common_format_args_test(score_log_args, core_fmt_args, 2, "test_234");
+}
+
 #[test]
 fn test_arg_name_alias() {
     let x1 = 123;
@@ -114,10 +157,8 @@ fn test_arg_pos_and_name() {
     let x1 = 123;
     let x2 = 234;
     let x3 = 345;
+    // Arguments matching the placeholder names are accepted, where `format_args` would capture them implicitly.
     let score_log_args = score_log_format_args!("test_{x3}_{}_{x2}_{}_{x1}", x1, x2, x3);
-    // NOTE: known misalignment.
-    // It is not allowed to have redundant arguments in Rust (`("{x1}", x1)`).
-    // This is currently not possible to do using `score_log_format_args`.
     let core_fmt_args = format_args!("test_{x3}_{}_{x2}_{}_{x1}", x1, x2);
     common_format_args_test(score_log_args, core_fmt_args, 10, "test_345_123_234_234_123");
 }
@@ -136,7 +177,7 @@ fn test_format_spec_empty() {
     let args = score_log_format_args!("{:}", 123);
 
     let placeholder = match args.0.first().unwrap() {
-        Fragment::Literal(_) => panic!("invalid variant"),
+        Fragment::Literal(_) | Fragment::Raw(_) => panic!("invalid variant"),
         Fragment::Placeholder(placeholder) => placeholder,
     };
 
@@ -157,7 +198,7 @@ fn test_format_spec_all() {
     let args = score_log_format_args!("{:c<-#0333.555x}", 123);
 
     let placeholder = match args.0.first().unwrap() {
-        Fragment::Literal(_) => panic!("invalid variant"),
+        Fragment::Literal(_) | Fragment::Raw(_) => panic!("invalid variant"),
         Fragment::Placeholder(placeholder) => placeholder,
     };
 
@@ -178,7 +219,7 @@ fn test_format_spec_debug() {
     let args = score_log_format_args!("{:#X?}", 123);
 
     let placeholder = match args.0.first().unwrap() {
-        Fragment::Literal(_) => panic!("invalid variant"),
+        Fragment::Literal(_) | Fragment::Raw(_) => panic!("invalid variant"),
         Fragment::Placeholder(placeholder) => placeholder,
     };
 
@@ -199,7 +240,7 @@ fn test_format_spec_display_hint_octal() {
     let args = score_log_format_args!("{:o}", 123);
 
     let placeholder = match args.0.first().unwrap() {
-        Fragment::Literal(_) => panic!("invalid variant"),
+        Fragment::Literal(_) | Fragment::Raw(_) => panic!("invalid variant"),
         Fragment::Placeholder(placeholder) => placeholder,
     };
 
@@ -212,7 +253,7 @@ fn test_format_spec_display_hint_lower_hex() {
     let args = score_log_format_args!("{:x}", 123);
 
     let placeholder = match args.0.first().unwrap() {
-        Fragment::Literal(_) => panic!("invalid variant"),
+        Fragment::Literal(_) | Fragment::Raw(_) => panic!("invalid variant"),
         Fragment::Placeholder(placeholder) => placeholder,
     };
 
@@ -225,7 +266,7 @@ fn test_format_spec_display_hint_upper_hex() {
     let args = score_log_format_args!("{:X}", 123);
 
     let placeholder = match args.0.first().unwrap() {
-        Fragment::Literal(_) => panic!("invalid variant"),
+        Fragment::Literal(_) | Fragment::Raw(_) => panic!("invalid variant"),
         Fragment::Placeholder(placeholder) => placeholder,
     };
 
@@ -238,7 +279,7 @@ fn test_format_spec_display_hint_pointer() {
     let args = score_log_format_args!("{:p}", 123);
 
     let placeholder = match args.0.first().unwrap() {
-        Fragment::Literal(_) => panic!("invalid variant"),
+        Fragment::Literal(_) | Fragment::Raw(_) => panic!("invalid variant"),
         Fragment::Placeholder(placeholder) => placeholder,
     };
 
@@ -251,7 +292,7 @@ fn test_format_spec_display_hint_binary() {
     let args = score_log_format_args!("{:b}", 123);
 
     let placeholder = match args.0.first().unwrap() {
-        Fragment::Literal(_) => panic!("invalid variant"),
+        Fragment::Literal(_) | Fragment::Raw(_) => panic!("invalid variant"),
         Fragment::Placeholder(placeholder) => placeholder,
     };
 
@@ -264,7 +305,7 @@ fn test_format_spec_display_hint_lower_exp() {
     let args = score_log_format_args!("{:e}", 123);
 
     let placeholder = match args.0.first().unwrap() {
-        Fragment::Literal(_) => panic!("invalid variant"),
+        Fragment::Literal(_) | Fragment::Raw(_) => panic!("invalid variant"),
         Fragment::Placeholder(placeholder) => placeholder,
     };
 
@@ -277,10 +318,37 @@ fn test_format_spec_display_hint_upper_exp() {
     let args = score_log_format_args!("{:E}", 123);
 
     let placeholder = match args.0.first().unwrap() {
-        Fragment::Literal(_) => panic!("invalid variant"),
+        Fragment::Literal(_) | Fragment::Raw(_) => panic!("invalid variant"),
         Fragment::Placeholder(placeholder) => placeholder,
     };
 
     let format_spec = placeholder.format_spec();
     assert!(format_spec.get_display_hint() == DisplayHint::UpperExp);
 }
+
+#[test]
+fn test_score_format() {
+    let (x, y) = (123, "test");
+    let output = score_log_fmt::score_format!("x: {}, y: {}, z: {z}!", x, y, z = 1.5);
+    assert_eq!(output, std::format!("x: {}, y: {}, z: {z}!", x, y, z = 1.5));
+}
+
+#[test]
+fn test_score_format_spec() {
+    let (x, y) = (42u32, 12.34567f64);
+    let output = score_log_fmt::score_format!(
+        "[{x:>6}][{:06}][{x:+}][{:<5}][{y:8.2}][{y:+.3e}]",
+        -7,
+        true,
+        x = x,
+        y = y
+    );
+    let expected = std::format!(
+        "[{x:>6}][{:06}][{x:+}][{:<5}][{y:8.2}][{y:+.3e}]",
+        -7,
+        true,
+        x = x,
+        y = y
+    );
+    assert_eq!(output, expected);
+}
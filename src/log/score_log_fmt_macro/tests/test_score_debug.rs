@@ -80,6 +80,32 @@ fn test_struct_unit() {
     assert_eq!(w.get(), expected);
 }
 
+#[test]
+fn test_struct_non_exhaustive() {
+    #[derive(ScoreDebug)]
+    #[score_debug(non_exhaustive)]
+    struct Named {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(ScoreDebug)]
+    #[score_debug(non_exhaustive)]
+    struct Unnamed(i32, &'static str);
+
+    #[derive(ScoreDebug)]
+    #[score_debug(non_exhaustive)]
+    struct Unit;
+
+    let named = Named { x: 1, y: -2 };
+    let unnamed = Unnamed(3, "abc");
+    let args = score_log_format_args!("{:?} {:?} {:?}", named, unnamed, Unit);
+    let mut w = StringWriter::new();
+    let _ = write(&mut w, args).map_err(|_| panic!("write failed"));
+
+    assert_eq!(w.get(), "Named { x: 1, y: -2, .. } Unnamed(3, \"abc\", ..) Unit { .. }");
+}
+
 #[test]
 fn test_struct_generics() {
     #[derive(Debug, ScoreDebug)]
@@ -103,6 +129,30 @@ fn test_struct_generics() {
     assert_eq!(w.get(), expected);
 }
 
+#[test]
+fn test_struct_cow_phantom_data() {
+    #[derive(Debug, ScoreDebug)]
+    struct Example<'a, T> {
+        borrowed: std::borrow::Cow<'a, str>,
+        owned: std::borrow::Cow<'a, str>,
+        marker: core::marker::PhantomData<T>,
+    }
+
+    let p = Example::<u64> {
+        borrowed: std::borrow::Cow::Borrowed("borrowed"),
+        owned: std::borrow::Cow::Owned("owned".to_string()),
+        marker: core::marker::PhantomData,
+    };
+
+    let args = score_log_format_args!("{:?}", p);
+    let mut w = StringWriter::new();
+    let _ = write(&mut w, args).map_err(|_| panic!("write failed"));
+
+    // Compare with Rust built-in `Debug` derive macro.
+    let expected = format!("{:?}", p);
+    assert_eq!(w.get(), expected);
+}
+
 #[test]
 fn test_enum_plain() {
     #[allow(dead_code)]
@@ -163,4 +213,80 @@ fn test_enum_empty() {
     #[allow(dead_code)]
     #[derive(ScoreDebug)]
     enum X {}
+
+    // Check that implementation exists.
+    fn assert_score_debug<T: ScoreDebug>() {}
+    assert_score_debug::<X>();
+}
+
+#[test]
+fn test_struct_unit_generic() {
+    #[derive(Debug, ScoreDebug)]
+    struct Marker<T>(core::marker::PhantomData<T>);
+
+    #[derive(Debug, ScoreDebug)]
+    struct Unit;
+
+    let args = score_log_format_args!("{:?} {:?}", Unit, Marker::<u8>(core::marker::PhantomData));
+    let mut w = StringWriter::new();
+    let _ = write(&mut w, args).map_err(|_| panic!("write failed"));
+
+    // Compare with Rust built-in `Debug` derive macro.
+    let expected = format!("{:?} {:?}", Unit, Marker::<u8>(core::marker::PhantomData));
+    assert_eq!(w.get(), expected);
+}
+
+#[test]
+fn test_struct_pretty() {
+    #[derive(Debug, ScoreDebug)]
+    struct Point(i32, i32);
+
+    #[derive(Debug, ScoreDebug)]
+    struct Shape {
+        name: String,
+        points: Vec<Point>,
+        origin: Option<Point>,
+    }
+
+    let shape = Shape {
+        name: "line".to_string(),
+        points: vec![Point(0, 0), Point(3, -4)],
+        origin: None,
+    };
+
+    let args = score_log_format_args!("{:#?}", shape);
+    let mut w = StringWriter::new();
+    let _ = write(&mut w, args).map_err(|_| panic!("write failed"));
+
+    // Compare with Rust built-in `Debug` derive macro.
+    let expected = format!("{:#?}", shape);
+    assert_eq!(w.get(), expected);
+}
+
+#[test]
+fn test_struct_fn_pointer() {
+    fn double(v: i32) -> i32 {
+        v * 2
+    }
+
+    #[derive(Debug, ScoreDebug)]
+    struct Handler {
+        id: u8,
+        callback: fn(i32) -> i32,
+    }
+
+    let handler = Handler {
+        id: 1,
+        callback: double,
+    };
+
+    let args = score_log_format_args!("{:?}", handler);
+    let mut w = StringWriter::new();
+    let _ = write(&mut w, args).map_err(|_| panic!("write failed"));
+
+    // Function addresses vary between builds, so only the shape is checked.
+    let callback = w.get().strip_prefix("Handler { id: 1, callback: ").unwrap();
+    assert!(callback.starts_with("0x"));
+    assert!(callback.ends_with(" }"));
+    assert_eq!((handler.callback)(2), 4);
 }
@@ -0,0 +1,18 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+// Literal subspans are unstable, so on the pinned stable toolchain the whole literal is highlighted,
+// and the offending placeholder is named in the message.
+fn main() {
+    let _args = score_log::format_args!("a {} {:q}", 1, 2);
+}
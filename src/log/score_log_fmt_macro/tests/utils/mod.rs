@@ -43,38 +43,6 @@ impl ScoreWrite for StringWriter {
         write!(self.buf, "{}", v).map_err(|_| Error)
     }
 
-    fn write_i8(&mut self, v: &i8, _spec: &FormatSpec) -> Result {
-        write!(self.buf, "{}", v).map_err(|_| Error)
-    }
-
-    fn write_i16(&mut self, v: &i16, _spec: &FormatSpec) -> Result {
-        write!(self.buf, "{}", v).map_err(|_| Error)
-    }
-
-    fn write_i32(&mut self, v: &i32, _spec: &FormatSpec) -> Result {
-        write!(self.buf, "{}", v).map_err(|_| Error)
-    }
-
-    fn write_i64(&mut self, v: &i64, _spec: &FormatSpec) -> Result {
-        write!(self.buf, "{}", v).map_err(|_| Error)
-    }
-
-    fn write_u8(&mut self, v: &u8, _spec: &FormatSpec) -> Result {
-        write!(self.buf, "{}", v).map_err(|_| Error)
-    }
-
-    fn write_u16(&mut self, v: &u16, _spec: &FormatSpec) -> Result {
-        write!(self.buf, "{}", v).map_err(|_| Error)
-    }
-
-    fn write_u32(&mut self, v: &u32, _spec: &FormatSpec) -> Result {
-        write!(self.buf, "{}", v).map_err(|_| Error)
-    }
-
-    fn write_u64(&mut self, v: &u64, _spec: &FormatSpec) -> Result {
-        write!(self.buf, "{}", v).map_err(|_| Error)
-    }
-
     fn write_str(&mut self, v: &str, _spec: &FormatSpec) -> Result {
         write!(self.buf, "{}", v).map_err(|_| Error)
     }
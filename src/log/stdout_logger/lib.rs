@@ -16,152 +16,14 @@
 
 mod timestamp;
 
-use crate::timestamp::timestamp;
+use crate::timestamp::{timestamp, timestamp_rfc3339, MAX_SECS};
 use core::cell::RefCell;
 use core::fmt::Write;
-use score_log::fmt::{score_write, Error, FormatSpec, Result, ScoreWrite};
-use score_log::{LevelFilter, Log, Metadata, Record};
+use score_log::fmt::{score_write, StackWriter};
+use score_log::{Level, LevelFilter, Log, Metadata, Record};
+use std::collections::HashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 
-/// Fixed size buffer for strings.
-struct FixedBuf<const N: usize> {
-    buf: [u8; N],
-    len: usize,
-}
-
-impl<const N: usize> FixedBuf<N> {
-    pub const fn new() -> Self {
-        Self { buf: [0; N], len: 0 }
-    }
-
-    /// Get buffer as a string.
-    pub fn as_str(&self) -> &str {
-        // SAFETY: All bytes in `self.buf[..self.len]` are guaranteed to form valid UTF-8.
-        unsafe { core::str::from_utf8_unchecked(&self.buf[..self.len]) }
-    }
-
-    /// Reset buffer state.
-    pub fn clear(&mut self) {
-        self.len = 0;
-    }
-
-    /// Get number of remaining bytes in the buffer.
-    pub fn remaining(&self) -> usize {
-        N - self.len
-    }
-}
-
-impl<const N: usize> Default for FixedBuf<N> {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl<const N: usize> Write for FixedBuf<N> {
-    fn write_str(&mut self, s: &str) -> core::fmt::Result {
-        // Get number of remaining bytes in the buffer.
-        // Return if buffer is full.
-        let remaining = self.remaining();
-        if remaining == 0 {
-            return Ok(());
-        }
-
-        // Get provided string as bytes.
-        let bytes = s.as_bytes();
-
-        // Get number of bytes requested or remaining in the buffer.
-        let mut end = bytes.len().min(remaining);
-
-        // Move back until char boundary.
-        // Return if buffer is full.
-        while end > 0 && !s.is_char_boundary(end) {
-            end -= 1;
-        }
-        if end == 0 {
-            return Ok(());
-        }
-
-        // Write to underlying buffer.
-        self.buf[self.len..self.len + end].copy_from_slice(&bytes[..end]);
-        self.len += end;
-
-        Ok(())
-    }
-}
-
-/// Writer implementation based on fixed size buffer.
-#[derive(Default)]
-struct FixedBufWriter<const N: usize> {
-    buf: FixedBuf<N>,
-}
-
-impl<const N: usize> FixedBufWriter<N> {
-    /// Create `FixedBufWriter` instance.
-    pub fn new() -> Self {
-        Self { buf: FixedBuf::new() }
-    }
-
-    /// Get data from buffer.
-    pub fn get(&self) -> &str {
-        self.buf.as_str()
-    }
-
-    /// Reset buffer state.
-    pub fn clear(&mut self) {
-        self.buf.clear();
-    }
-}
-
-impl<const N: usize> ScoreWrite for FixedBufWriter<N> {
-    fn write_bool(&mut self, v: &bool, _spec: &FormatSpec) -> Result {
-        write!(self.buf, "{}", v).map_err(|_| Error)
-    }
-
-    fn write_f32(&mut self, v: &f32, _spec: &FormatSpec) -> Result {
-        write!(self.buf, "{}", v).map_err(|_| Error)
-    }
-
-    fn write_f64(&mut self, v: &f64, _spec: &FormatSpec) -> Result {
-        write!(self.buf, "{}", v).map_err(|_| Error)
-    }
-
-    fn write_i8(&mut self, v: &i8, _spec: &FormatSpec) -> Result {
-        write!(self.buf, "{}", v).map_err(|_| Error)
-    }
-
-    fn write_i16(&mut self, v: &i16, _spec: &FormatSpec) -> Result {
-        write!(self.buf, "{}", v).map_err(|_| Error)
-    }
-
-    fn write_i32(&mut self, v: &i32, _spec: &FormatSpec) -> Result {
-        write!(self.buf, "{}", v).map_err(|_| Error)
-    }
-
-    fn write_i64(&mut self, v: &i64, _spec: &FormatSpec) -> Result {
-        write!(self.buf, "{}", v).map_err(|_| Error)
-    }
-
-    fn write_u8(&mut self, v: &u8, _spec: &FormatSpec) -> Result {
-        write!(self.buf, "{}", v).map_err(|_| Error)
-    }
-
-    fn write_u16(&mut self, v: &u16, _spec: &FormatSpec) -> Result {
-        write!(self.buf, "{}", v).map_err(|_| Error)
-    }
-
-    fn write_u32(&mut self, v: &u32, _spec: &FormatSpec) -> Result {
-        write!(self.buf, "{}", v).map_err(|_| Error)
-    }
-
-    fn write_u64(&mut self, v: &u64, _spec: &FormatSpec) -> Result {
-        write!(self.buf, "{}", v).map_err(|_| Error)
-    }
-
-    fn write_str(&mut self, v: &str, _spec: &FormatSpec) -> Result {
-        write!(self.buf, "{}", v).map_err(|_| Error)
-    }
-}
-
 /// Builder for the `StdoutLogger`.
 pub struct StdoutLoggerBuilder(StdoutLogger);
 
@@ -177,6 +39,14 @@ impl StdoutLoggerBuilder {
         self
     }
 
+    /// Show current thread in logs.
+    ///
+    /// Thread name is used if set, thread ID otherwise.
+    pub fn show_thread(mut self, show_thread: bool) -> Self {
+        self.0.show_thread = show_thread;
+        self
+    }
+
     /// Show module name in logs.
     pub fn show_module(mut self, show_module: bool) -> Self {
         self.0.show_module = show_module;
@@ -195,9 +65,21 @@ impl StdoutLoggerBuilder {
         self
     }
 
+    /// Show process ID and context in logs, enabled by default.
+    pub fn show_context(mut self, show_context: bool) -> Self {
+        self.0.show_context = show_context;
+        self
+    }
+
+    /// Show log level in logs, enabled by default.
+    pub fn show_level(mut self, show_level: bool) -> Self {
+        self.0.show_level = show_level;
+        self
+    }
+
     /// Show timestamp.
     ///
-    /// UTC timestamp in the following format:
+    /// UTC timestamp in the format set with [`Self::timestamp_format`], by default:
     /// `[year]/[month]/[day] [hour]:[minute]:[second].[subsecond digits:7]`
     ///
     /// Example:
@@ -207,18 +89,128 @@ impl StdoutLoggerBuilder {
         self
     }
 
+    /// Set format of the timestamp.
+    pub fn timestamp_format(mut self, timestamp_format: TimestampFormat) -> Self {
+        self.0.timestamp_format = timestamp_format;
+        self
+    }
+
+    /// Keep the writer buffer in thread-local storage, enabled by default.
+    ///
+    /// If disabled, a new buffer is created on the stack for each logged record instead,
+    /// which allows using the logger in environments without thread-local storage.
+    pub fn thread_local_writer(mut self, thread_local_writer: bool) -> Self {
+        self.0.thread_local_writer = thread_local_writer;
+        self
+    }
+
+    /// Print `Fatal` and `Error` records to stderr, and all other records to stdout.
+    ///
+    /// Disabled by default, all records are printed to stdout.
+    pub fn split_streams(mut self, split_streams: bool) -> Self {
+        self.0.split_streams = split_streams;
+        self
+    }
+
+    /// Set colorization of the log level.
+    pub fn color(mut self, color: ColorMode) -> Self {
+        self.0.color = color;
+        self
+    }
+
     /// Filter logs by level.
     pub fn log_level(mut self, log_level: LevelFilter) -> Self {
         self.0.log_level = log_level;
         self
     }
 
+    /// Filter logs by level for a specific context.
+    ///
+    /// Overrides the level set with [`Self::log_level`] for records with exactly matching context.
+    pub fn context_level(mut self, context: &str, log_level: LevelFilter) -> Self {
+        self.0.context_levels.insert(context.to_string(), log_level);
+        self
+    }
+
+    /// Filter logs by level for a module path prefix.
+    ///
+    /// Overrides the levels set with [`Self::log_level`] and [`Self::context_level`] for records
+    /// with module path equal to `prefix` or nested in it, e.g., `my_crate::net` matches `my_crate::net::tcp`,
+    /// but not `my_crate::network`. If multiple prefixes match, the longest one is used.
+    pub fn module_level(mut self, prefix: &str, log_level: LevelFilter) -> Self {
+        match self.0.module_levels.iter_mut().find(|(p, _)| p == prefix) {
+            Some((_, level)) => *level = log_level,
+            None => self.0.module_levels.push((prefix.to_string(), log_level)),
+        }
+        self
+    }
+
+    /// Filter logs using level filters specification.
+    ///
+    /// Specification is a comma-separated list of entries, e.g., `info,NET=debug,DB=trace`:
+    /// - `level` - sets the default level, same as [`Self::log_level`],
+    /// - `context=level` - sets level for a context, same as [`Self::context_level`].
+    ///
+    /// Levels are parsed case-insensitively. Empty entries are ignored.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if an entry is malformed or doesn't contain a valid level.
+    pub fn filters(mut self, spec: &str) -> core::result::Result<Self, ParseFiltersError> {
+        for entry in spec.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let invalid_entry = || ParseFiltersError(entry.to_string());
+            match entry.split_once('=') {
+                Some((context, level)) => {
+                    let context = context.trim();
+                    if context.is_empty() {
+                        return Err(invalid_entry());
+                    }
+                    let level = level.trim().parse().map_err(|_| invalid_entry())?;
+                    self = self.context_level(context, level);
+                },
+                None => {
+                    let level = entry.parse().map_err(|_| invalid_entry())?;
+                    self = self.log_level(level);
+                },
+            }
+        }
+        Ok(self)
+    }
+
+    /// Create builder with level filters read from an environment variable.
+    ///
+    /// Refer to [`Self::filters`] for specification format.
+    /// Default parameters are used if the environment variable is not set.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the environment variable contains a malformed specification,
+    /// or isn't valid unicode, in which case the error entry is the lossy conversion of the value.
+    pub fn from_env(var: &str) -> core::result::Result<Self, ParseFiltersError> {
+        match std::env::var(var) {
+            Ok(spec) => Self::new().filters(&spec),
+            Err(std::env::VarError::NotPresent) => Ok(Self::new()),
+            Err(std::env::VarError::NotUnicode(value)) => Err(ParseFiltersError(value.to_string_lossy().into_owned())),
+        }
+    }
+
     /// Build the `StdoutLogger` with provided context and configuration.
-    pub fn build(self) -> StdoutLogger {
+    pub fn build(mut self) -> StdoutLogger {
+        // Resolve color mode once per stream, output targets don't change.
+        use std::io::IsTerminal;
+        (self.0.stdout_color, self.0.stderr_color) = match self.0.color {
+            ColorMode::Auto => (std::io::stdout().is_terminal(), std::io::stderr().is_terminal()),
+            ColorMode::Always => (true, true),
+            ColorMode::Never => (false, false),
+        };
         self.0
     }
 
     /// Build the `StdoutLogger` and set it as the default logger.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a global logger is already set, see [`try_set_as_default_logger`](Self::try_set_as_default_logger).
     pub fn set_as_default_logger(self) {
         if let Err(e) = self.try_set_as_default_logger() {
             panic!("unable to set logger: {e}");
@@ -226,40 +218,126 @@ impl StdoutLoggerBuilder {
     }
 
     /// Build the `StdoutLogger` and try to set it as the default logger.
+    ///
+    /// Returns an error if a global logger is already set.
     pub fn try_set_as_default_logger(self) -> core::result::Result<(), score_log::SetLoggerError> {
         let logger = self.build();
-        let level = logger.log_level();
+        let level = logger.max_log_level();
         score_log::set_global_logger(Box::new(logger))?;
         score_log::set_max_level(level);
         Ok(())
     }
 }
 
+/// Colorization mode of the log level.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorMode {
+    /// Colorize if the output stream is a terminal, resolved separately for stdout and stderr.
+    Auto,
+    /// Always colorize.
+    Always,
+    /// Never colorize.
+    #[default]
+    Never,
+}
+
+/// Format of the timestamp.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimestampFormat {
+    /// `[year]/[month]/[day] [hour]:[minute]:[second].[subsecond digits:7]`, e.g., `2026/01/27 11:33:41.1420089`.
+    #[default]
+    Default,
+    /// RFC 3339 with millisecond precision, e.g., `2026-01-27T11:33:41.142Z`.
+    Rfc3339,
+}
+
+/// The type returned by [`StdoutLoggerBuilder::filters`] when the specification contains a malformed entry.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseFiltersError(String);
+
+impl ParseFiltersError {
+    /// Malformed entry.
+    pub fn entry(&self) -> &str {
+        &self.0
+    }
+}
+
+impl core::fmt::Display for ParseFiltersError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(fmt, "invalid level filter entry: \"{}\"", self.0)
+    }
+}
+
+impl core::error::Error for ParseFiltersError {}
+
 impl Default for StdoutLoggerBuilder {
     fn default() -> Self {
         Self(StdoutLogger {
             context: "DFLT".to_string(),
+            show_thread: false,
             show_module: false,
             show_file: false,
             show_line: false,
             show_timestamp: true,
+            timestamp_format: TimestampFormat::default(),
+            show_context: true,
+            show_level: true,
+            thread_local_writer: true,
+            split_streams: false,
+            color: ColorMode::default(),
+            stdout_color: false,
+            stderr_color: false,
             log_level: LevelFilter::Info,
+            context_levels: HashMap::new(),
+            module_levels: Vec::new(),
         })
     }
 }
 
+/// ANSI escape sequence resetting the color.
+const COLOR_RESET: &str = "\x1b[0m";
+
+/// ANSI escape sequence setting the color for the log level.
+fn level_color(level: Level) -> &'static str {
+    match level {
+        Level::Fatal => "\x1b[1;31m",
+        Level::Error => "\x1b[31m",
+        Level::Warn => "\x1b[33m",
+        Level::Info => "\x1b[32m",
+        Level::Debug => "\x1b[34m",
+        Level::Trace => "\x1b[90m",
+    }
+}
+
+/// Appended to log lines that didn't fit into the writer buffer.
+const TRUNCATION_MARKER: &str = "...";
+
+/// Size of the writer buffer, log lines are truncated to this length.
+const WRITER_SIZE: usize = 2048;
+
 thread_local! {
-    static WRITER: RefCell<FixedBufWriter<2048>> = RefCell::new(FixedBufWriter::new());
+    static WRITER: RefCell<StackWriter<WRITER_SIZE>> = const { RefCell::new(StackWriter::new()) };
 }
 
 /// String-based logger implementation.
 pub struct StdoutLogger {
     context: String,
+    show_thread: bool,
     show_module: bool,
     show_file: bool,
     show_line: bool,
     show_timestamp: bool,
+    timestamp_format: TimestampFormat,
+    show_context: bool,
+    show_level: bool,
+    thread_local_writer: bool,
+    split_streams: bool,
+    color: ColorMode,
+    stdout_color: bool,
+    stderr_color: bool,
     log_level: LevelFilter,
+    context_levels: HashMap<String, LevelFilter>,
+    module_levels: Vec<(String, LevelFilter)>,
 }
 
 impl StdoutLogger {
@@ -267,67 +345,678 @@ impl StdoutLogger {
     pub fn log_level(&self) -> LevelFilter {
         self.log_level
     }
+
+    /// Log level for provided context.
+    ///
+    /// Per-context level is used if set, current log level otherwise.
+    pub fn context_log_level(&self, context: &str) -> LevelFilter {
+        self.context_levels.get(context).copied().unwrap_or(self.log_level)
+    }
+
+    /// Log level for provided module path and context.
+    ///
+    /// Per-module level of the longest matching prefix is used if set, per-context level otherwise.
+    pub fn module_log_level(&self, module_path: &str, context: &str) -> LevelFilter {
+        self.module_levels
+            .iter()
+            .filter(|(prefix, _)| {
+                module_path
+                    .strip_prefix(prefix.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, level)| *level)
+            .unwrap_or_else(|| self.context_log_level(context))
+    }
+
+    /// Most verbose log level across current log level, all per-context and all per-module levels.
+    pub fn max_log_level(&self) -> LevelFilter {
+        self.context_levels
+            .values()
+            .chain(self.module_levels.iter().map(|(_, level)| level))
+            .copied()
+            .fold(self.log_level, |acc, level| acc.max(level))
+    }
+
+    /// Write log line for the record into the writer.
+    fn write_record<const N: usize>(&self, writer: &mut StackWriter<N>, record: &Record) {
+        // Write timestamp, preferring the one captured with the record.
+        // Timestamps before the epoch or after year 9999 can't be formatted and are skipped.
+        if self.show_timestamp {
+            let time = record.timestamp().unwrap_or_else(SystemTime::now);
+            let since_epoch = time.duration_since(UNIX_EPOCH).ok();
+            if let Some(now) = since_epoch.filter(|now| now.as_secs() <= MAX_SECS) {
+                let (default_u8, rfc3339_u8);
+                let timestamp_u8: &[u8] = match self.timestamp_format {
+                    TimestampFormat::Default => {
+                        default_u8 = timestamp(now);
+                        &default_u8
+                    },
+                    TimestampFormat::Rfc3339 => {
+                        rfc3339_u8 = timestamp_rfc3339(now);
+                        &rfc3339_u8
+                    },
+                };
+                let timestamp_str = unsafe { str::from_utf8_unchecked(timestamp_u8) };
+                let _ = score_write!(writer, "[{}]", timestamp_str);
+            }
+        }
+
+        // Write thread, module, file and line.
+        if self.show_thread || self.show_module || self.show_file || self.show_line {
+            let _ = score_write!(writer, "[");
+            if self.show_thread {
+                let thread = std::thread::current();
+                match thread.name() {
+                    Some(name) => {
+                        let _ = score_write!(writer, "{}:", name);
+                    },
+                    None => {
+                        let _ = write!(writer, "{:?}:", thread.id());
+                    },
+                }
+            }
+            if self.show_module {
+                let _ = score_write!(writer, "{}:", record.module_path());
+            }
+            if self.show_file {
+                let _ = score_write!(writer, "{}:", record.file());
+            }
+            if self.show_line {
+                let _ = score_write!(writer, "{}", record.line());
+            }
+            let _ = score_write!(writer, "]");
+        }
+
+        // Write context, log level, log data.
+        if self.show_context {
+            let context = record.context();
+            let pid = std::process::id();
+            let _ = score_write!(writer, "[{}][{}]", pid, context);
+        }
+        if self.show_level {
+            let level = record.level().as_str();
+            if self.is_color_level(record.level()) {
+                let color = level_color(record.level());
+                let _ = score_write!(writer, "{}[{}]{}", color, level, COLOR_RESET);
+            } else {
+                let _ = score_write!(writer, "[{}]", level);
+            }
+        }
+        // Separate log data from the prefix, if any.
+        if writer.get().is_empty() {
+            let _ = score_write!(writer, "{}", record.args());
+        } else {
+            let _ = score_write!(writer, " {}", record.args());
+        }
+    }
+
+    /// Check if the record with provided level is printed to stderr.
+    fn is_stderr_level(&self, level: Level) -> bool {
+        self.split_streams && level <= Level::Error
+    }
+
+    /// Check if the record with provided level is colorized, depending on the stream it is printed to.
+    fn is_color_level(&self, level: Level) -> bool {
+        if self.is_stderr_level(level) {
+            self.stderr_color
+        } else {
+            self.stdout_color
+        }
+    }
+
+    /// Write log line for the record and print it to the stdout or stderr sink.
+    fn print_record<const N: usize>(
+        &self,
+        writer: &mut StackWriter<N>,
+        record: &Record,
+        stdout: &mut impl std::io::Write,
+        stderr: &mut impl std::io::Write,
+    ) {
+        // Write log line.
+        self.write_record(writer, record);
+
+        // Print to selected stream, marking lines truncated to the buffer size.
+        let marker = if writer.overflowed() { TRUNCATION_MARKER } else { "" };
+        let _ = if self.is_stderr_level(record.level()) {
+            writeln!(stderr, "{}{}", writer.get(), marker)
+        } else {
+            writeln!(stdout, "{}{}", writer.get(), marker)
+        };
+
+        // Reset buffer.
+        writer.clear();
+    }
 }
 
 impl Log for StdoutLogger {
     fn enabled(&self, metadata: &Metadata) -> bool {
-        metadata.level() <= self.log_level()
+        let level = match metadata.module_path() {
+            Some(module_path) => self.module_log_level(module_path, metadata.context()),
+            None => {
+                // Module path is not known, so any per-module level might apply.
+                // Records are filtered precisely in `log`.
+                let module_level = self.module_levels.iter().map(|(_, level)| *level).max();
+                self.context_log_level(metadata.context())
+                    .max(module_level.unwrap_or(LevelFilter::Off))
+            },
+        };
+        metadata.level() <= level
     }
 
     fn context(&self) -> &str {
         &self.context
     }
 
+    /// Same as [`StdoutLogger::max_log_level`].
+    fn max_level(&self) -> LevelFilter {
+        self.max_log_level()
+    }
+
     fn log(&self, record: &Record) {
         // Finish early if not enabled for requested level.
-        let metadata = record.metadata();
-        if !self.enabled(metadata) {
+        if record.level() > self.module_log_level(record.module_path(), record.context()) {
             return;
         }
 
-        // Operate in a scope of borrowed writer.
-        WRITER.with_borrow_mut(|writer| {
-            // Write timestamp.
-            if self.show_timestamp {
-                if let Ok(now) = SystemTime::now().duration_since(UNIX_EPOCH) {
-                    let timestamp_u8 = timestamp(now);
-                    let timestamp_str = unsafe { str::from_utf8_unchecked(timestamp_u8.as_slice()) };
-                    let _ = score_write!(writer, "[{}]", timestamp_str);
-                }
-            }
-
-            // Write module, file and line.
-            if self.show_module || self.show_file || self.show_line {
-                let _ = score_write!(writer, "[");
-                if self.show_module {
-                    let _ = score_write!(writer, "{}:", record.module_path());
-                }
-                if self.show_file {
-                    let _ = score_write!(writer, "{}:", record.file());
-                }
-                if self.show_line {
-                    let _ = score_write!(writer, "{}", record.line());
-                }
-                let _ = score_write!(writer, "]");
-            }
-
-            // Write context, log level, log data.
-            let context = record.context();
-            let level = metadata.level().as_str();
-            let pid = std::process::id();
-            let _ = score_write!(writer, "[{}][{}][{}] {}", pid, context, level, record.args());
-
-            // Print to stdout.
-            println!("{}", writer.get());
-
-            // Reset buffer.
-            writer.clear();
-        });
+        if self.thread_local_writer {
+            // Operate in a scope of borrowed writer.
+            WRITER.with_borrow_mut(|writer| {
+                self.print_record(writer, record, &mut std::io::stdout(), &mut std::io::stderr())
+            });
+        } else {
+            let mut writer = StackWriter::<WRITER_SIZE>::new();
+            self.print_record(&mut writer, record, &mut std::io::stdout(), &mut std::io::stderr());
+        }
     }
 
     fn flush(&self) {
         use std::io::Write;
         let mut stdout = std::io::stdout();
         stdout.flush().unwrap();
+        if self.split_streams {
+            std::io::stderr().flush().unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use score_log::format_args;
+
+    /// Check if provided string has the shape of the pattern, with `d` matching any ASCII digit.
+    fn has_shape(s: &str, pattern: &str) -> bool {
+        s.len() == pattern.len()
+            && s.bytes().zip(pattern.bytes()).all(|(c, p)| match p {
+                b'd' => c.is_ascii_digit(),
+                _ => c == p,
+            })
+    }
+
+    fn write_record(logger: &StdoutLogger) -> String {
+        let mut writer = StackWriter::<2048>::new();
+        let args = format_args!("test_{}", 123);
+        let record = Record::new(args, Metadata::new(Level::Info, "CTX"), "module", "file.rs", 42);
+        logger.write_record(&mut writer, &record);
+        writer.get().to_string()
+    }
+
+    #[test]
+    fn test_try_set_as_default_logger_twice() {
+        assert!(StdoutLoggerBuilder::new().try_set_as_default_logger().is_ok());
+        assert!(StdoutLoggerBuilder::new().try_set_as_default_logger().is_err());
+    }
+
+    #[test]
+    fn test_write_record_timestamp_prefix() {
+        let logger = StdoutLoggerBuilder::new()
+            .show_timestamp(true)
+            .show_module(true)
+            .show_file(true)
+            .show_line(true)
+            .build();
+        let line = write_record(&logger);
+
+        // Timestamp must be the first field, followed by module, file and line.
+        let (timestamp, rest) = line.strip_prefix('[').unwrap().split_once(']').unwrap();
+        assert!(
+            has_shape(timestamp, "dddd/dd/dd dd:dd:dd.ddddddd"),
+            "invalid timestamp: {timestamp}"
+        );
+        let expected_rest = format!("[module:file.rs:42][{}][CTX][INFO] test_123", std::process::id());
+        assert_eq!(rest, expected_rest);
+    }
+
+    #[test]
+    fn test_write_record_timestamp_rfc3339_prefix() {
+        let logger = StdoutLoggerBuilder::new()
+            .show_timestamp(true)
+            .timestamp_format(TimestampFormat::Rfc3339)
+            .show_module(true)
+            .show_file(true)
+            .show_line(true)
+            .build();
+        let line = write_record(&logger);
+
+        // Timestamp must be the first field, followed by module, file and line.
+        let (timestamp, rest) = line.strip_prefix('[').unwrap().split_once(']').unwrap();
+        assert!(
+            has_shape(timestamp, "dddd-dd-ddTdd:dd:dd.dddZ"),
+            "invalid timestamp: {timestamp}"
+        );
+        let expected_rest = format!("[module:file.rs:42][{}][CTX][INFO] test_123", std::process::id());
+        assert_eq!(rest, expected_rest);
+    }
+
+    #[test]
+    fn test_write_record_record_timestamp() {
+        let logger = StdoutLoggerBuilder::new().show_timestamp(true).build();
+        let mut writer = StackWriter::<2048>::new();
+        let record = Record::builder()
+            .context("CTX")
+            .timestamp(Some(UNIX_EPOCH + core::time::Duration::from_millis(1_500)))
+            .build();
+        logger.write_record(&mut writer, &record);
+        assert!(writer.get().starts_with("[1970/01/01 00:00:01.5000000]"));
+
+        let logger = StdoutLoggerBuilder::new()
+            .show_timestamp(true)
+            .timestamp_format(TimestampFormat::Rfc3339)
+            .build();
+        writer.clear();
+        logger.write_record(&mut writer, &record);
+        assert!(writer.get().starts_with("[1970-01-01T00:00:01.500Z]"));
+    }
+
+    #[test]
+    fn test_write_record_timestamp_out_of_range() {
+        let mut writer = StackWriter::<2048>::new();
+        for format in [TimestampFormat::Default, TimestampFormat::Rfc3339] {
+            let logger = StdoutLoggerBuilder::new()
+                .show_timestamp(true)
+                .timestamp_format(format)
+                .build();
+
+            let record = Record::builder()
+                .context("CTX")
+                .timestamp(Some(UNIX_EPOCH + core::time::Duration::from_secs(253402300800)))
+                .build();
+            writer.clear();
+            logger.write_record(&mut writer, &record);
+            assert!(writer.get().starts_with(&format!("[{}][CTX]", std::process::id())));
+
+            let record = Record::builder()
+                .context("CTX")
+                .timestamp(Some(UNIX_EPOCH + core::time::Duration::from_secs(253402300799)))
+                .build();
+            writer.clear();
+            logger.write_record(&mut writer, &record);
+            assert!(writer.get().starts_with("[9999"));
+        }
+    }
+
+    #[test]
+    fn test_write_record_no_timestamp() {
+        let logger = StdoutLoggerBuilder::new().show_timestamp(false).build();
+        let line = write_record(&logger);
+        assert_eq!(line, format!("[{}][CTX][INFO] test_123", std::process::id()));
+    }
+
+    #[test]
+    fn test_write_record_thread_name() {
+        let logger = std::sync::Arc::new(
+            StdoutLoggerBuilder::new()
+                .show_timestamp(false)
+                .show_thread(true)
+                .build(),
+        );
+        let handles: Vec<_> = ["worker_1", "worker_2"]
+            .into_iter()
+            .map(|name| {
+                let logger = logger.clone();
+                std::thread::Builder::new()
+                    .name(name.to_string())
+                    .spawn(move || write_record(&logger))
+                    .unwrap()
+            })
+            .collect();
+        let lines: Vec<String> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+
+        let pid = std::process::id();
+        assert_eq!(lines[0], format!("[worker_1:][{pid}][CTX][INFO] test_123"));
+        assert_eq!(lines[1], format!("[worker_2:][{pid}][CTX][INFO] test_123"));
+    }
+
+    #[test]
+    fn test_write_record_thread_id() {
+        let logger = StdoutLoggerBuilder::new()
+            .show_timestamp(false)
+            .show_thread(true)
+            .show_line(true)
+            .build();
+        let (line, id) = std::thread::spawn(move || (write_record(&logger), std::thread::current().id()))
+            .join()
+            .unwrap();
+        assert_eq!(line, format!("[{id:?}:42][{}][CTX][INFO] test_123", std::process::id()));
+    }
+
+    #[test]
+    fn test_write_record_color() {
+        let logger = StdoutLoggerBuilder::new()
+            .show_timestamp(false)
+            .color(ColorMode::Always)
+            .build();
+        let line = write_record(&logger);
+        assert_eq!(
+            line,
+            format!("[{}][CTX]\x1b[32m[INFO]\x1b[0m test_123", std::process::id())
+        );
+
+        let logger = StdoutLoggerBuilder::new()
+            .show_timestamp(false)
+            .color(ColorMode::Never)
+            .build();
+        let line = write_record(&logger);
+        assert_eq!(line, format!("[{}][CTX][INFO] test_123", std::process::id()));
+    }
+
+    #[test]
+    fn test_write_record_show_context_and_level() {
+        let pid = std::process::id();
+        let cases = [
+            (true, true, format!("[{pid}][CTX][INFO] test_123")),
+            (true, false, format!("[{pid}][CTX] test_123")),
+            (false, true, "[INFO] test_123".to_string()),
+            (false, false, "test_123".to_string()),
+        ];
+        for (show_context, show_level, expected) in cases {
+            let logger = StdoutLoggerBuilder::new()
+                .show_timestamp(false)
+                .show_context(show_context)
+                .show_level(show_level)
+                .build();
+            assert_eq!(write_record(&logger), expected);
+        }
+
+        // Location prefix is kept.
+        let logger = StdoutLoggerBuilder::new()
+            .show_timestamp(false)
+            .show_line(true)
+            .show_context(false)
+            .show_level(false)
+            .build();
+        assert_eq!(write_record(&logger), "[42] test_123");
+    }
+
+    #[test]
+    fn test_thread_local_writer() {
+        assert!(StdoutLoggerBuilder::new().build().thread_local_writer);
+        let logger = StdoutLoggerBuilder::new().thread_local_writer(false).build();
+        assert!(!logger.thread_local_writer);
+
+        // Record is written into a buffer on the stack.
+        let args = format_args!("test_{}", 123);
+        let record = Record::new(args, Metadata::new(Level::Info, "CTX"), "module", "file.rs", 42);
+        logger.log(&record);
+    }
+
+    /// Print one record per level with the level name as a message, returning lines printed to stdout and stderr.
+    fn print_all_levels(logger: &StdoutLogger) -> (Vec<String>, Vec<String>) {
+        let (mut stdout, mut stderr) = (Vec::new(), Vec::new());
+        let mut writer = StackWriter::<2048>::new();
+        for level in Level::iter() {
+            let message = level.as_str();
+            let args = format_args!("{}", message);
+            let record = Record::new(args, Metadata::new(level, "CTX"), "module", "file.rs", 1);
+            logger.print_record(&mut writer, &record, &mut stdout, &mut stderr);
+        }
+        let lines = |sink: Vec<u8>| String::from_utf8(sink).unwrap().lines().map(str::to_string).collect();
+        (lines(stdout), lines(stderr))
+    }
+
+    #[test]
+    fn test_split_streams_levels() {
+        let builder = || {
+            StdoutLoggerBuilder::new()
+                .show_timestamp(false)
+                .show_context(false)
+                .show_level(false)
+                .log_level(LevelFilter::Trace)
+        };
+
+        let logger = builder().build();
+        assert!(!logger.split_streams);
+        let (stdout, stderr) = print_all_levels(&logger);
+        assert_eq!(stdout, ["FATAL", "ERROR", "WARN", "INFO", "DEBUG", "TRACE"]);
+        assert!(stderr.is_empty());
+
+        let logger = builder().split_streams(true).build();
+        let (stdout, stderr) = print_all_levels(&logger);
+        assert_eq!(stdout, ["WARN", "INFO", "DEBUG", "TRACE"]);
+        assert_eq!(stderr, ["FATAL", "ERROR"]);
+    }
+
+    #[test]
+    fn test_color_mode_auto_resolved() {
+        use std::io::IsTerminal;
+        let logger = StdoutLoggerBuilder::new().color(ColorMode::Auto).build();
+        assert_eq!(logger.stdout_color, std::io::stdout().is_terminal());
+        assert_eq!(logger.stderr_color, std::io::stderr().is_terminal());
+    }
+
+    #[test]
+    fn test_color_per_stream() {
+        let mut logger = StdoutLoggerBuilder::new().split_streams(true).build();
+        logger.stdout_color = false;
+        logger.stderr_color = true;
+        assert!(logger.is_color_level(Level::Fatal));
+        assert!(logger.is_color_level(Level::Error));
+        assert!(!logger.is_color_level(Level::Warn));
+        assert!(!logger.is_color_level(Level::Info));
+
+        logger.split_streams = false;
+        assert!(!logger.is_color_level(Level::Error));
+    }
+
+    #[test]
+    fn test_context_level_more_verbose() {
+        let logger = StdoutLoggerBuilder::new()
+            .log_level(LevelFilter::Info)
+            .context_level("NET", LevelFilter::Debug)
+            .build();
+
+        assert!(logger.enabled(&Metadata::new(Level::Debug, "NET")));
+        assert!(!logger.enabled(&Metadata::new(Level::Trace, "NET")));
+        assert!(!logger.enabled(&Metadata::new(Level::Debug, "DFLT")));
+        assert!(logger.enabled(&Metadata::new(Level::Info, "DFLT")));
+    }
+
+    #[test]
+    fn test_context_level_less_verbose() {
+        let logger = StdoutLoggerBuilder::new()
+            .log_level(LevelFilter::Info)
+            .context_level("NET", LevelFilter::Off)
+            .build();
+
+        assert!(!logger.enabled(&Metadata::new(Level::Fatal, "NET")));
+        assert!(logger.enabled(&Metadata::new(Level::Fatal, "DFLT")));
+    }
+
+    #[test]
+    fn test_context_level_exact_match() {
+        let logger = StdoutLoggerBuilder::new()
+            .log_level(LevelFilter::Info)
+            .context_level("NET", LevelFilter::Trace)
+            .build();
+
+        assert!(!logger.enabled(&Metadata::new(Level::Trace, "NETWORK")));
+        assert!(!logger.enabled(&Metadata::new(Level::Trace, "net")));
+        assert_eq!(logger.context_log_level("NET"), LevelFilter::Trace);
+        assert_eq!(logger.context_log_level("NETWORK"), LevelFilter::Info);
+    }
+
+    #[test]
+    fn test_module_level_overlapping_prefixes() {
+        let logger = StdoutLoggerBuilder::new()
+            .log_level(LevelFilter::Info)
+            .context_level("NET", LevelFilter::Warn)
+            .module_level("my_crate", LevelFilter::Debug)
+            .module_level("my_crate::net", LevelFilter::Error)
+            .module_level("my_crate::net::tcp", LevelFilter::Trace)
+            .build();
+
+        assert_eq!(logger.module_log_level("my_crate", "DFLT"), LevelFilter::Debug);
+        assert_eq!(logger.module_log_level("my_crate::db", "DFLT"), LevelFilter::Debug);
+        assert_eq!(logger.module_log_level("my_crate::net", "DFLT"), LevelFilter::Error);
+        assert_eq!(logger.module_log_level("my_crate::net::udp", "NET"), LevelFilter::Error);
+        assert_eq!(
+            logger.module_log_level("my_crate::net::tcp::conn", "DFLT"),
+            LevelFilter::Trace
+        );
+        // Prefix must end on a path segment boundary.
+        assert_eq!(logger.module_log_level("my_crate::network", "DFLT"), LevelFilter::Debug);
+        assert_eq!(logger.module_log_level("my_crate_ext", "DFLT"), LevelFilter::Info);
+        // Context level is used if no prefix matches.
+        assert_eq!(logger.module_log_level("other", "NET"), LevelFilter::Warn);
+        assert_eq!(logger.module_log_level("other", "DFLT"), LevelFilter::Info);
+    }
+
+    #[test]
+    fn test_module_level_overwrite() {
+        let logger = StdoutLoggerBuilder::new()
+            .module_level("my_crate", LevelFilter::Debug)
+            .module_level("my_crate", LevelFilter::Off)
+            .build();
+        assert_eq!(logger.module_log_level("my_crate", "DFLT"), LevelFilter::Off);
+        assert_eq!(logger.max_log_level(), LevelFilter::Info);
+    }
+
+    #[test]
+    fn test_module_level_enabled() {
+        let logger = StdoutLoggerBuilder::new()
+            .log_level(LevelFilter::Info)
+            .module_level("my_crate::net", LevelFilter::Trace)
+            .build();
+
+        // Module path is unknown in metadata, so the most verbose per-module level applies.
+        assert!(logger.enabled(&Metadata::new(Level::Trace, "DFLT")));
+        assert_eq!(logger.max_log_level(), LevelFilter::Trace);
+
+        // Module path is known, so only the matching per-module level applies.
+        let metadata = |module_path| {
+            Metadata::builder()
+                .level(Level::Trace)
+                .context("DFLT")
+                .module_path(Some(module_path))
+                .build()
+        };
+        assert!(logger.enabled(&metadata("my_crate::net::tcp")));
+        assert!(!logger.enabled(&metadata("my_crate::db")));
+    }
+
+    #[test]
+    fn test_filters_well_formed() {
+        let logger = StdoutLoggerBuilder::new()
+            .filters("info,NET=debug,DB=trace")
+            .unwrap()
+            .build();
+        assert_eq!(logger.log_level(), LevelFilter::Info);
+        assert_eq!(logger.context_log_level("NET"), LevelFilter::Debug);
+        assert_eq!(logger.context_log_level("DB"), LevelFilter::Trace);
+        assert_eq!(logger.context_log_level("OTHER"), LevelFilter::Info);
+
+        let logger = StdoutLoggerBuilder::new()
+            .filters(" NET = off , WARN ,")
+            .unwrap()
+            .build();
+        assert_eq!(logger.log_level(), LevelFilter::Warn);
+        assert_eq!(logger.context_log_level("NET"), LevelFilter::Off);
+
+        let logger = StdoutLoggerBuilder::new().filters("NET=error").unwrap().build();
+        assert_eq!(logger.log_level(), LevelFilter::Info);
+        assert_eq!(logger.context_log_level("NET"), LevelFilter::Error);
+
+        let logger = StdoutLoggerBuilder::new().filters("").unwrap().build();
+        assert_eq!(logger.log_level(), LevelFilter::Info);
+        assert_eq!(logger.max_log_level(), LevelFilter::Info);
+
+        // Last entry wins.
+        let logger = StdoutLoggerBuilder::new()
+            .filters("debug,NET=warn,error,NET=trace")
+            .unwrap()
+            .build();
+        assert_eq!(logger.log_level(), LevelFilter::Error);
+        assert_eq!(logger.context_log_level("NET"), LevelFilter::Trace);
+    }
+
+    #[test]
+    fn test_filters_malformed() {
+        let tests = [
+            ("verbose", "verbose"),
+            ("info,NET=loud", "NET=loud"),
+            ("info,=debug", "=debug"),
+            ("NET=", "NET="),
+            ("NET=debug=trace", "NET=debug=trace"),
+        ];
+        for (spec, entry) in tests {
+            let error = StdoutLoggerBuilder::new().filters(spec).err().unwrap();
+            assert_eq!(error.entry(), entry);
+            assert_eq!(error.to_string(), format!("invalid level filter entry: \"{entry}\""));
+        }
+    }
+
+    #[test]
+    fn test_from_env_not_set() {
+        let logger = StdoutLoggerBuilder::from_env("STDOUT_LOGGER_TEST_NOT_SET")
+            .unwrap()
+            .build();
+        assert_eq!(logger.log_level(), LevelFilter::Info);
+    }
+
+    #[test]
+    fn test_from_env() {
+        std::env::set_var("STDOUT_LOGGER_TEST_FROM_ENV", "warn,NET=debug");
+        let logger = StdoutLoggerBuilder::from_env("STDOUT_LOGGER_TEST_FROM_ENV")
+            .unwrap()
+            .build();
+        assert_eq!(logger.log_level(), LevelFilter::Warn);
+        assert_eq!(logger.context_log_level("NET"), LevelFilter::Debug);
+
+        std::env::set_var("STDOUT_LOGGER_TEST_FROM_ENV", "warn,NET=loud");
+        let error = StdoutLoggerBuilder::from_env("STDOUT_LOGGER_TEST_FROM_ENV")
+            .err()
+            .unwrap();
+        assert_eq!(error.entry(), "NET=loud");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_from_env_not_unicode() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let value = std::ffi::OsStr::from_bytes(b"info,NET=\xff");
+        std::env::set_var("STDOUT_LOGGER_TEST_NOT_UNICODE", value);
+        let error = StdoutLoggerBuilder::from_env("STDOUT_LOGGER_TEST_NOT_UNICODE")
+            .err()
+            .unwrap();
+        assert_eq!(error.entry(), "info,NET=\u{fffd}");
+    }
+
+    #[test]
+    fn test_max_log_level() {
+        let logger = StdoutLoggerBuilder::new().log_level(LevelFilter::Warn).build();
+        assert_eq!(logger.max_log_level(), LevelFilter::Warn);
+
+        let logger = StdoutLoggerBuilder::new()
+            .log_level(LevelFilter::Warn)
+            .context_level("NET", LevelFilter::Debug)
+            .context_level("IO", LevelFilter::Error)
+            .build();
+        assert_eq!(logger.max_log_level(), LevelFilter::Debug);
+        assert_eq!(Log::max_level(&logger), LevelFilter::Debug);
+
+        let logger: Box<dyn Log> = Box::new(StdoutLoggerBuilder::new().log_level(LevelFilter::Error).build());
+        assert_eq!(logger.max_level(), LevelFilter::Error);
     }
 }
@@ -0,0 +1,47 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+#![allow(missing_docs)]
+
+use core::cell::Cell;
+use score_log::{Level, LevelFilter, Log};
+use stdout_logger::StdoutLoggerBuilder;
+
+// Global max level is set, so it's tested in a separate binary.
+#[test]
+fn disabled_module_arguments_not_evaluated() {
+    score_log::set_max_level(LevelFilter::Trace);
+    let evaluated = Cell::new(0);
+    let expensive = || {
+        evaluated.set(evaluated.get() + 1);
+        "value"
+    };
+
+    // Other module is more verbose, this one is not.
+    let logger = StdoutLoggerBuilder::new()
+        .log_level(LevelFilter::Info)
+        .module_level("other_crate::net", LevelFilter::Trace)
+        .build();
+    score_log::trace!(logger: logger, "{}", expensive());
+    assert_eq!(evaluated.get(), 0);
+    assert!(!score_log::log_enabled!(logger: logger, Level::Trace));
+
+    // This module is verbose.
+    let logger = StdoutLoggerBuilder::new()
+        .log_level(LevelFilter::Info)
+        .module_level(module_path!(), LevelFilter::Trace)
+        .build();
+    score_log::trace!(logger: logger, "{}", expensive());
+    assert_eq!(evaluated.get(), 1);
+    assert!(score_log::log_enabled!(logger: logger, Level::Trace));
+}
@@ -18,6 +18,9 @@
 
 use core::time::Duration;
 
+/// Last second that can be formatted, `9999-12-31T23:59:59Z`.
+pub const MAX_SECS: u64 = 253402300799;
+
 /// Determine if provided year is a leap year.
 fn is_leap_year(year: u64) -> bool {
     (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
@@ -92,13 +95,11 @@ fn write<const N: usize>(buf: &mut [u8], value: u64) {
     }
 }
 
-/// Get timestamp in following format:
-/// `[year]/[month]/[day] [hour]:[minute]:[second].[subsecond digits:7]`
-pub fn timestamp(duration_since_epoch_start: Duration) -> [u8; 27] {
-    debug_assert!(duration_since_epoch_start.as_secs() <= 253402300799);
+/// Calculate date and time - (year, month, day), (hour, minute, second).
+fn get_date_time(duration_since_epoch_start: Duration) -> ((u64, u64, u64), (u64, u64, u64)) {
+    debug_assert!(duration_since_epoch_start.as_secs() <= MAX_SECS);
 
     let secs = duration_since_epoch_start.as_secs();
-    let subsec_nanos = duration_since_epoch_start.subsec_nanos() as u64;
 
     const SECS_PER_DAY: u64 = 24 * 60 * 60;
 
@@ -106,11 +107,14 @@ pub fn timestamp(duration_since_epoch_start: Duration) -> [u8; 27] {
     let days_in_epoch = secs / SECS_PER_DAY;
     let secs_in_day = secs % SECS_PER_DAY;
 
-    // Calculate time.
-    let (hour, minute, second) = get_time(secs_in_day);
+    (get_date(days_in_epoch), get_time(secs_in_day))
+}
 
-    // Calculate date.
-    let (year, month, day) = get_date(days_in_epoch);
+/// Get timestamp in following format:
+/// `[year]/[month]/[day] [hour]:[minute]:[second].[subsecond digits:7]`
+pub fn timestamp(duration_since_epoch_start: Duration) -> [u8; 27] {
+    let ((year, month, day), (hour, minute, second)) = get_date_time(duration_since_epoch_start);
+    let subsec_nanos = duration_since_epoch_start.subsec_nanos() as u64;
 
     // Format output as `u8` array of known size.
     let mut output = [0; _];
@@ -135,9 +139,39 @@ pub fn timestamp(duration_since_epoch_start: Duration) -> [u8; 27] {
     output
 }
 
+/// Get RFC 3339 UTC timestamp with millisecond precision in following format:
+/// `[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:3]Z`
+pub fn timestamp_rfc3339(duration_since_epoch_start: Duration) -> [u8; 24] {
+    let ((year, month, day), (hour, minute, second)) = get_date_time(duration_since_epoch_start);
+    let subsec_millis = duration_since_epoch_start.subsec_millis() as u64;
+
+    // Format output as `u8` array of known size.
+    let mut output = [0; _];
+
+    // Write date in `YYYY-MM-ddT` format.
+    write::<4>(&mut output[0..4], year);
+    output[4] = b'-';
+    write::<2>(&mut output[5..7], month);
+    output[7] = b'-';
+    write::<2>(&mut output[8..10], day);
+    output[10] = b'T';
+
+    // Write time in `HH:mm:ss.nnnZ` format.
+    write::<2>(&mut output[11..13], hour);
+    output[13] = b':';
+    write::<2>(&mut output[14..16], minute);
+    output[16] = b':';
+    write::<2>(&mut output[17..19], second);
+    output[19] = b'.';
+    write::<3>(&mut output[20..23], subsec_millis);
+    output[23] = b'Z';
+
+    output
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{get_date, get_time, timestamp};
+    use super::{get_date, get_time, timestamp, timestamp_rfc3339};
     use core::time::Duration;
 
     #[test]
@@ -230,4 +264,27 @@ mod tests {
         let duration = Duration::from_secs(253402300800);
         let _ = timestamp(duration);
     }
+
+    #[test]
+    fn test_timestamp_rfc3339_zero() {
+        let ts_u8 = timestamp_rfc3339(Duration::from_secs(0));
+        let ts_str = str::from_utf8(ts_u8.as_slice()).unwrap();
+        assert_eq!(ts_str, "1970-01-01T00:00:00.000Z");
+    }
+
+    #[test]
+    fn test_timestamp_rfc3339_in_range() {
+        let duration = Duration::from_secs(1769604017) + Duration::from_nanos(123456789);
+        let ts_u8 = timestamp_rfc3339(duration);
+        let ts_str = str::from_utf8(ts_u8.as_slice()).unwrap();
+        assert_eq!(ts_str, "2026-01-28T12:40:17.123Z");
+    }
+
+    #[test]
+    fn test_timestamp_rfc3339_max_allowed() {
+        let duration = Duration::from_secs(253402300799) + Duration::from_nanos(999999999);
+        let ts_u8 = timestamp_rfc3339(duration);
+        let ts_str = str::from_utf8(ts_u8.as_slice()).unwrap();
+        assert_eq!(ts_str, "9999-12-31T23:59:59.999Z");
+    }
 }